@@ -3,22 +3,33 @@ use crate::public::{
     error::{internal_error, InternalComponent},
 };
 
-const PRIORITY: [i8; 15] = [
-    2, // Symbols::Plus
-    2, // Symbols::Minus
-    3, // Symbols::Multiply
-    3, // Symbols::Divide
-    3, // Symbols::Mod
-    4, // Symbols::Power
-    5, // Symbols::Not
-    1, // Symbols::LessThan
-    1, // Symbols::MoreThan
-    1, // Symbols::LessThanEqual
-    1, // Symbols::MoreThanEqual
-    1, // Symbols::CompareEqual
-    1, // Symbols::NotEqual
-    0, // Symbols::AndSign
-    0, // Symbols::OrSign
+const PRIORITY: [i8; 26] = [
+    6,  // Symbols::Plus
+    6,  // Symbols::Minus
+    7,  // Symbols::Multiply
+    7,  // Symbols::Divide
+    7,  // Symbols::FloorDivide
+    7,  // Symbols::Mod
+    8,  // Symbols::Power
+    9,  // Symbols::Not
+    1,  // Symbols::LessThan
+    1,  // Symbols::MoreThan
+    1,  // Symbols::LessThanEqual
+    1,  // Symbols::MoreThanEqual
+    1,  // Symbols::CompareEqual
+    1,  // Symbols::NotEqual
+    0,  // Symbols::AndSign
+    0,  // Symbols::OrSign
+    4,  // Symbols::BitAnd
+    2,  // Symbols::BitOr
+    3,  // Symbols::BitXor
+    9,  // Symbols::BitNot
+    5,  // Symbols::ShiftLeft
+    5,  // Symbols::ShiftRight
+    10, // Symbols::Factorial
+    10, // Symbols::Percent
+    6,  // Symbols::PlusMinus
+    -1, // Symbols::Convert
 ];
 
 fn get_priority(symbol_node: &ASTNode) -> Result<i8, ()> {
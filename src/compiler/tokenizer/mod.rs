@@ -7,51 +7,200 @@ use crate::compiler::tokenizer::char_converter::char_converter;
 use crate::public::compile_time::dividers::Divider;
 use crate::public::compile_time::keywords::Keyword;
 use crate::public::compile_time::parens::Paren;
+use crate::public::env::ENV_OPTION;
 use crate::public::error::{assignment_error, syntax_error};
+use crate::public::value::big_decimal::BigDecimal;
+use crate::public::value::quantity::Unit;
 use crate::public::value::symbols::Symbols;
 use crate::public::value::{number::Number, value::ValueType};
 use crate::utils::ascii::{ascii_to_num, is_identi_ascii};
 
 use token::{Token, TokenType, TokenVec};
 
-fn number_resolver(chars: &mut Chars, first_ch: char, index: &mut usize) -> (char, Number) {
+fn radix_resolver(chars: &mut Chars, radix: u32, index: &mut usize) -> Result<(char, Number), ()> {
+    let mut value = Number::Int(0);
+    let mut has_digit = false;
+    let mut last_ch = '\0';
+    let mut cached_ch = '\0';
+
+    while let Some(ch) = chars.next() {
+        *index += 1;
+
+        if ch == '_' {
+            let is_digit = |ch: char| ch.is_digit(radix);
+            check_separator(is_digit(last_ch) && chars.clone().next().is_some_and(is_digit))?;
+            last_ch = ch;
+            continue;
+        }
+        if let Some(digit) = ch.to_digit(radix) {
+            value = value * Number::Int(radix as i64);
+            value = value + Number::Int(digit as i64);
+            has_digit = true;
+            last_ch = ch;
+            continue;
+        }
+        if ch.is_ascii_alphanumeric() {
+            let msg = format!("invalid digit '{}' for base {} number literal", ch, radix);
+            return Err(syntax_error(&msg)?);
+        }
+        cached_ch = ch;
+        break;
+    }
+
+    if !has_digit {
+        return Err(syntax_error("missing digits after the number prefix")?);
+    }
+    return Ok((cached_ch, value));
+}
+
+fn number_resolver(
+    chars: &mut Chars,
+    first_ch: char,
+    index: &mut usize,
+) -> Result<(char, Number), ()> {
     enum State {
         Int,
         Float,
+        Exponent,
+    }
+
+    if first_ch == '0' {
+        // number literal with prefix: 0x | 0o | 0b
+        let radix = match chars.clone().next() {
+            Some('x' | 'X') => 16,
+            Some('o' | 'O') => 8,
+            Some('b' | 'B') => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            chars.next();
+            *index += 1;
+            return radix_resolver(chars, radix, index);
+        }
     }
 
     let mut state = State::Int;
-    let mut float_para: f64 = 10.0;
-    let mut value = Number::Int(ascii_to_num(first_ch));
+    // the integer value is computed digit by digit so that
+    // it can be promoted to big integer when overflowed,
+    // and the float value is parsed from the literal string
+    // so that it is correctly rounded.
+    let mut int_value = Number::Int(ascii_to_num(first_ch));
+    let mut literal = String::from(first_ch);
     let mut cached_ch = '\0';
 
     while let Some(ch) = chars.next() {
         *index += 1;
 
-        if ch.is_ascii_digit() {
-            let num_ascii = ascii_to_num(ch);
-            match state {
-                State::Int => {
-                    value = value * Number::Int(10);
-                    value = value + Number::Int(num_ascii);
+        match ch {
+            '0'..='9' => {
+                if let State::Int = state {
+                    int_value = int_value * Number::Int(10);
+                    int_value = int_value + Number::Int(ascii_to_num(ch));
                 }
-                State::Float => {
-                    value = value + Number::Float((num_ascii as f64) / float_para);
-                    float_para *= 10.0;
+                literal.push(ch);
+            }
+            '_' => {
+                let is_after_digit = literal.ends_with(|ch: char| ch.is_ascii_digit());
+                let is_before_digit = chars.clone().next().is_some_and(|ch| ch.is_ascii_digit());
+                check_separator(is_after_digit && is_before_digit)?;
+            }
+            '.' if matches!(state, State::Int) => {
+                state = State::Float;
+                literal.push(ch);
+            }
+            '.' if chars.clone().next().is_some_and(|ch| ch.is_ascii_digit()) => {
+                // e.g. `1.2.3` and `1e2.3`
+                literal.push(ch);
+                literal.extend(chars.clone().take_while(|ch| ch.is_ascii_digit()));
+                let msg = format!("invalid number literal '{}'", literal);
+                return Err(syntax_error(&msg)?);
+            }
+            // `e` is the exponent only when followed by digits,
+            // otherwise it is an identifier, e.g. `2e` and `2E`.
+            'e' | 'E' if !matches!(state, State::Exponent) && is_exponent(chars) => {
+                state = State::Exponent;
+                literal.push(ch);
+
+                // sign of exponent
+                if let Some(sign @ ('+' | '-')) = chars.clone().next() {
+                    chars.next();
+                    *index += 1;
+                    literal.push(sign);
                 }
             }
-            continue;
+            _ => {
+                cached_ch = ch;
+                break;
+            }
         }
+    }
 
-        if ch == '.' {
-            state = State::Float;
-            value = value.float();
-            continue;
+    let value = match state {
+        State::Int => int_value,
+        State::Float | State::Exponent => {
+            // the literal is parsed as decimal in decimal mode
+            let precision = unsafe { ENV_OPTION.decimal_precision };
+            let parsed = if precision > 0 {
+                BigDecimal::parse(&literal).map(|decimal| Number::Decimal(decimal.round(precision)))
+            } else {
+                literal.parse::<f64>().ok().map(Number::Float)
+            };
+            match parsed {
+                Some(Number::Float(f)) if f.is_infinite() => overflowed_resolver(&literal)?,
+                Some(float_value) => float_value,
+                None => {
+                    let msg = format!("invalid number literal '{}'", literal);
+                    return Err(syntax_error(&msg)?);
+                }
+            }
         }
-        cached_ch = ch;
-        break;
+    };
+    return Ok((cached_ch, value));
+}
+
+// the exponent digits with optional sign after `e` or `E`
+fn is_exponent(chars: &Chars) -> bool {
+    let mut following = chars.clone();
+    let next = match following.next() {
+        Some('+' | '-') => following.next(),
+        next => next,
+    };
+    return next.is_some_and(|ch| ch.is_ascii_digit());
+}
+
+// the digit separator `_` is only allowed between digits
+fn check_separator(is_between_digits: bool) -> Result<(), ()> {
+    if !is_between_digits {
+        return Err(syntax_error(
+            "digit separator '_' should be between digits",
+        )?);
     }
-    return (cached_ch, value);
+    return Ok(());
+}
+
+// the literal overflowed as float is resolved as big integer
+// when it is an integer, e.g. `2e400` and `1.5e400`.
+fn overflowed_resolver(literal: &str) -> Result<Number, ()> {
+    let (mantissa, exponent) = literal.split_once(['e', 'E']).unwrap_or((literal, "0"));
+    let (int_part, fraction_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let shift = exponent
+        .parse::<i64>()
+        .ok()
+        .and_then(|exp| exp.checked_sub(fraction_part.len() as i64));
+
+    let digits = int_part.chars().chain(fraction_part.chars());
+    let mantissa = digits.fold(Number::Int(0), |value, ch| {
+        value * Number::Int(10) + Number::Int(ascii_to_num(ch))
+    });
+    let result = match shift {
+        Some(shift) if shift >= 0 => mantissa * Number::pow(Number::Int(10), Number::Int(shift)),
+        _ => Number::Float(f64::INFINITY),
+    };
+    if let Number::Float(_) = result {
+        let msg = format!("number literal '{}' is too large", literal);
+        return Err(syntax_error(&msg)?);
+    }
+    return Ok(result);
 }
 
 fn identi_resolver(chars: &mut Chars, first_ch: char, index: &mut usize) -> (char, String) {
@@ -71,6 +220,232 @@ fn identi_resolver(chars: &mut Chars, first_ch: char, index: &mut usize) -> (cha
     return (cached_ch, value);
 }
 
+// resolves the unit after number literal separated by spaces, e.g. `3 km`, `2 m^2` and `9.8 m/s^2`,
+// the units joined by `*` or `/` should not be separated by spaces,
+// and `2t` without space is still `2 * t` for the variable `t`.
+// the unit can also be in brackets, e.g. `3[km]` and `2 [in]`.
+// returns None and consumes nothing when there is no valid unit.
+fn unit_resolver(
+    chars: &mut Chars,
+    first_ch: char,
+    index: &mut usize,
+) -> Result<Option<(char, String)>, ()> {
+    let mut lookahead = chars.clone();
+    let mut consumed = 0;
+
+    let mut ch = first_ch;
+    while ch == ' ' || ch == '\t' {
+        let Some(next_ch) = lookahead.next() else {
+            return Ok(None);
+        };
+        ch = next_ch;
+        consumed += 1;
+    }
+    if ch != '[' {
+        if consumed == 0 {
+            return Ok(None);
+        }
+        let Some((lookahead, unit_consumed, cached_ch, unit)) = spaced_unit_resolver(lookahead, ch)
+        else {
+            return Ok(None);
+        };
+        *chars = lookahead;
+        *index += consumed + unit_consumed;
+        return Ok(Some((cached_ch, unit)));
+    }
+
+    let mut unit = String::new();
+    loop {
+        let Some(ch) = lookahead.next() else {
+            return Err(syntax_error("missing `]` after unit")?);
+        };
+        consumed += 1;
+        if ch == ']' {
+            break;
+        }
+        unit.push(ch);
+    }
+    if Unit::parse(&unit).is_none() {
+        let msg = format!("unknown unit \"{}\"", unit.trim());
+        return Err(syntax_error(&msg)?);
+    }
+
+    *chars = lookahead;
+    *index += consumed;
+    return Ok(Some(('\0', unit.trim().to_string())));
+}
+
+// the longest valid unit starting with `first_ch`,
+// returns the rest characters, the consumed count, the cached char and the unit.
+fn spaced_unit_resolver(mut chars: Chars, first_ch: char) -> Option<(Chars, usize, char, String)> {
+    let mut consumed = 0;
+    let mut unit = String::new();
+    let mut result = None;
+
+    let mut ch = first_ch;
+    while is_identi_ascii(ch) {
+        let start = unit.len();
+        unit.push(ch);
+        let mut cached_ch = '\0';
+        for next_ch in chars.by_ref() {
+            consumed += 1;
+            if is_identi_ascii(next_ch) || next_ch.is_ascii_digit() {
+                unit.push(next_ch);
+            } else {
+                cached_ch = next_ch;
+                break;
+            }
+        }
+        // the keywords, e.g. `in`, and the invocation, e.g. `2 f(x)`
+        let word = &unit[start..];
+        if Keyword::is_keyword(word).is_some()
+            || Symbols::is_word_symbol(word).is_some()
+            || cached_ch == '('
+        {
+            break;
+        }
+
+        // the integer exponent of the unit
+        if cached_ch == '^' {
+            let mut exp_chars = chars.clone();
+            let mut exp_text = String::from('^');
+            let mut exp_consumed = 0;
+            let mut exp_cached_ch = '\0';
+            for ch in exp_chars.by_ref() {
+                exp_consumed += 1;
+                if ch.is_ascii_digit() || (ch == '-' && exp_text.len() == 1) {
+                    exp_text.push(ch);
+                } else {
+                    exp_cached_ch = ch;
+                    break;
+                }
+            }
+            if exp_text.ends_with(|ch: char| ch.is_ascii_digit()) {
+                unit.push_str(&exp_text);
+                chars = exp_chars;
+                consumed += exp_consumed;
+                cached_ch = exp_cached_ch;
+            }
+        }
+        if Unit::parse(&unit).is_some() {
+            result = Some((chars.clone(), consumed, cached_ch, unit.clone()));
+        }
+
+        // the next unit joined by `*` or `/`
+        match (cached_ch, chars.clone().next()) {
+            ('*' | '/', Some(next_ch)) if is_identi_ascii(next_ch) => {
+                unit.push(cached_ch);
+                chars.next();
+                consumed += 1;
+                ch = next_ch;
+            }
+            _ => break,
+        }
+    }
+    return result;
+}
+
+// resolves the unit expression after `->` until the end of expression
+fn unit_expr_resolver(chars: &mut Chars, index: &mut usize) -> Result<(char, String), ()> {
+    let mut unit = String::new();
+    let mut paren_depth = 0;
+    let mut cached_ch = '\0';
+
+    while let Some(ch) = chars.next() {
+        *index += 1;
+
+        if ch == '-' && chars.clone().next() == Some('>') {
+            // the chained conversion, e.g. `x -> m -> cm`
+            cached_ch = ch;
+            break;
+        }
+        let is_unit_char =
+            ch.is_alphanumeric() || matches!(ch, ' ' | '\t' | '*' | '/' | '^' | '-' | '.' | '_');
+        if ch == '(' {
+            paren_depth += 1;
+        } else if ch == ')' && paren_depth > 0 {
+            paren_depth -= 1;
+        } else if !is_unit_char {
+            cached_ch = ch;
+            break;
+        }
+        unit.push(ch);
+    }
+
+    let unit = unit.trim().to_string();
+    if unit.is_empty() {
+        return Err(syntax_error("missing unit after `->`")?);
+    }
+    return Ok((cached_ch, unit));
+}
+
+// whether the last token ends an operand,
+// which makes the following `!` and `%` postfix operators.
+fn is_operand_end(tokens: &TokenVec) -> bool {
+    return matches!(
+        tokens.back(),
+        Some(
+            Token::Number(_)
+                | Token::Identi(_)
+                | Token::Paren(Paren::RightParen | Paren::RightBracket)
+                | Token::Symbol(Symbols::Factorial | Symbols::Percent)
+        )
+    );
+}
+
+// whether the following characters start an operand,
+// `%` followed by an operand is the modulo operator, e.g. `7 % 3` and `7 % -3`.
+fn is_operand_start(chars: &Chars) -> bool {
+    let mut lookahead = chars.clone().skip_while(|ch| *ch == ' ' || *ch == '\t');
+    let is_start = |ch: char| is_identi_ascii(ch) || ch.is_ascii_digit() || ch == '(';
+    return match lookahead.next() {
+        Some('-') => lookahead.next().is_some_and(is_start),
+        Some(ch) => is_start(ch) || ch == '\'' || ch == '\"' || ch == '[' || ch == '{',
+        None => false,
+    };
+}
+
+// inserts the omitted multiplying symbols, e.g. `2x`, `3PI`, `2(3 + 4)`, `(a + b)(a - b)` and `5!x`,
+// the parentheses following identifier, `fn`, `)` of invocation, `]` and `}`
+// are regarded as invocation, such as `f(x)`, `f(x)(y)` and `arr[0](x)`.
+fn implicit_multiply(tokens: TokenVec) -> TokenVec {
+    let mut result = TokenVec::with_capacity(tokens.len());
+    // whether the unclosed `(` is grouping parenthesis
+    let mut paren_stack = Vec::<bool>::new();
+    let mut is_operand_end = false;
+
+    for token in tokens {
+        let is_operand_start = match token {
+            // `2 3` is not regarded as multiplying
+            Token::Number(_) => !matches!(result.back(), Some(Token::Number(_))),
+            Token::Identi(_) | Token::Paren(Paren::LeftParen) => true,
+            _ => false,
+        };
+        if is_operand_end && is_operand_start {
+            result.push_back(Token::Symbol(Symbols::Multiply));
+        }
+
+        is_operand_end = match token {
+            Token::Number(_) | Token::Symbol(Symbols::Factorial | Symbols::Percent) => true,
+            Token::Paren(Paren::RightParen) => paren_stack.pop().unwrap_or(false),
+            _ => false,
+        };
+        if token == Token::Paren(Paren::LeftParen) {
+            let is_invocation = matches!(
+                result.back(),
+                Some(
+                    Token::Identi(_)
+                        | Token::Keyword(Keyword::Function)
+                        | Token::Paren(Paren::RightParen | Paren::RightBracket | Paren::RightBrace)
+                )
+            );
+            paren_stack.push(!is_invocation);
+        }
+        result.push_back(token);
+    }
+    return result;
+}
+
 pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
     // is used for check is number minus OR
     // check is in annotation state.
@@ -100,7 +475,7 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
         if ch.is_ascii_digit() {
             last_type = TokenType::Number;
             let mut value: Number;
-            (cached_ch, value) = number_resolver(&mut chars, ch, &mut index);
+            (cached_ch, value) = number_resolver(&mut chars, ch, &mut index)?;
 
             if is_num_minus {
                 is_num_minus = false;
@@ -108,6 +483,17 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
             }
 
             let current_token = Token::Number(value);
+            if let Some((next_ch, unit)) = unit_resolver(&mut chars, cached_ch, &mut index)? {
+                // `3 km` is resolved as `(3 -> "km")`
+                cached_ch = next_ch;
+                last_type = TokenType::Paren;
+                tokens.push_back(Token::Paren(Paren::LeftParen));
+                tokens.push_back(current_token);
+                tokens.push_back(Token::Symbol(Symbols::Convert));
+                tokens.push_back(Token::String(unit));
+                tokens.push_back(Token::Paren(Paren::RightParen));
+                continue;
+            }
             tokens.push_back(current_token);
             continue;
         }
@@ -128,6 +514,10 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
                         return Err(syntax_error(&msg)?);
                     }
                 }
+            } else if let Some(symbol) = Symbols::is_word_symbol(&value) {
+                // bitwise operators: band | bor | bxor | bnot
+                last_type = TokenType::Symbol;
+                tokens.push_back(Token::Symbol(symbol));
             } else {
                 // check is keyword
                 match Keyword::is_keyword(&value) {
@@ -154,6 +544,17 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
             }
 
             // Computing symbols
+            '±' => {
+                last_type = TokenType::Symbol;
+                tokens.push_back(Token::Symbol(Symbols::PlusMinus));
+            }
+            '+' if chars.as_str().starts_with("/-") => {
+                // the ASCII form of `±`: `+/-`
+                chars.nth(1);
+                index += 2;
+                last_type = TokenType::Symbol;
+                tokens.push_back(Token::Symbol(Symbols::PlusMinus));
+            }
             '+' => {
                 if last_type == TokenType::Symbol || last_type == TokenType::Unknown {
                     is_num_minus = false;
@@ -162,6 +563,26 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
                     tokens.push_back(Token::Symbol(Symbols::Plus));
                 }
             }
+            '-' if chars.clone().next() == Some('>') => {
+                // unit converting symbol: `->`
+                chars.next();
+                index += 1;
+
+                // the unit in String literal, e.g. `x -> "km"`
+                let next_ch = chars.clone().find(|ch| *ch != ' ' && *ch != '\t');
+                if let Some('\'' | '\"') = next_ch {
+                    last_type = TokenType::Symbol;
+                    tokens.push_back(Token::Symbol(Symbols::Convert));
+                    continue;
+                }
+
+                let unit: String;
+                (cached_ch, unit) = unit_expr_resolver(&mut chars, &mut index)?;
+                tokens.push_back(Token::Symbol(Symbols::Convert));
+                tokens.push_back(Token::String(unit));
+                last_type = TokenType::String;
+                continue;
+            }
             '-' => {
                 let last_token = tokens.back();
                 if last_type == TokenType::Unknown
@@ -176,6 +597,29 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
                     tokens.push_back(Token::Symbol(Symbols::Minus));
                 }
             }
+            '/' | '<' | '>' if chars.clone().next() == Some(ch) => {
+                // floor division and shift symbols: `//`, `<<` and `>>`
+                chars.next();
+                index += 1;
+
+                let symbol = match ch {
+                    '/' => Symbols::FloorDivide,
+                    '<' => Symbols::ShiftLeft,
+                    _ => Symbols::ShiftRight,
+                };
+                last_type = TokenType::Symbol;
+                tokens.push_back(Token::Symbol(symbol));
+            }
+            '!' if is_operand_end(&tokens) && chars.clone().next() != Some('=') => {
+                // postfix factorial: `5!`
+                last_type = TokenType::Paren;
+                tokens.push_back(Token::Symbol(Symbols::Factorial));
+            }
+            '%' if is_operand_end(&tokens) && !is_operand_start(&chars) => {
+                // postfix percent: `20%`
+                last_type = TokenType::Paren;
+                tokens.push_back(Token::Symbol(Symbols::Percent));
+            }
             '*' | '/' | '%' | '^' | '!' | '<' | '>' | '&' | '|' => {
                 last_type = TokenType::Symbol;
                 tokens.push_back(Token::Symbol(Symbols::from(ch)));
@@ -262,5 +706,5 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
             }
         }
     }
-    return Ok(tokens);
+    return Ok(implicit_multiply(tokens));
 }
@@ -1,7 +1,10 @@
-use crate::public::compile_time::ast::ast_enum::RootNode;
-use crate::public::run_time::scope::Scope;
+use crate::public::compile_time::ast::ast_enum::{ASTNode, RootNode};
+use crate::public::compile_time::ast::types::ExpressionNode;
+use crate::public::run_time::scope::{LocalScope, Scope};
+use crate::public::value::number::Number;
 use crate::public::value::value::Value;
 
+use super::resolvers::invocation::invocation_resolve::function_invoke;
 use super::resolvers::sequence;
 
 pub fn compute(root_node: RootNode, scope: &mut Scope) -> Result<Value, ()> {
@@ -29,3 +32,36 @@ pub fn compute(root_node: RootNode, scope: &mut Scope) -> Result<Value, ()> {
 
     return Ok(result);
 }
+
+// invoke a callable value with Number arguments,
+// used by the build-in functions which receive callback.
+// for Lazy-Expression, the arguments are assigned to
+// the variables named in `lazy_vars` before computing.
+pub fn callback(
+    callable: &Value,
+    args: &[Number],
+    lazy_vars: &[&str],
+    scope: &mut Scope,
+) -> Result<Value, ()> {
+    if let Value::LazyExpression(l_expr) = callable {
+        // the variables of caller are visible in the Lazy-Expression
+        let mut local_scope = scope.caller_local.clone().unwrap_or_else(LocalScope::init);
+        for (var_name, num) in lazy_vars.iter().zip(args) {
+            let value = Value::from(num.clone());
+            local_scope.variables.insert(var_name.to_string(), value);
+        }
+
+        let local_scope_cached = scope.local.replace(local_scope);
+        let result = sequence::resolve(&l_expr.borrow(), scope);
+        scope.local = local_scope_cached;
+        return result;
+    }
+
+    let params = args
+        .iter()
+        .map(|num| ExpressionNode {
+            elements: vec![ASTNode::NumberLiteral(num.clone())],
+        })
+        .collect();
+    return function_invoke(callable.clone(), &params, scope);
+}
@@ -1,8 +1,8 @@
 use crate::public::compile_time::ast::ast_enum::ASTNode;
 use crate::public::compile_time::ast::types::AssignmentNode;
-use crate::public::error::assignment_error;
+use crate::public::error::{assignment_error, type_error};
 use crate::public::run_time::scope::Scope;
-use crate::public::value::value::Value;
+use crate::public::value::value::{Value, ValueType};
 
 use super::composer::{compose, element_reading, object_reading};
 use super::expression;
@@ -26,7 +26,21 @@ pub fn resolve(node: &AssignmentNode, scope: &mut Scope, is_global: bool) -> Res
 
         ASTNode::ElementReading(sub_node) => {
             let target_node = &sub_node.target_node;
-            let target_value = compose::resolve(target_node, scope)?;
+            let target_value = match target_node {
+                ASTNode::ElementReading(row_node) => {
+                    // the row of Matrix is a copy, assigning to it has no effect
+                    let row_target = compose::resolve(&row_node.target_node, scope)?;
+                    if let Value::Matrix(_) = row_target {
+                        return Err(type_error(
+                            Some("element assignment"),
+                            vec![ValueType::Array, ValueType::Map],
+                            ValueType::Matrix,
+                        )?);
+                    }
+                    element_reading::resolve(row_target, &row_node.index_node, scope)?
+                }
+                _ => compose::resolve(target_node, scope)?,
+            };
             element_reading::assign(
                 target_value,
                 &sub_node.index_node,
@@ -1,11 +1,12 @@
-use std::cell::RefMut;
+use std::cell::{Ref, RefMut};
 
 use crate::public::compile_time::ast::types::ExpressionNode;
-use crate::public::error::{assignment_error, range_error, syntax_error};
+use crate::public::error::{assignment_error, range_error, syntax_error, type_error};
 use crate::public::run_time::scope::Scope;
-use crate::public::value::array::RawArray;
+use crate::public::value::array::{ArrayLiteral, RawArray};
 use crate::public::value::map::RawMap;
-use crate::public::value::value::Value;
+use crate::public::value::matrix::Matrix;
+use crate::public::value::value::{Value, ValueType};
 
 use super::super::expression;
 
@@ -27,6 +28,7 @@ fn middle_ware(
     arr_callback: impl Fn(RefMut<RawArray>, usize) -> Result<Value, ()>,
     str_callback: impl Fn(RefMut<String>, usize) -> Result<Value, ()>,
     map_callback: impl Fn(RefMut<RawMap>, &str) -> Result<Value, ()>,
+    mat_callback: impl Fn(Ref<Matrix>, usize) -> Result<Value, ()>,
 ) -> Result<Value, ()> {
     match (&target_value, index_value) {
         (Value::Array(arr), Value::Number(num)) => {
@@ -50,10 +52,18 @@ fn middle_ware(
             let key_str = key_temp.as_str();
             map_callback(map_ref, key_str)
         }
+        (Value::Matrix(mat), Value::Number(num)) => {
+            // matrix
+            let mat_ref = mat.borrow();
+            let index = num.int_value() as usize;
+            check_outof_range(index, mat_ref.rows())?;
+            mat_callback(mat_ref, index)
+        }
         _ => match target_value {
             Value::Array(_) => Err(syntax_error("Array indexing must be Number typed")?),
             Value::String(_) => Err(syntax_error("String indexing must be Number typed")?),
             Value::Map(_) => Err(syntax_error("Map key must be String typed")?),
+            Value::Matrix(_) => Err(syntax_error("Matrix indexing must be Number typed")?),
             _ => Err(syntax_error("invalid indexing")?),
         },
     }
@@ -80,6 +90,11 @@ pub fn resolve(
                 None => Ok(Value::EMPTY),
             }
         },
+        |mat_ref, index| {
+            // the row is read as an Array
+            let row: ArrayLiteral = mat_ref.row(index).into_iter().map(Value::from).collect();
+            Ok(Value::from(row))
+        },
     )?;
     return Ok(result);
 }
@@ -107,6 +122,13 @@ pub fn assign(
             map_ref.set(String::from(key), value.clone());
             Ok(Value::EMPTY)
         },
+        |_, _| {
+            return Err(type_error(
+                Some("element assignment"),
+                vec![ValueType::Array, ValueType::Map],
+                ValueType::Matrix,
+            )?);
+        },
     )?;
     return Ok(());
 }
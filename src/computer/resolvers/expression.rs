@@ -1,12 +1,15 @@
 use crate::public::compile_time::ast::ast_enum::ASTNode;
 use crate::public::compile_time::ast::types::{ExpressionNode, ModuleType};
-use crate::public::error::{internal_error, syntax_error, type_error, InternalComponent};
+use crate::public::error::{
+    internal_error, math_error, syntax_error, type_error, InternalComponent,
+};
 use crate::public::run_time::scope::Scope;
 use crate::public::value::into_rc_refcell;
+use crate::public::value::number::Number;
 use crate::public::value::symbols::Symbols;
 use crate::public::value::value::{Value, ValueType};
 
-use super::operate::operate;
+use super::operate::{operate, postfix_operate};
 use super::{array_literal, assignment, composer::compose, function_definition, instantiation};
 use super::{class_definition, map_literal};
 
@@ -18,7 +21,7 @@ pub fn resolve(node: &ExpressionNode, scope: &mut Scope) -> Result<Value, ()> {
 
     let mut value_stack = Vec::<Value>::new();
 
-    for current_node in elements {
+    for (index, current_node) in elements.iter().enumerate() {
         let current_value = match current_node {
             ASTNode::Expression(node) => resolve(node, scope)?,
 
@@ -59,6 +62,46 @@ pub fn resolve(node: &ExpressionNode, scope: &mut Scope) -> Result<Value, ()> {
                             "operating number is missing for Not operator",
                         )?);
                     }
+                } else if let Symbols::Factorial | Symbols::Percent = sym {
+                    let Some(val) = value_stack.pop() else {
+                        let msg = format!("operating number is missing for {} operator", sym);
+                        return Err(syntax_error(&msg)?);
+                    };
+                    let result = postfix_operate(val, *sym)?;
+
+                    // the percent added to or subtracted from a value
+                    // is relative to the value, e.g. `x + 10%` == `x * 1.1`.
+                    let next_node = elements.get(index + 1);
+                    let is_relative = *sym == Symbols::Percent
+                        && matches!(
+                            next_node,
+                            Some(ASTNode::SymbolLiteral(Symbols::Plus | Symbols::Minus))
+                        );
+                    match value_stack.last() {
+                        Some(base @ (Value::Number(_) | Value::Quantity(_))) if is_relative => {
+                            operate(base.clone(), result, Symbols::Multiply)?
+                        }
+                        _ => result,
+                    }
+                } else if *sym == Symbols::BitNot {
+                    // get last value and expected as integer
+                    let Some(val) = value_stack.pop() else {
+                        return Err(syntax_error(
+                            "operating number is missing for BitNot operator",
+                        )?);
+                    };
+                    match val {
+                        Value::Number(Number::Int(i)) => Value::from(!i),
+                        // !x == -x - 1
+                        Value::Number(num @ Number::BigInt(_)) => {
+                            Value::Number(Number::Int(-1) - num)
+                        }
+                        _ => {
+                            return Err(math_error(
+                                "bitwise operation is only supported for integers",
+                            )?)
+                        }
+                    }
                 } else {
                     if value_stack.len() < 2 {
                         // no enough value for operating
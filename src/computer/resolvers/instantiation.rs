@@ -1,6 +1,7 @@
 use crate::public::compile_time::ast::types::InstantiationNode;
 use crate::public::error::type_error;
 use crate::public::run_time::scope::Scope;
+use crate::public::std::modules::polynomial::PolynomialModule;
 use crate::public::value::oop::class::Class;
 use crate::public::value::oop::object::Object;
 use crate::public::value::value::{Value, ValueType};
@@ -19,5 +20,6 @@ pub fn resolve(node: &InstantiationNode, scope: &mut Scope) -> Result<Object, ()
     };
 
     let instantiation_params = array_literal::resolve(&node.params, scope)?;
+    PolynomialModule::check_instantiation(&target_class, &instantiation_params)?;
     return Class::instantiate(target_class.clone(), instantiation_params);
 }
@@ -15,6 +15,16 @@ fn call(function: &BuildInFunction, scope: &mut Scope) -> Result<Value, ()> {
         BuildInFnIdenti::Map(map_fn) => map_fn.call(scope),
         BuildInFnIdenti::FileSystem(fs_fn) => fs_fn.call(scope),
         BuildInFnIdenti::BitOps(b_ops_fn) => b_ops_fn.call(scope),
+        BuildInFnIdenti::NumTheory(num_theory_fn) => num_theory_fn.call(scope),
+        BuildInFnIdenti::Stats(stats_fn) => stats_fn.call(scope),
+        BuildInFnIdenti::Matrix(matrix_fn) => matrix_fn.call(scope),
+        BuildInFnIdenti::Numeric(numeric_fn) => numeric_fn.call(scope),
+        BuildInFnIdenti::Random(random_fn) => random_fn.call(scope),
+        BuildInFnIdenti::Dist(dist_fn) => dist_fn.call(scope),
+        BuildInFnIdenti::Polynomial(poly_fn) => poly_fn.call(scope),
+        BuildInFnIdenti::Const(const_fn) => const_fn.call(scope),
+        BuildInFnIdenti::Finance(finance_fn) => finance_fn.call(scope),
+        BuildInFnIdenti::Series(series_fn) => series_fn.call(scope),
     }
 }
 
@@ -33,14 +43,15 @@ pub fn invoke(
         expression::resolve,
     )?;
 
-    // cache local scope
-    let mut local_scope_cached = scope.local.take();
+    // cache local scope, the local scope of caller is
+    // kept for the Lazy-Expression callbacks.
+    let local_scope_cached = scope.local.take();
+    let caller_local_cached = std::mem::replace(&mut scope.caller_local, local_scope_cached);
 
     scope.local = Some(local_scope);
-    let fn_result = call(&function, scope)?;
-    scope.local = None;
+    let fn_result = call(&function, scope);
 
-    scope.local = local_scope_cached.take();
+    scope.local = std::mem::replace(&mut scope.caller_local, caller_local_cached);
 
-    Ok(fn_result)
+    fn_result
 }
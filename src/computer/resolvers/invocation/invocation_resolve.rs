@@ -18,7 +18,7 @@ fn variable_invoke(
     return Ok(result);
 }
 
-fn function_invoke(
+pub fn function_invoke(
     function_value: Value,
     params: &Vec<ExpressionNode>,
     scope: &mut Scope,
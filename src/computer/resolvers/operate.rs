@@ -1,23 +1,297 @@
 use crate::public::compile_time::ast::ast_enum::ASTNode;
-use crate::public::error::{internal_error, syntax_error, InternalComponent};
+use crate::public::error::{
+    dimension_error, internal_error, math_error, real_number_error, syntax_error, type_error,
+    InternalComponent,
+};
+use crate::public::std::modules::num_theory::NumTheoryModule;
+use crate::public::std::modules::polynomial::PolynomialModule;
+use crate::public::value::interval::Interval;
+use crate::public::value::matrix::Matrix;
 use crate::public::value::number::Number;
+use crate::public::value::quantity::{
+    convert_value, dim_combine, dim_scale, dim_text, quotient, Quantity, DIMENSIONLESS,
+};
 use crate::public::value::symbols::Symbols;
-use crate::public::value::value::Value;
+use crate::public::value::value::{Value, ValueType};
+
+// matrix arithmetic, scalar operand is only allowed
+// in multiplying, dividing and as the exponent.
+fn matrix_operate(val1: &Value, val2: &Value, operator: Symbols) -> Result<Matrix, ()> {
+    let result = match (val1, val2, operator) {
+        (Value::Matrix(mat1), Value::Matrix(mat2), _) => {
+            let (mat1, mat2) = (mat1.borrow(), mat2.borrow());
+            let result = match operator {
+                Symbols::Plus => mat1.zip_with(&mat2, |num1, num2| num1 + num2),
+                Symbols::Minus => mat1.zip_with(&mat2, |num1, num2| num1 - num2),
+                Symbols::Multiply => mat1.mul(&mat2),
+                _ => return Err(syntax_error("invalid computing expression")?),
+            };
+            let Some(result) = result else {
+                let msg = format!(
+                    "matrix shapes {}x{} and {}x{} do not match for {} operation",
+                    mat1.rows(),
+                    mat1.cols(),
+                    mat2.rows(),
+                    mat2.cols(),
+                    operator
+                );
+                return Err(math_error(&msg)?);
+            };
+            result
+        }
+        (Value::Number(num), Value::Matrix(mat), Symbols::Multiply)
+        | (Value::Matrix(mat), Value::Number(num), Symbols::Multiply) => {
+            mat.borrow().map(|element| num.clone() * element)
+        }
+        (Value::Matrix(mat), Value::Number(num), Symbols::Divide) => {
+            if num.is_zero() {
+                return Err(math_error("the divisor should not to be ZERO")?);
+            }
+            mat.borrow().map(|element| element.true_div(num))
+        }
+        (Value::Matrix(mat), Value::Number(num), Symbols::Power) => {
+            let mat = mat.borrow();
+            let Number::Int(exp) = num else {
+                return Err(math_error("matrix can only be raised to an integer power")?);
+            };
+            if !mat.is_square() {
+                return Err(math_error("only square matrix can be raised to a power")?);
+            }
+            let Some(result) = mat.pow(*exp) else {
+                return Err(math_error(
+                    "singular matrix can not be raised to a negative power",
+                )?);
+            };
+            result
+        }
+        _ => return Err(syntax_error("invalid computing expression")?),
+    };
+    return Ok(result);
+}
+
+// physical quantity arithmetic, Number operand is regarded as dimensionless,
+// and the result without dimension is converted back to Number.
+fn quantity_operate(val1: &Value, val2: &Value, operator: Symbols) -> Result<Value, ()> {
+    let to_quantity = |value: &Value| match value {
+        Value::Number(num) => Quantity::from_si(num.clone(), DIMENSIONLESS, None),
+        Value::Quantity(qty) => qty.clone(),
+        _ => unreachable!(),
+    };
+    let (qty1, qty2) = (to_quantity(val1), to_quantity(val2));
+    let (num1, num2) = (qty1.value().clone(), qty2.value().clone());
+    let (dim1, dim2) = (qty1.dim(), qty2.dim());
+    // the display unit of the result is taken from the left operand,
+    // or the only Quantity operand when scaled by Number.
+    let (unit1, unit2) = (qty1.unit().cloned(), qty2.unit().cloned());
+
+    let check_dimension = || {
+        if dim1 != dim2 {
+            return dimension_error(&dim_text(dim1), &dim_text(dim2));
+        }
+        return Ok(());
+    };
+
+    let result = match operator {
+        Symbols::Plus | Symbols::Minus => {
+            check_dimension()?;
+            let value = match operator {
+                Symbols::Plus => num1 + num2,
+                _ => num1 - num2,
+            };
+            Quantity::from_si(value, *dim1, unit1.or(unit2))
+        }
+        Symbols::Multiply => {
+            let unit = match (val1, val2) {
+                (Value::Number(_), _) => unit2,
+                (_, Value::Number(_)) => unit1,
+                _ => None,
+            };
+            Quantity::from_si(num1 * num2, dim_combine(dim1, dim2, 1), unit)
+        }
+        Symbols::Divide => {
+            let unit = match val2 {
+                Value::Number(_) => unit1,
+                _ => None,
+            };
+            if num2.is_zero() {
+                return Err(math_error("the divisor should not to be ZERO")?);
+            }
+            Quantity::from_si(quotient(num1, &num2), dim_combine(dim1, dim2, -1), unit)
+        }
+        Symbols::Power => {
+            let Value::Number(Number::Int(exp)) = val2 else {
+                return Err(math_error(
+                    "quantity can only be raised to an integer power",
+                )?);
+            };
+            let Ok(exp) = i8::try_from(*exp) else {
+                return Err(math_error("the exponent of quantity is too large")?);
+            };
+            let unit = unit1.filter(|_| exp == 1);
+            let value = match exp < 0 {
+                true => quotient(Number::Int(1), &Number::pow(num1, Number::Int(-exp as i64))),
+                false => Number::pow(num1, Number::Int(exp as i64)),
+            };
+            Quantity::from_si(value, dim_scale(dim1, exp), unit)
+        }
+        Symbols::LessThan | Symbols::MoreThan | Symbols::LessThanEqual | Symbols::MoreThanEqual => {
+            check_dimension()?;
+            let result = match operator {
+                Symbols::LessThan => num1 < num2,
+                Symbols::MoreThan => num1 > num2,
+                Symbols::LessThanEqual => num1 <= num2,
+                _ => num1 >= num2,
+            };
+            return Ok(Value::from(result));
+        }
+        _ => return Err(syntax_error("invalid computing expression")?),
+    };
+
+    if *result.dim() == DIMENSIONLESS {
+        return Ok(Value::from(result.value().clone()));
+    }
+    return Ok(Value::from(result));
+}
+
+// interval arithmetic, Number operand is regarded as the interval
+// containing only itself, and the display style is taken from the left operand.
+fn interval_operate(val1: &Value, val2: &Value, operator: Symbols) -> Result<Interval, ()> {
+    if let (Value::Number(Number::Complex(_)), _) | (_, Value::Number(Number::Complex(_))) =
+        (val1, val2)
+    {
+        return Err(real_number_error("interval operand", "Complex")?);
+    }
+    let (itv1, itv2) = match (val1, val2) {
+        (Value::Interval(itv1), Value::Interval(itv2)) => (*itv1, *itv2),
+        (Value::Interval(itv), Value::Number(num)) => (*itv, Interval::from_number(num)),
+        (Value::Number(num), Value::Interval(itv)) => {
+            (Interval::from_number(num).with_style(itv), *itv)
+        }
+        _ => unreachable!(),
+    };
+
+    let result = match operator {
+        Symbols::Plus => itv1.add(&itv2),
+        Symbols::Minus => itv1.sub(&itv2),
+        Symbols::Multiply => itv1.mul(&itv2),
+        Symbols::Divide => itv1.div(&itv2)?,
+        Symbols::Power => match val2 {
+            Value::Number(Number::Int(exp)) => itv1.powi(*exp)?,
+            _ => {
+                if itv1.lower() <= 0.0 {
+                    return Err(math_error(
+                        "the base interval should be positive for non-integer exponent",
+                    )?);
+                }
+                // x ^ y == exp(y * ln(x))
+                let ln_base = itv1.monotonic(f64::ln, true);
+                itv2.mul(&ln_base)
+                    .monotonic(f64::exp, true)
+                    .with_style(&itv1)
+            }
+        },
+        _ => return Err(syntax_error("invalid computing expression")?),
+    };
+    return Ok(result);
+}
+
+// bitwise operating of integers, big integers are in two's complement like `bnot`.
+fn bitwise_operate(num1: Number, num2: Number, operator: Symbols) -> Result<Value, ()> {
+    let (Number::Int(_) | Number::BigInt(_), Number::Int(_) | Number::BigInt(_)) = (&num1, &num2)
+    else {
+        return Err(math_error(
+            "bitwise operation is only supported for integers",
+        )?);
+    };
+
+    let result = match (num1, num2, operator) {
+        (num, bits, Symbols::ShiftLeft | Symbols::ShiftRight) => {
+            if bits < Number::Int(0) {
+                return Err(math_error("the shift bits should not be negative")?);
+            }
+            // the shift bits of BigInt are too large anyway
+            let bits = match bits {
+                Number::Int(i) => i as u64,
+                _ => u64::MAX,
+            };
+            match operator {
+                Symbols::ShiftLeft => match Number::shift_left(&num, bits) {
+                    Some(result) => result,
+                    None => return Err(math_error("the result of shifting is too large")?),
+                },
+                _ => Number::shift_right(&num, bits),
+            }
+        }
+        (Number::Int(i1), Number::Int(i2), _) => match operator {
+            Symbols::BitAnd => Number::Int(i1 & i2),
+            Symbols::BitOr => Number::Int(i1 | i2),
+            Symbols::BitXor => Number::Int(i1 ^ i2),
+            _ => unreachable!(),
+        },
+        (num1, num2, _) => {
+            let op: fn(u32, u32) -> u32 = match operator {
+                Symbols::BitAnd => |l1, l2| l1 & l2,
+                Symbols::BitOr => |l1, l2| l1 | l2,
+                Symbols::BitXor => |l1, l2| l1 ^ l2,
+                _ => unreachable!(),
+            };
+            Number::bitwise(&num1, &num2, op)
+        }
+    };
+    return Ok(Value::Number(result));
+}
+
+// postfix factorial and percent operating, e.g. `5!` and `20%`
+pub fn postfix_operate(val: Value, operator: Symbols) -> Result<Value, ()> {
+    let Value::Number(num) = val else {
+        let operator_name = format!("{} operator", operator);
+        return Err(type_error(
+            Some(&operator_name),
+            vec![ValueType::Number],
+            val.get_type(),
+        )?);
+    };
+
+    let result = match operator {
+        Symbols::Factorial => {
+            let Number::Int(input) = num else {
+                return Err(math_error("the input of factorial should be an integer")?);
+            };
+            NumTheoryModule::factorial(input)?
+        }
+        Symbols::Percent => quotient(num, &Number::Int(100)),
+        _ => unreachable!(),
+    };
+    return Ok(Value::Number(result));
+}
 
 pub fn operate(val1: Value, val2: Value, operator: Symbols) -> Result<Value, ()> {
     let result = match (&val1, &val2, operator) {
+        (Value::Number(mid), Value::Number(err), Symbols::PlusMinus) => {
+            // interval creating: `mid ± err`
+            Value::from(Interval::from_error(mid, err)?)
+        }
         (Value::Number(num1_ref), Value::Number(num2_ref), _) => {
             // number computing and comparing
-            let num1 = *num1_ref;
-            let num2 = *num2_ref;
+            let num1 = num1_ref.clone();
+            let num2 = num2_ref.clone();
 
             match operator {
                 Symbols::Plus => Value::Number(num1 + num2),
                 Symbols::Minus => Value::Number(num1 - num2),
                 Symbols::Multiply => Value::Number(num1 * num2),
                 Symbols::Divide => Value::Number(num1 / num2),
+                Symbols::FloorDivide => Value::Number(Number::floor_div(num1, num2)),
                 Symbols::Mod => Value::Number(Number::modulo(num1, num2)),
                 Symbols::Power => Value::Number(Number::pow(num1, num2)),
+                Symbols::LessThan
+                | Symbols::MoreThan
+                | Symbols::LessThanEqual
+                | Symbols::MoreThanEqual
+                    if matches!(num1, Number::Complex(_)) || matches!(num2, Number::Complex(_)) =>
+                {
+                    return Err(math_error("complex numbers have no ordering")?);
+                }
                 Symbols::LessThan => Value::from(num1 < num2),
                 Symbols::MoreThan => Value::from(num1 > num2),
                 Symbols::NotEqual => Value::from(num1 != num2),
@@ -26,12 +300,75 @@ pub fn operate(val1: Value, val2: Value, operator: Symbols) -> Result<Value, ()>
                 Symbols::MoreThanEqual => Value::from(num1 >= num2),
                 Symbols::AndSign => Value::from(num1.int_value() != 0 && num2.int_value() != 0),
                 Symbols::OrSign => Value::from(num1.int_value() != 0 || num2.int_value() != 0),
+                Symbols::BitAnd
+                | Symbols::BitOr
+                | Symbols::BitXor
+                | Symbols::ShiftLeft
+                | Symbols::ShiftRight => bitwise_operate(num1, num2, operator)?,
                 _ => {
                     let msg = format!("unexpected symbol `{}` for operating", operator);
                     return Err(internal_error(InternalComponent::Computer, &msg)?);
                 }
             }
         }
+        (Value::Number(_) | Value::Quantity(_), Value::String(unit_str), Symbols::Convert) => {
+            // unit attaching and converting
+            convert_value(&val1, &unit_str.borrow())?
+        }
+        (
+            Value::Quantity(_),
+            Value::Number(_) | Value::Quantity(_),
+            Symbols::Plus
+            | Symbols::Minus
+            | Symbols::Multiply
+            | Symbols::Divide
+            | Symbols::Power
+            | Symbols::LessThan
+            | Symbols::MoreThan
+            | Symbols::LessThanEqual
+            | Symbols::MoreThanEqual,
+        )
+        | (
+            Value::Number(_),
+            Value::Quantity(_),
+            Symbols::Plus
+            | Symbols::Minus
+            | Symbols::Multiply
+            | Symbols::Divide
+            | Symbols::LessThan
+            | Symbols::MoreThan
+            | Symbols::LessThanEqual
+            | Symbols::MoreThanEqual,
+        ) => {
+            // quantity computing
+            quantity_operate(&val1, &val2, operator)?
+        }
+        (
+            Value::Interval(_),
+            Value::Number(_) | Value::Interval(_),
+            Symbols::Plus | Symbols::Minus | Symbols::Multiply | Symbols::Divide | Symbols::Power,
+        )
+        | (
+            Value::Number(_),
+            Value::Interval(_),
+            Symbols::Plus | Symbols::Minus | Symbols::Multiply | Symbols::Divide | Symbols::Power,
+        ) => {
+            // interval computing
+            Value::from(interval_operate(&val1, &val2, operator)?)
+        }
+        (
+            Value::Interval(_),
+            Value::Number(_) | Value::Interval(_),
+            Symbols::LessThan | Symbols::MoreThan | Symbols::LessThanEqual | Symbols::MoreThanEqual,
+        )
+        | (
+            Value::Number(_),
+            Value::Interval(_),
+            Symbols::LessThan | Symbols::MoreThan | Symbols::LessThanEqual | Symbols::MoreThanEqual,
+        ) => {
+            // the overlapping intervals are neither less nor greater
+            return Err(math_error("intervals have no ordering")?);
+        }
         (Value::String(str_ref), _, Symbols::Plus) => {
             // stringify computing
             let mut str_cloned = str_ref.borrow().clone();
@@ -95,6 +432,28 @@ pub fn operate(val1: Value, val2: Value, operator: Symbols) -> Result<Value, ()>
 
             Value::from(new_lazy_expr)
         }
+        (
+            Value::Matrix(_),
+            Value::Matrix(_) | Value::Number(_),
+            Symbols::Plus | Symbols::Minus | Symbols::Multiply | Symbols::Divide | Symbols::Power,
+        )
+        | (Value::Number(_), Value::Matrix(_), Symbols::Multiply) => {
+            // matrix computing
+            Value::from(matrix_operate(&val1, &val2, operator)?)
+        }
+        (
+            Value::Object(_),
+            Value::Object(_) | Value::Number(_),
+            Symbols::Plus | Symbols::Minus | Symbols::Multiply | Symbols::Divide | Symbols::Power,
+        )
+        | (
+            Value::Number(_),
+            Value::Object(_),
+            Symbols::Plus | Symbols::Minus | Symbols::Multiply,
+        ) if PolynomialModule::is_instance(&val1) || PolynomialModule::is_instance(&val2) => {
+            // polynomial computing
+            PolynomialModule::operate(&val1, &val2, operator)?
+        }
         (_, _, Symbols::NotEqual | Symbols::CompareEqual | Symbols::AndSign | Symbols::OrSign) =>
         // all typed value comparing
         {
@@ -106,6 +465,70 @@ pub fn operate(val1: Value, val2: Value, operator: Symbols) -> Result<Value, ()>
                 _ => unreachable!(),
             }
         }
+        (Value::Interval(_), _, _) | (_, Value::Interval(_), _) => {
+            // the interval with other typed value, e.g. Quantity,
+            // or the operators not supported by interval, e.g. `//` and `%`.
+            let operator_name = format!("{} operator", operator);
+            let other = if let Value::Interval(_) = val1 {
+                &val2
+            } else {
+                &val1
+            };
+            let (expected, found) = match operator {
+                Symbols::Plus
+                | Symbols::Minus
+                | Symbols::Multiply
+                | Symbols::Divide
+                | Symbols::Power
+                | Symbols::LessThan
+                | Symbols::MoreThan
+                | Symbols::LessThanEqual
+                | Symbols::MoreThanEqual => (
+                    vec![ValueType::Number, ValueType::Interval],
+                    other.get_type(),
+                ),
+                _ => (vec![ValueType::Number], ValueType::Interval),
+            };
+            return Err(type_error(Some(&operator_name), expected, found)?);
+        }
+        (Value::Quantity(_), _, _) | (_, Value::Quantity(_), _) => {
+            // the quantity with other typed value, the exponent of quantity,
+            // or the operators not supported by quantity, e.g. `//` and `%`.
+            let operator_name = format!("{} operator", operator);
+            let (expected, found) = match (&val1, operator) {
+                (
+                    Value::Quantity(_),
+                    Symbols::Plus
+                    | Symbols::Minus
+                    | Symbols::Multiply
+                    | Symbols::Divide
+                    | Symbols::LessThan
+                    | Symbols::MoreThan
+                    | Symbols::LessThanEqual
+                    | Symbols::MoreThanEqual,
+                ) => (
+                    vec![ValueType::Number, ValueType::Quantity],
+                    val2.get_type(),
+                ),
+                (Value::Quantity(_), Symbols::Power) => (vec![ValueType::Number], val2.get_type()),
+                (
+                    _,
+                    Symbols::Plus
+                    | Symbols::Minus
+                    | Symbols::Multiply
+                    | Symbols::Divide
+                    | Symbols::LessThan
+                    | Symbols::MoreThan
+                    | Symbols::LessThanEqual
+                    | Symbols::MoreThanEqual,
+                ) => (
+                    vec![ValueType::Number, ValueType::Quantity],
+                    val1.get_type(),
+                ),
+                _ => (vec![ValueType::Number], ValueType::Quantity),
+            };
+            return Err(type_error(Some(&operator_name), expected, found)?);
+        }
         _ => return Err(syntax_error("invalid computing expression")?),
     };
     return Ok(result);
@@ -6,20 +6,29 @@ pub enum CommandArg {
     Help,
     Timer,
     Headfile,
+    Exact,
+    Display,
+    Angle,
 }
 
-pub const COMMAND_COUNT: usize = 4;
+pub const COMMAND_COUNT: usize = 7;
 pub const COMMANDS: [[&'static str; 2]; COMMAND_COUNT] = [
     ["-v", "--version"],
     ["-h", "--help"],
     ["-t", "--timer"],
     ["-hf", "--headfile"],
+    ["-e", "--exact"],
+    ["-d", "--display"],
+    ["-a", "--angle"],
 ];
 pub const COMMAND_DESCRIPTIONS: [&'static str; COMMAND_COUNT] = [
     "print current executable file version and exit.",
     "print this help message.",
     "print extra execute duration message code execution.",
     "directly import variables in head files, must with script paths following.",
+    "enable exact mode, integer division and negative integer power result in fraction.",
+    "set number display mode with following mode spec, e.g. `fixed:2`, `sig:6`, `sci`, `eng`, `mixed`, `hex`.",
+    "set angle mode of trigonometric functions with following mode, `rad`, `deg` or `grad`.",
 ];
 
 impl CommandArg {
@@ -33,6 +42,12 @@ impl CommandArg {
             (COMMANDS[2][1], CommandArg::Timer),
             (COMMANDS[3][0], CommandArg::Headfile),
             (COMMANDS[3][1], CommandArg::Headfile),
+            (COMMANDS[4][0], CommandArg::Exact),
+            (COMMANDS[4][1], CommandArg::Exact),
+            (COMMANDS[5][0], CommandArg::Display),
+            (COMMANDS[5][1], CommandArg::Display),
+            (COMMANDS[6][0], CommandArg::Angle),
+            (COMMANDS[6][1], CommandArg::Angle),
         ])
     }
 }
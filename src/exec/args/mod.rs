@@ -3,8 +3,9 @@ pub mod commands;
 use std::{collections::VecDeque, io, process};
 
 use crate::public::{
-    env::{Env, ENV_OPTION},
+    env::{AngleMode, Env, ENV_OPTION},
     run_time::scope::Scope,
+    value::number_format::DisplayMode,
 };
 
 use super::{headfile, repl::repl, script};
@@ -33,6 +34,27 @@ fn args_resolve(
         if let Some(command) = command_map.get::<str>(&current_arg) {
             match command {
                 CommandArg::Timer => unsafe { ENV_OPTION.timer = true },
+                CommandArg::Exact => unsafe { ENV_OPTION.exact_mode = true },
+                CommandArg::Display => {
+                    let spec = args.pop_front().unwrap_or_default();
+                    match DisplayMode::parse(&spec) {
+                        Some(mode) => unsafe { ENV_OPTION.display_mode = mode },
+                        None => {
+                            println!("Invalid display mode: {}.", spec);
+                            process::exit(0);
+                        }
+                    }
+                }
+                CommandArg::Angle => {
+                    let spec = args.pop_front().unwrap_or_default();
+                    match AngleMode::parse(&spec) {
+                        Some(mode) => unsafe { ENV_OPTION.angle_mode = mode },
+                        None => {
+                            println!("Invalid angle mode: {}.", spec);
+                            process::exit(0);
+                        }
+                    }
+                }
                 CommandArg::Help => {
                     calc_env.help_output();
                     process::exit(0);
@@ -6,15 +6,68 @@ use std::time::Instant;
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 
 use super::attempt::attempt;
-use crate::public::env::{Env, ENV_OPTION};
-use crate::public::error::{import_error, syntax_error};
+use crate::public::env::{AngleMode, Env, ENV_OPTION};
+use crate::public::error::{import_error, syntax_error, type_error};
 use crate::public::run_time::scope::Scope;
-use crate::public::value::value::Value;
+use crate::public::value::value::{Value, ValueType};
 use crate::utils::completer::Completer;
 use crate::utils::line_editor::{LineEditor, Signal};
 use crate::utils::print_line;
 
-const PROMPT: &'static str = "> ";
+// the prompt with current angle mode, e.g. `[deg] > `
+fn prompt() -> String {
+    let angle_mode = unsafe { ENV_OPTION.angle_mode };
+    return format!("[{}] > ", angle_mode);
+}
+
+// outputs the description and the value of the variable or property,
+// e.g. `:help Const.h`
+fn help_resolve(name: &str, scope: &Scope) -> Result<(), ()> {
+    let mut path = name.split('.');
+    let mut value = scope.read_var(path.next().unwrap().trim())?;
+    let mut description = None;
+    for prop_name in path {
+        let prop_name = prop_name.trim();
+        let Value::Object(obj) = &value else {
+            return Err(type_error(
+                Some(name),
+                vec![ValueType::Object],
+                value.get_type(),
+            )?);
+        };
+        let prop_value = obj.as_ref().borrow().get(prop_name)?;
+        description = obj
+            .as_ref()
+            .borrow()
+            .get_completer()
+            .and_then(|completer| completer.description(prop_name).map(String::from));
+        value = prop_value;
+    }
+
+    if let Some(description) = description {
+        print_line(description);
+    }
+    print!("= ");
+    print_line(value);
+    return Ok(());
+}
+
+// the REPL commands start with `:`, e.g. `:deg`
+fn command_resolve(command: &str, scope: &Scope) {
+    if let Some(name) = command.trim().strip_prefix("help ") {
+        // the error is output when it is raised
+        help_resolve(name.trim(), scope).unwrap_or(());
+        return;
+    }
+
+    match AngleMode::parse(command) {
+        Some(mode) => unsafe { ENV_OPTION.angle_mode = mode },
+        None => {
+            let msg = format!("unknown REPL command `:{}`", command.trim());
+            syntax_error(&msg).unwrap_err();
+        }
+    }
+}
 
 fn import_all(scope: &mut Scope) -> Result<(), ()> {
     scope.import_std("Basic")?;
@@ -24,6 +77,16 @@ fn import_all(scope: &mut Scope) -> Result<(), ()> {
     scope.import_std("Map")?;
     scope.import_std("FS")?;
     scope.import_std("BitOps")?;
+    scope.import_std("NumTheory")?;
+    scope.import_std("Stats")?;
+    scope.import_std("Matrix")?;
+    scope.import_std("Numeric")?;
+    scope.import_std("Random")?;
+    scope.import_std("Dist")?;
+    scope.import_std("Polynomial")?;
+    scope.import_std("Const")?;
+    scope.import_std("Finance")?;
+    scope.import_std("Series")?;
     Ok(())
 }
 
@@ -54,9 +117,11 @@ pub fn repl(scope: &mut Scope, calc_env: Env) -> io::Result<()> {
 
     enable_raw_mode()?;
 
-    let mut le = LineEditor::new(PROMPT);
+    let mut le = LineEditor::new(prompt());
     loop {
         support_keyboard_enhancement::resolve()?;
+        // the angle mode may be changed by the last line
+        le.set_prompt(prompt());
 
         let sig = le.readline(scope)?;
         let line_content = match sig {
@@ -68,6 +133,11 @@ pub fn repl(scope: &mut Scope, calc_env: Env) -> io::Result<()> {
             }
         };
 
+        if let Some(command) = line_content.trim().strip_prefix(':') {
+            command_resolve(command, scope);
+            continue;
+        }
+
         let result: Result<Value, ()>;
         if unsafe { ENV_OPTION.timer } {
             let now = Instant::now();
@@ -1,19 +1,151 @@
 use std::collections::VecDeque;
+use std::fmt;
 
 use crate::exec::args::commands;
+use crate::public::value::number_format::DisplayMode;
 
 pub struct EnvOption {
     pub timer: bool,
     pub is_repl: bool,
     pub support_ansi: bool,
+
+    // when enabled, integer division and
+    // negative integer power result in fraction.
+    pub exact_mode: bool,
+    pub display_mode: DisplayMode,
+    pub angle_mode: AngleMode,
+    // the significant digits of decimal numbers,
+    // ZERO means the decimal mode is disabled.
+    pub decimal_precision: usize,
+    // the rounding of money amounts in Finance module
+    pub rounding_mode: RoundingMode,
 }
 
 pub static mut ENV_OPTION: EnvOption = EnvOption {
     timer: false,
     is_repl: false,
     support_ansi: false,
+
+    exact_mode: false,
+    display_mode: DisplayMode::Default,
+    angle_mode: AngleMode::Radian,
+    decimal_precision: 0,
+    rounding_mode: RoundingMode::HalfUp,
 };
 
+// the unit of the angles in trigonometric functions
+#[derive(PartialEq, Clone, Copy)]
+pub enum AngleMode {
+    Radian,
+    Degree,
+    Gradian,
+}
+
+impl AngleMode {
+    pub fn parse(spec: &str) -> Option<Self> {
+        let mode = match spec.trim() {
+            "rad" | "radian" => Self::Radian,
+            "deg" | "degree" => Self::Degree,
+            "grad" | "gradian" => Self::Gradian,
+            _ => return None,
+        };
+        return Some(mode);
+    }
+
+    // the angle of a quarter turn
+    fn quarter_turn(&self) -> f64 {
+        match self {
+            Self::Radian => std::f64::consts::FRAC_PI_2,
+            Self::Degree => 90.0,
+            Self::Gradian => 100.0,
+        }
+    }
+    pub fn to_radians(self, angle: f64) -> f64 {
+        match self {
+            Self::Radian => angle,
+            _ => angle / self.quarter_turn() * std::f64::consts::FRAC_PI_2,
+        }
+    }
+    pub fn to_angle(self, radians: f64) -> f64 {
+        match self {
+            Self::Radian => radians,
+            _ => radians / std::f64::consts::FRAC_PI_2 * self.quarter_turn(),
+        }
+    }
+    // the count of quarter turns modulo 4 when the angle is
+    // exactly a multiple of quarter turn, used to get the exact
+    // trigonometric values in degree and gradian mode.
+    pub fn quarter_turns(&self, angle: f64) -> Option<u8> {
+        if *self == Self::Radian {
+            return None;
+        }
+        let turns = angle / self.quarter_turn();
+        if turns.fract() != 0.0 || !turns.is_finite() {
+            return None;
+        }
+        return Some(turns.rem_euclid(4.0) as u8);
+    }
+}
+
+impl fmt::Display for AngleMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Radian => write!(f, "rad"),
+            Self::Degree => write!(f, "deg"),
+            Self::Gradian => write!(f, "grad"),
+        }
+    }
+}
+
+// the rounding direction to the nearest multiple of unit
+#[derive(PartialEq, Clone, Copy)]
+pub enum RoundingMode {
+    // the ties are rounded away from ZERO | toward ZERO | to the even one
+    HalfUp,
+    HalfDown,
+    HalfEven,
+    // away from ZERO | toward ZERO
+    Up,
+    Down,
+    // toward positive | negative infinity
+    Ceiling,
+    Floor,
+    // the exact result without rounding
+    Unrounded,
+}
+
+impl RoundingMode {
+    pub fn parse(spec: &str) -> Option<Self> {
+        let mode = match spec.trim() {
+            "half_up" => Self::HalfUp,
+            "half_down" => Self::HalfDown,
+            "half_even" => Self::HalfEven,
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "ceil" => Self::Ceiling,
+            "floor" => Self::Floor,
+            "none" => Self::Unrounded,
+            _ => return None,
+        };
+        return Some(mode);
+    }
+}
+
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HalfUp => write!(f, "half_up"),
+            Self::HalfDown => write!(f, "half_down"),
+            Self::HalfEven => write!(f, "half_even"),
+            Self::Up => write!(f, "up"),
+            Self::Down => write!(f, "down"),
+            Self::Ceiling => write!(f, "ceil"),
+            Self::Floor => write!(f, "floor"),
+            Self::Unrounded => write!(f, "none"),
+        }
+    }
+}
+
 // --- --- --- --- --- ---
 
 pub struct Env {
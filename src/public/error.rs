@@ -7,6 +7,32 @@ use crate::utils::print_line;
 
 use super::value::value::ValueType;
 
+// the errors are not printed when enabled,
+// e.g. the sample points in solving equations.
+static mut SILENT: bool = false;
+
+// calls `f` with the errors not printed
+pub fn silently<T>(f: impl FnOnce() -> T) -> T {
+    let cached = unsafe { SILENT };
+    unsafe { SILENT = true };
+    let result = f();
+    unsafe { SILENT = cached };
+    return result;
+}
+
+macro_rules! output {
+    ($($arg:tt)*) => {
+        if !unsafe { SILENT } {
+            print!($($arg)*);
+        }
+    };
+}
+fn output_line<T: Display>(content: T) {
+    if !unsafe { SILENT } {
+        print_line(content);
+    }
+}
+
 type ErrorResult = Result<(), ()>;
 fn error_name_output(name: &str) -> StyledContent<&str> {
     name.white().on_red().bold()
@@ -30,30 +56,51 @@ pub fn type_error(param: Option<&str>, expected: Vec<ValueType>, found: ValueTyp
         return res_string;
     }
 
-    print!("{}", error_name_output(TYPE_ERROR_NAME));
+    output!("{}", error_name_output(TYPE_ERROR_NAME));
     if let Some(name) = param {
-        print!(" for \"{}\"", name);
+        output!(" for \"{}\"", name);
     }
-    print_line(format!(": expected {}, found {}.", join(expected), found));
+    output_line(format!(": expected {}, found {}.", join(expected), found));
+    return Err(());
+}
+
+// the dimensions of physical quantities do not match
+pub fn dimension_error(expected: &str, found: &str) -> ErrorResult {
+    output_line(format!(
+        "{}: dimension mismatch, expected `{}`, found `{}`.",
+        error_name_output(TYPE_ERROR_NAME),
+        expected,
+        found
+    ));
+    return Err(());
+}
+
+// the complex or undefined Number where real Number is expected
+pub fn real_number_error(param: &str, found: &str) -> ErrorResult {
+    output!("{}", error_name_output(TYPE_ERROR_NAME));
+    output_line(format!(
+        " for \"{}\": expected real Number, found {}.",
+        param, found
+    ));
     return Err(());
 }
 
 const MATH_ERROR_NAME: &'static str = " MathError ";
 pub fn math_error(msg: &str) -> ErrorResult {
-    print_line(format!("{}: {}.", error_name_output(MATH_ERROR_NAME), msg));
+    output_line(format!("{}: {}.", error_name_output(MATH_ERROR_NAME), msg));
     return Err(());
 }
 
 const RANGE_ERROR_NAME: &'static str = " RangeError ";
 pub fn range_error<T: Display>(param: &str, expected: T, found: usize) -> ErrorResult {
-    print!("{} for \"{}\"", error_name_output(RANGE_ERROR_NAME), param);
-    print_line(format!(": expected {}, found {}.", expected, found));
+    output!("{} for \"{}\"", error_name_output(RANGE_ERROR_NAME), param);
+    output_line(format!(": expected {}, found {}.", expected, found));
     return Err(());
 }
 
 const SYNTAX_ERROR_NAME: &'static str = " SyntaxError ";
 pub fn syntax_error(msg: &str) -> ErrorResult {
-    print_line(format!(
+    output_line(format!(
         "{}: {}.\r",
         error_name_output(SYNTAX_ERROR_NAME),
         msg
@@ -63,7 +110,7 @@ pub fn syntax_error(msg: &str) -> ErrorResult {
 
 const ASSIGNMENT_ERROR_NAME: &'static str = " SyntaxError ";
 pub fn assignment_error(msg: &str) -> ErrorResult {
-    print_line(format!(
+    output_line(format!(
         "{}: {}.",
         error_name_output(ASSIGNMENT_ERROR_NAME),
         msg
@@ -77,7 +124,7 @@ pub enum ReferenceType {
     Property,
 }
 pub fn reference_error(type__: ReferenceType, target_name: &str) -> ErrorResult {
-    print_line(format!(
+    output_line(format!(
         "{}: {} `{}` is not defined.",
         error_name_output(REFERENCE_ERROR_NAME),
         match type__ {
@@ -91,7 +138,7 @@ pub fn reference_error(type__: ReferenceType, target_name: &str) -> ErrorResult
 
 const IMPORT_ERROR_NAME: &'static str = " ImportError ";
 pub fn import_error(msg: &str) -> ErrorResult {
-    print_line(format!(
+    output_line(format!(
         "{}: {}.",
         error_name_output(IMPORT_ERROR_NAME),
         msg
@@ -124,7 +171,7 @@ impl fmt::Display for InternalComponent {
 
 const INTERNAL_ERROR_NAME: &'static str = " InternalError ";
 pub fn internal_error(from: InternalComponent, msg: &str) -> ErrorResult {
-    print_line(format!(
+    output_line(format!(
         "{} from {}: {}.",
         error_name_output(INTERNAL_ERROR_NAME),
         from,
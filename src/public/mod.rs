@@ -12,4 +12,7 @@ use value::value::ValueType;
 pub trait Param {
     fn type__(&self) -> ValueType;
     fn identi(&self) -> &str;
+    fn is_optional(&self) -> bool {
+        false
+    }
 }
@@ -1,6 +1,9 @@
 use crate::public::std::modules::{
-    array::ArrayModule, basic::BasicModule, bit_ops::BitOpsModule, file_system::FileSysModule,
-    map::MapModule, math::MathModule, string::StringModule,
+    array::ArrayModule, basic::BasicModule, bit_ops::BitOpsModule, consts::ConstModule,
+    dist::DistModule, file_system::FileSysModule, finance::FinanceModule, map::MapModule,
+    math::MathModule, matrix::MatrixModule, num_theory::NumTheoryModule, numeric::NumericModule,
+    polynomial::PolynomialModule, random::RandomModule, series::SeriesModule, stats::StatsModule,
+    string::StringModule,
 };
 
 #[derive(PartialEq, Clone)]
@@ -12,4 +15,14 @@ pub enum BuildInFnIdenti {
     Map(MapModule),
     FileSystem(FileSysModule),
     BitOps(BitOpsModule),
+    NumTheory(NumTheoryModule),
+    Stats(StatsModule),
+    Matrix(MatrixModule),
+    Numeric(NumericModule),
+    Random(RandomModule),
+    Dist(DistModule),
+    Polynomial(PolynomialModule),
+    Const(ConstModule),
+    Finance(FinanceModule),
+    Series(SeriesModule),
 }
@@ -2,22 +2,39 @@ use std::collections::HashMap;
 use std::f64::consts::E as STD_E;
 use std::f64::consts::PI as STD_PI;
 
+use crate::public::value::big_decimal::BigDecimal;
+use crate::public::value::complex::Complex;
 use crate::public::value::unique::EMPTY_GLOBAL_UNIQUE;
 use crate::public::value::{number::Number, unique::GlobalUnique, value::Value};
 
 pub const PI: Value = Value::Number(Number::Float(STD_PI));
 pub const E: Value = Value::Number(Number::Float(STD_E));
+pub const I: Value = Value::Number(Number::Complex(Complex::I));
 pub const TRUE: Value = Value::Boolean(true);
 pub const FALSE: Value = Value::Boolean(false);
 
+// the constants whose value depends on the decimal precision,
+// the float values are used when the decimal mode is disabled.
+pub fn precision_constants(precision: usize) -> [(&'static str, Value); 2] {
+    if precision == 0 {
+        return [("PI", PI), ("E", E)];
+    }
+    let pi = Number::Decimal(BigDecimal::pi(precision));
+    let e = Number::Decimal(BigDecimal::one().exp(precision));
+    return [("PI", Value::from(pi)), ("E", Value::from(e))];
+}
+
 pub static mut IS_INITED: bool = false;
 pub static mut VOID_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut BOOL_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut NUMBER_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
+pub static mut QUANTITY_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
+pub static mut INTERVAL_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut UNIQUE_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut STRING_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut ARRAY_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut MAP_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
+pub static mut MATRIX_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut LAZYEXPR_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut FUNCTION_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
 pub static mut CLASS_T: GlobalUnique = EMPTY_GLOBAL_UNIQUE;
@@ -29,10 +46,13 @@ unsafe fn static_init() {
     VOID_T.init("Void-Type");
     BOOL_T.init("Boolean-Type");
     NUMBER_T.init("Number-Type");
+    QUANTITY_T.init("Quantity-Type");
+    INTERVAL_T.init("Interval-Type");
     UNIQUE_T.init("Number-Type");
     STRING_T.init("String-Type");
     ARRAY_T.init("Array-Type");
     MAP_T.init("Map-Type");
+    MATRIX_T.init("Matrix-Type");
     LAZYEXPR_T.init("Lazy-Expression-Type");
     FUNCTION_T.init("Function-Type");
     CLASS_T.init("Class-Type");
@@ -48,10 +68,13 @@ pub unsafe fn entry() -> HashMap<String, Value> {
         (String::from("VOID"), Value::from(VOID_T.unwrap())),
         (String::from("BOOLEAN"), Value::from(BOOL_T.unwrap())),
         (String::from("NUMBER"), Value::from(NUMBER_T.unwrap())),
+        (String::from("QUANTITY"), Value::from(QUANTITY_T.unwrap())),
+        (String::from("INTERVAL"), Value::from(INTERVAL_T.unwrap())),
         (String::from("UNIQUE"), Value::from(UNIQUE_T.unwrap())),
         (String::from("STRING"), Value::from(STRING_T.unwrap())),
         (String::from("ARRAY"), Value::from(ARRAY_T.unwrap())),
         (String::from("MAP"), Value::from(MAP_T.unwrap())),
+        (String::from("MATRIX"), Value::from(MATRIX_T.unwrap())),
         (String::from("LAZYEXPR"), Value::from(LAZYEXPR_T.unwrap())),
         (String::from("FUNCION"), Value::from(FUNCTION_T.unwrap())),
         (String::from("CLASS"), Value::from(CLASS_T.unwrap())),
@@ -59,6 +82,7 @@ pub unsafe fn entry() -> HashMap<String, Value> {
         // --- --- --- --- --- ---
         (String::from("PI"), PI),
         (String::from("E"), E),
+        (String::from("I"), I),
         (String::from("true"), TRUE),
         (String::from("false"), FALSE),
     ])
@@ -22,6 +22,7 @@ impl GlobalScope {
     }
 }
 
+#[derive(Clone)]
 pub struct LocalScope {
     pub variables: HashMap<String, Value>,
 }
@@ -35,7 +36,7 @@ impl LocalScope {
 
 // --- --- --- --- --- ---
 
-const STD_MODULE_COUNT: usize = 7;
+const STD_MODULE_COUNT: usize = 17;
 const STD_MODULE_DATA: [(&'static str, StdModules); STD_MODULE_COUNT] = [
     ("Basic", StdModules::Basic),
     ("Math", StdModules::Math),
@@ -44,10 +45,23 @@ const STD_MODULE_DATA: [(&'static str, StdModules); STD_MODULE_COUNT] = [
     ("Map", StdModules::Map),
     ("FS", StdModules::FileSystem),
     ("BitOps", StdModules::BitOps),
+    ("NumTheory", StdModules::NumTheory),
+    ("Stats", StdModules::Stats),
+    ("Matrix", StdModules::Matrix),
+    ("Numeric", StdModules::Numeric),
+    ("Random", StdModules::Random),
+    ("Dist", StdModules::Dist),
+    ("Polynomial", StdModules::Polynomial),
+    ("Const", StdModules::Const),
+    ("Finance", StdModules::Finance),
+    ("Series", StdModules::Series),
 ];
 pub struct Scope {
     pub global: GlobalScope,
     pub local: Option<LocalScope>,
+    // the local scope of the caller of current build-in function,
+    // which is visible to the Lazy-Expression callbacks.
+    pub caller_local: Option<LocalScope>,
     pub completer: Option<Completer>,
     user_module_imported: HashSet<String>,
     std_module_imported: [bool; STD_MODULE_COUNT],
@@ -58,6 +72,7 @@ impl Scope {
         Self {
             global: GlobalScope::init(),
             local: None,
+            caller_local: None,
             completer: None,
 
             user_module_imported: HashSet::<String>::new(),
@@ -70,6 +85,7 @@ impl Scope {
         Self {
             global: GlobalScope::init(),
             local: None,
+            caller_local: None,
             completer: None,
 
             user_module_imported: HashSet::<String>::new(),
@@ -1,9 +1,11 @@
 use std::rc::Rc;
 
 use self::modules::{
-    array::ArrayModule, basic::BasicModule, bit_ops::BitOpsModule, file_system::FileSysModule,
-    map::MapModule, math::MathModule, string::StringModule, ClassModule, FunctionModule,
-    ObjectModule,
+    array::ArrayModule, basic::BasicModule, bit_ops::BitOpsModule, consts::ConstModule,
+    dist::DistModule, file_system::FileSysModule, finance::FinanceModule, map::MapModule,
+    math::MathModule, matrix::MatrixModule, num_theory::NumTheoryModule, numeric::NumericModule,
+    polynomial::PolynomialModule, random::RandomModule, series::SeriesModule, stats::StatsModule,
+    string::StringModule, ClassModule, FunctionModule, ObjectModule,
 };
 use super::value::{
     oop::{class::Class, object::Object},
@@ -12,6 +14,7 @@ use super::value::{
 
 pub mod modules;
 
+pub mod symbolic;
 mod utils;
 
 #[derive(PartialEq, Clone, Copy)]
@@ -23,6 +26,16 @@ pub enum StdModules {
     Map,
     FileSystem,
     BitOps,
+    NumTheory,
+    Stats,
+    Matrix,
+    Numeric,
+    Random,
+    Dist,
+    Polynomial,
+    Const,
+    Finance,
+    Series,
 }
 
 impl StdModules {
@@ -30,6 +43,7 @@ impl StdModules {
         match self {
             StdModules::Basic => BasicModule::function_list(),
             StdModules::BitOps => BitOpsModule::function_list(),
+            StdModules::Series => SeriesModule::function_list(),
             _ => unreachable!(),
         }
     }
@@ -38,6 +52,14 @@ impl StdModules {
         match self {
             StdModules::Math => MathModule::module_object(),
             StdModules::FileSystem => FileSysModule::module_object(),
+            StdModules::NumTheory => NumTheoryModule::module_object(),
+            StdModules::Stats => StatsModule::module_object(),
+            StdModules::Matrix => MatrixModule::module_object(),
+            StdModules::Numeric => NumericModule::module_object(),
+            StdModules::Random => RandomModule::module_object(),
+            StdModules::Dist => DistModule::module_object(),
+            StdModules::Const => ConstModule::module_object(),
+            StdModules::Finance => FinanceModule::module_object(),
             _ => unreachable!(),
         }
     }
@@ -47,6 +69,7 @@ impl StdModules {
             StdModules::Array => ArrayModule::module_class(),
             StdModules::String => StringModule::module_class(),
             StdModules::Map => MapModule::module_class(),
+            StdModules::Polynomial => PolynomialModule::module_class(),
             _ => unreachable!(),
         }
     }
@@ -5,30 +5,37 @@ use std::rc::Rc;
 
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 
-use crate::public::error::{internal_error, type_error, InternalComponent, math_error};
+use crate::public::env::ENV_OPTION;
+use crate::public::error::{math_error, syntax_error, type_error};
 use crate::public::run_time::build_in::BuildInFnIdenti;
 use crate::public::run_time::constants::{
-    ARRAY_T, BOOL_T, CLASS_T, FUNCTION_T, LAZYEXPR_T, MAP_T, NUMBER_T, OBJECT_T, STRING_T,
-    UNIQUE_T, VOID_T,
+    ARRAY_T, BOOL_T, CLASS_T, FUNCTION_T, INTERVAL_T, LAZYEXPR_T, MAP_T, MATRIX_T, NUMBER_T,
+    OBJECT_T, QUANTITY_T, STRING_T, UNIQUE_T, VOID_T,
 };
 use crate::public::run_time::scope::Scope;
 use crate::public::std::modules::map::MapModule;
 use crate::public::std::utils::get_self_prop::get_self_prop;
 use crate::public::std::utils::str_to_num::str_to_num;
 use crate::public::value::array::{ArrayLiteral, RawArray};
+use crate::public::value::complex::Complex;
 use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::interval::Interval;
 use crate::public::value::map::RawMap;
 use crate::public::value::number::Number;
+use crate::public::value::number_format::DisplayMode;
+use crate::public::value::quantity::convert_value;
 use crate::public::value::unique::Unique;
 use crate::public::value::value::{Value, ValueType};
 use crate::public::value::GetAddr;
 
-use super::super::utils::get_val::get_val;
+use super::super::symbolic::solve::{equation_expr, solve_equation, solve_system, SCAN_RANGE};
+use super::super::utils::get_val::{get_optional_val, get_val};
 use super::array::ArrayModule;
 use super::string::StringModule;
 use super::{BuildInFnCall, ClassModule, FunctionModule};
 
 #[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum BasicModule {
     INPUT,
     TYPE,
@@ -37,6 +44,8 @@ pub enum BasicModule {
     INT,
     FLOAT,
     FRACTION,
+    COMPLEX,
+    INTERVAL,
     BOOLEAN,
     UNIQUE,
     STRING,
@@ -45,6 +54,12 @@ pub enum BasicModule {
     ASCII,
     LEN,
 
+    SOLVE,
+    CONVERT,
+
+    FORMAT,
+    DISPLAY,
+    EXACT,
     EXIT,
 }
 
@@ -65,14 +80,59 @@ impl FunctionModule for BasicModule {
         let fraction = BuildInFunction {
             params: vec![
                 BuildInFnParam(ValueType::Number, "upper"),
-                BuildInFnParam(ValueType::Number, "lower"),
+                BuildInFnParam(ValueType::Number, "lower?"),
+                BuildInFnParam(ValueType::Number, "max_denominator?"),
             ],
             identi: BuildInFnIdenti::Basic(Self::FRACTION),
         };
+        let complex = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Number, "re"),
+                BuildInFnParam(ValueType::Number, "im"),
+            ],
+            identi: BuildInFnIdenti::Basic(Self::COMPLEX),
+        };
+        let interval = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Number, "lower"),
+                BuildInFnParam(ValueType::Number, "upper"),
+            ],
+            identi: BuildInFnIdenti::Basic(Self::INTERVAL),
+        };
         let unique = BuildInFunction {
             params: vec![BuildInFnParam(ValueType::String, "input")],
             identi: BuildInFnIdenti::Basic(Self::UNIQUE),
         };
+        let format = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Number, "num"),
+                BuildInFnParam(ValueType::String, "spec"),
+            ],
+            identi: BuildInFnIdenti::Basic(Self::FORMAT),
+        };
+        let display = BuildInFunction {
+            params: vec![BuildInFnParam(ValueType::String, "spec")],
+            identi: BuildInFnIdenti::Basic(Self::DISPLAY),
+        };
+        let solve = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Void, "equation"),
+                BuildInFnParam(ValueType::Void, "var"),
+                BuildInFnParam(ValueType::Interval, "range?"),
+            ],
+            identi: BuildInFnIdenti::Basic(Self::SOLVE),
+        };
+        let convert = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Void, "quantity"),
+                BuildInFnParam(ValueType::String, "unit"),
+            ],
+            identi: BuildInFnIdenti::Basic(Self::CONVERT),
+        };
+        let exact = BuildInFunction {
+            params: vec![BuildInFnParam(ValueType::Boolean, "enable")],
+            identi: BuildInFnIdenti::Basic(Self::EXACT),
+        };
 
         // --- --- --- --- --- ---
 
@@ -103,12 +163,19 @@ impl FunctionModule for BasicModule {
             (String::from("int"), Value::from(int)),
             (String::from("float"), Value::from(float)),
             (String::from("fraction"), Value::from(fraction)),
+            (String::from("complex"), Value::from(complex)),
+            (String::from("interval"), Value::from(interval)),
             (String::from("bool"), Value::from(boolean)),
             (String::from("unique"), Value::from(unique)),
             (String::from("string"), Value::from(string)),
             (String::from("array"), Value::from(array)),
             (String::from("ascii"), Value::from(ascii)),
             (String::from("len"), Value::from(len)),
+            (String::from("solve"), Value::from(solve)),
+            (String::from("convert"), Value::from(convert)),
+            (String::from("format"), Value::from(format)),
+            (String::from("display_mode"), Value::from(display)),
+            (String::from("exact_mode"), Value::from(exact)),
             (String::from("exit"), Value::from(exit)),
         ];
     }
@@ -142,25 +209,143 @@ impl BuildInFnCall for BasicModule {
             }
             Self::FRACTION => {
                 let upper_value = get_val("upper", scope)?;
-                let lower_value = get_val("lower", scope)?;
+                let lower_value = get_optional_val("lower", scope);
+                let max_lower_value = get_optional_val("max_denominator", scope);
+
+                let Value::Number(upper) = upper_value else {
+                    unreachable!()
+                };
+                let mut result = match lower_value {
+                    Some(Value::Number(lower)) => {
+                        if lower.is_zero() {
+                            return Err(math_error("the divisor should not to be ZERO")?);
+                        }
+                        upper.exact_quotient(&lower)
+                    }
+                    _ => upper.rationalize(None),
+                };
+                if let Some(max_lower_value) = max_lower_value {
+                    let max_lower = max_lower_value.get_i64()?;
+                    if max_lower < 1 {
+                        return Err(math_error("the max denominator should be positive")?);
+                    }
+                    result = result.rationalize(Some(max_lower));
+                }
 
-                if lower_value.get_f64() == Ok(0.0) {
-                    return Err(math_error("the divisor should not to be ZERO")?);
+                if let Number::NotANumber = result {
+                    return Err(math_error("only real number can be converted to fraction")?);
                 }
+                Value::from(result)
+            }
+            Self::COMPLEX => {
+                let re_value = get_val("re", scope)?;
+                let im_value = get_val("im", scope)?;
+
+                let re = re_value.get_f64()?;
+                let im = im_value.get_f64()?;
+                Value::from(Number::from_complex(Complex::new(re, im)))
+            }
+            Self::INTERVAL => {
+                let (Value::Number(lower), Value::Number(upper)) =
+                    (get_val("lower", scope)?, get_val("upper", scope)?)
+                else {
+                    unreachable!()
+                };
+                Value::from(Interval::from_bounds(&lower, &upper)?)
+            }
+            Self::CONVERT => {
+                let quantity_value = get_val("quantity", scope)?;
+                let unit_value = get_val("unit", scope)?;
+                let unit = unit_value.get_str()?;
+                convert_value(&quantity_value, &unit)?
+            }
+            Self::FORMAT | Self::DISPLAY => {
+                let spec_value = get_val("spec", scope)?;
+                let spec = spec_value.get_str()?;
+                let Some(mode) = DisplayMode::parse(&spec) else {
+                    let msg = format!("invalid display mode \"{}\"", spec);
+                    return Err(syntax_error(&msg)?);
+                };
 
-                if let (Value::Number(Number::Int(upper)), Value::Number(Number::Int(lower))) =
-                    (upper_value, lower_value)
-                {
-                    return Ok(Value::Number(Number::Fraction(upper, lower)));
-                } else {
-                    return Err(internal_error(
-                        InternalComponent::Std,
-                        "two Int typed value is expected",
-                    )?);
+                if *self == Self::DISPLAY {
+                    unsafe { ENV_OPTION.display_mode = mode };
+                    return Ok(Value::EMPTY);
                 }
+                let num_value = get_val("num", scope)?;
+                let Value::Number(num) = num_value else {
+                    unreachable!()
+                };
+                Value::from(num.format(mode))
+            }
+            Self::EXACT => {
+                let enable_value = get_val("enable", scope)?;
+                unsafe { ENV_OPTION.exact_mode = enable_value.get_bool() };
+                Value::EMPTY
             }
             Self::EXIT => process::exit(0),
 
+            Self::SOLVE => {
+                let equation_value = get_val("equation", scope)?;
+                let var_value = get_val("var", scope)?;
+                let range_value = get_optional_val("range", scope);
+
+                match (&equation_value, &var_value) {
+                    (Value::LazyExpression(_), Value::String(var)) => {
+                        let range = match range_value {
+                            Some(Value::Interval(range)) => (range.lower(), range.upper()),
+                            _ => SCAN_RANGE,
+                        };
+                        if !(range.0.is_finite() && range.1.is_finite() && range.0 < range.1) {
+                            return Err(math_error(
+                                "the range to solve should be a finite interval",
+                            )?);
+                        }
+                        let expr = equation_expr(&equation_value)?;
+                        let var = var.borrow().clone();
+                        let roots = solve_equation(&expr, &var, range, scope)?;
+                        let roots_arr: ArrayLiteral = roots.into_iter().map(Value::from).collect();
+                        Value::from(roots_arr)
+                    }
+                    (Value::Array(equations), Value::Array(vars)) => {
+                        if range_value.is_some() {
+                            return Err(math_error(
+                                "the range to solve is only available for single equation",
+                            )?);
+                        }
+                        let mut exprs = vec![];
+                        for equation in equations.borrow().iter() {
+                            exprs.push(equation_expr(equation)?);
+                        }
+                        let mut var_names = vec![];
+                        for var in vars.borrow().iter() {
+                            let Value::String(name) = var else {
+                                return Err(type_error(
+                                    Some("var"),
+                                    vec![ValueType::String],
+                                    var.get_type(),
+                                )?);
+                            };
+                            var_names.push(name.borrow().clone());
+                        }
+
+                        let var_refs: Vec<&str> = var_names.iter().map(String::as_str).collect();
+                        let solution = solve_system(&exprs, &var_refs, scope)?;
+                        let result_map = var_names
+                            .into_iter()
+                            .zip(solution.into_iter().map(Value::from))
+                            .collect();
+                        Value::from(RawMap::new(result_map))
+                    }
+                    _ => {
+                        return Err(type_error(
+                            Some("Build-in function `solve`"),
+                            vec![ValueType::LazyExpression, ValueType::Array],
+                            equation_value.get_type(),
+                        )?)
+                    }
+                }
+            }
+
             _ => {
                 let input = get_val("input", scope)?;
 
@@ -171,10 +356,13 @@ impl BuildInFnCall for BasicModule {
                                 ValueType::Void => &VOID_T,
                                 ValueType::Boolean => &BOOL_T,
                                 ValueType::Number => &NUMBER_T,
+                                ValueType::Quantity => &QUANTITY_T,
+                                ValueType::Interval => &INTERVAL_T,
                                 ValueType::Unique => &UNIQUE_T,
                                 ValueType::String => &STRING_T,
                                 ValueType::Array => &ARRAY_T,
                                 ValueType::Map => &MAP_T,
+                                ValueType::Matrix => &MATRIX_T,
                                 ValueType::LazyExpression => &LAZYEXPR_T,
                                 ValueType::Function => &FUNCTION_T,
                                 ValueType::Class => &CLASS_T,
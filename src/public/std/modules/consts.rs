@@ -0,0 +1,158 @@
+use std::f64::consts::{E, LN_10, LN_2, PI, SQRT_2, TAU};
+
+use crate::public::error::{reference_error, ReferenceType};
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::map::RawMap;
+use crate::public::value::number::Number;
+use crate::public::value::oop::object::Object;
+use crate::public::value::quantity::{Quantity, Unit};
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_val::get_optional_val;
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ConstModule {
+    DESCRIBE,
+}
+
+// (name, value, unit, description),
+// the physical constants are the CODATA 2018 recommended values
+// and are Quantities with the unit, the dimensionless ones are Numbers.
+type ConstDef = (&'static str, f64, &'static str, &'static str);
+const CONSTANTS: [ConstDef; 31] = [
+    // physical constants
+    ("c", 299792458.0, "m/s", "speed of light in vacuum"),
+    ("h", 6.62607015e-34, "J*s", "Planck constant"),
+    ("hbar", 1.054571817e-34, "J*s", "reduced Planck constant"),
+    ("k_B", 1.380649e-23, "J/K", "Boltzmann constant"),
+    ("N_A", 6.02214076e23, "1/mol", "Avogadro constant"),
+    ("R", 8.314462618, "J/(mol*K)", "molar gas constant"),
+    ("F", 96485.33212, "C/mol", "Faraday constant"),
+    (
+        "G",
+        6.67430e-11,
+        "m^3/(kg*s^2)",
+        "Newtonian constant of gravitation",
+    ),
+    ("g_n", 9.80665, "m/s^2", "standard acceleration of gravity"),
+    ("e", 1.602176634e-19, "C", "elementary charge"),
+    ("m_e", 9.1093837015e-31, "kg", "electron mass"),
+    ("m_p", 1.67262192369e-27, "kg", "proton mass"),
+    ("m_n", 1.67492749804e-27, "kg", "neutron mass"),
+    ("m_u", 1.66053906660e-27, "kg", "atomic mass constant"),
+    (
+        "eps_0",
+        8.8541878128e-12,
+        "F/m",
+        "vacuum electric permittivity",
+    ),
+    (
+        "mu_0",
+        1.25663706212e-6,
+        "N/A^2",
+        "vacuum magnetic permeability",
+    ),
+    (
+        "sigma",
+        5.670374419e-8,
+        "W/(m^2*K^4)",
+        "Stefan-Boltzmann constant",
+    ),
+    ("a_0", 5.29177210903e-11, "m", "Bohr radius"),
+    ("R_inf", 10973731.568160, "1/m", "Rydberg constant"),
+    ("atm", 101325.0, "Pa", "standard atmosphere"),
+    ("alpha", 7.2973525693e-3, "", "fine-structure constant"),
+    // mathematical constants
+    (
+        "pi",
+        PI,
+        "",
+        "ratio of a circle's circumference to its diameter",
+    ),
+    (
+        "tau",
+        TAU,
+        "",
+        "ratio of a circle's circumference to its radius, 2pi",
+    ),
+    ("E", E, "", "Euler's number, the base of natural logarithm"),
+    (
+        "phi",
+        1.618033988749895,
+        "",
+        "golden ratio, (1 + sqrt(5)) / 2",
+    ),
+    ("sqrt2", SQRT_2, "", "square root of 2"),
+    ("sqrt3", 1.7320508075688772, "", "square root of 3"),
+    ("ln2", LN_2, "", "natural logarithm of 2"),
+    ("ln10", LN_10, "", "natural logarithm of 10"),
+    ("gamma", 0.5772156649015329, "", "Euler-Mascheroni constant"),
+    ("catalan", 0.915965594177219, "", "Catalan's constant"),
+];
+
+fn const_value(value: f64, unit: &str) -> Value {
+    let num = Number::Float(value);
+    if unit.is_empty() {
+        return Value::from(num);
+    }
+    // the units in the table are always valid
+    let unit = Unit::parse(unit).unwrap();
+    return Value::from(Quantity::new(num, unit));
+}
+
+// the description followed by the value in current display mode,
+// e.g. `speed of light in vacuum, 299792458 m/s`
+fn const_description(def: &ConstDef) -> String {
+    let (_, value, unit, description) = def;
+    return format!("{}, {}", description, const_value(*value, unit));
+}
+
+impl ObjectModule for ConstModule {
+    fn module_object() -> Object {
+        let describe = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::String, "name?"),
+            ],
+            identi: BuildInFnIdenti::Const(Self::DESCRIBE),
+        };
+
+        let mut module_obj_props = vec![(String::from("describe"), Value::from(describe))];
+        let mut descriptions = vec![];
+        for (name, value, unit, description) in CONSTANTS {
+            module_obj_props.push((String::from(name), const_value(value, unit)));
+            descriptions.push((name, String::from(description)));
+        }
+        let mut module_obj = Object::new(module_obj_props, None);
+        module_obj.describe_props(descriptions);
+        return module_obj;
+    }
+}
+
+impl BuildInFnCall for ConstModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let result = match self {
+            Self::DESCRIBE => {
+                let Some(name_value) = get_optional_val("name", scope) else {
+                    // the descriptions of all the constants
+                    let descriptions = CONSTANTS
+                        .iter()
+                        .map(|def| (String::from(def.0), Value::from(const_description(def))))
+                        .collect();
+                    return Ok(Value::from(RawMap::new(descriptions)));
+                };
+
+                let name = name_value.get_str()?;
+                let Some(def) = CONSTANTS.iter().find(|def| def.0 == *name) else {
+                    return Err(reference_error(ReferenceType::Property, &name)?);
+                };
+                Value::from(const_description(def))
+            }
+        };
+        return Ok(result);
+    }
+}
@@ -0,0 +1,489 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use crate::public::error::math_error;
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::oop::object::Object;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_val::{get_optional_val, get_val};
+use super::super::utils::special_fn::{beta_i, erfc, gamma_p, gamma_q, lgamma};
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum DistModule {
+    NORMALPDF,
+    NORMALCDF,
+    NORMALINV,
+    BINOMIALPMF,
+    BINOMIALCDF,
+    BINOMIALINV,
+    POISSONPMF,
+    POISSONCDF,
+    POISSONINV,
+    EXPONENTIALPDF,
+    EXPONENTIALCDF,
+    EXPONENTIALINV,
+    UNIFORMPDF,
+    UNIFORMCDF,
+    UNIFORMINV,
+    TPDF,
+    TCDF,
+    TINV,
+    CHI2PDF,
+    CHI2CDF,
+    CHI2INV,
+}
+
+enum Distribution {
+    Normal { mu: f64, sigma: f64 },
+    Binomial { n: i64, p: f64 },
+    Poisson { lambda: f64 },
+    Exponential { lambda: f64 },
+    Uniform { a: f64, b: f64 },
+    StudentT { df: f64 },
+    ChiSquared { df: f64 },
+}
+
+enum DistFn {
+    // probability density or probability mass function
+    Density,
+    // cumulative distribution function
+    Cumulative,
+    // inverse cumulative distribution function
+    Inverse,
+}
+
+// the coefficients of Acklam's rational approximation
+// for the inverse of the standard normal CDF.
+const ACKLAM_A: [f64; 6] = [
+    -3.969_683_028_665_376e1,
+    2.209_460_984_245_205e2,
+    -2.759_285_104_469_687e2,
+    1.383_577_518_672_69e2,
+    -3.066_479_806_614_716e1,
+    2.506_628_277_459_239,
+];
+const ACKLAM_B: [f64; 5] = [
+    -5.447_609_879_822_406e1,
+    1.615_858_368_580_409e2,
+    -1.556_989_798_598_866e2,
+    6.680_131_188_771_972e1,
+    -1.328_068_155_288_572e1,
+];
+const ACKLAM_C: [f64; 6] = [
+    -7.784_894_002_430_293e-3,
+    -3.223_964_580_411_365e-1,
+    -2.400_758_277_161_838,
+    -2.549_732_539_343_734,
+    4.374_664_141_464_968,
+    2.938_163_982_698_783,
+];
+const ACKLAM_D: [f64; 4] = [
+    7.784_695_709_041_462e-3,
+    3.224_671_290_700_398e-1,
+    2.445_134_137_142_996,
+    3.754_408_661_907_416,
+];
+
+fn polynomial(coeffs: &[f64], x: f64) -> f64 {
+    return coeffs.iter().fold(0.0, |acc, c| acc * x + c);
+}
+
+fn std_normal_cdf(z: f64) -> f64 {
+    return 0.5 * erfc(-z / SQRT_2);
+}
+
+// inverse of the standard normal CDF, `p` should be in (0, 1)
+fn std_normal_inv(p: f64) -> f64 {
+    const P_LOW: f64 = 0.02425;
+    let z = if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        polynomial(&ACKLAM_C, q) / (polynomial(&ACKLAM_D, q) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        polynomial(&ACKLAM_A, r) * q / (polynomial(&ACKLAM_B, r) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -polynomial(&ACKLAM_C, q) / (polynomial(&ACKLAM_D, q) * q + 1.0)
+    };
+
+    // one step of Halley's method refines the result to full precision
+    let e = std_normal_cdf(z) - p;
+    let u = e * (2.0 * PI).sqrt() * (z * z / 2.0).exp();
+    return z - u / (1.0 + z * u / 2.0);
+}
+
+// solves `cdf(x) = p` for continuous distribution by bisection,
+// the bracket [lower, upper] is expanded until it contains the result.
+fn bisect_inv(cdf: impl Fn(f64) -> f64, p: f64, mut lower: f64, mut upper: f64) -> f64 {
+    while cdf(upper) < p {
+        (lower, upper) = (upper, upper * 2.0);
+    }
+    while lower < 0.0 && cdf(lower) > p {
+        (lower, upper) = (lower * 2.0, lower);
+    }
+    for _ in 0..200 {
+        let middle = (lower + upper) / 2.0;
+        if middle == lower || middle == upper {
+            break;
+        }
+        if cdf(middle) < p {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+    return (lower + upper) / 2.0;
+}
+
+// the smallest integer `k` with `cdf(k) >= p` for discrete distribution,
+// searched from the `guess` one by one.
+fn discrete_inv(cdf: impl Fn(i64) -> f64, p: f64, guess: i64, max: i64) -> i64 {
+    let mut k = guess.clamp(0, max);
+    while k > 0 && cdf(k - 1) >= p {
+        k -= 1;
+    }
+    while k < max && cdf(k) < p {
+        k += 1;
+    }
+    return k;
+}
+
+impl Distribution {
+    fn density(&self, x: f64) -> f64 {
+        match *self {
+            Self::Normal { mu, sigma } => {
+                let z = (x - mu) / sigma;
+                (-z * z / 2.0).exp() / (sigma * (2.0 * PI).sqrt())
+            }
+            Self::Binomial { n, p } => {
+                if x != x.floor() || x < 0.0 || x > n as f64 {
+                    return 0.0;
+                }
+                // the cases where `ln(0)` occurs
+                if p == 0.0 || p == 1.0 {
+                    let certain = if p == 0.0 { 0.0 } else { n as f64 };
+                    return if x == certain { 1.0 } else { 0.0 };
+                }
+                let n = n as f64;
+                let ln_choose = lgamma(n + 1.0) - lgamma(x + 1.0) - lgamma(n - x + 1.0);
+                (ln_choose + x * p.ln() + (n - x) * (1.0 - p).ln()).exp()
+            }
+            Self::Poisson { lambda } => {
+                if x != x.floor() || x < 0.0 {
+                    return 0.0;
+                }
+                (x * lambda.ln() - lambda - lgamma(x + 1.0)).exp()
+            }
+            Self::Exponential { lambda } => {
+                if x < 0.0 {
+                    return 0.0;
+                }
+                lambda * (-lambda * x).exp()
+            }
+            Self::Uniform { a, b } => {
+                if x < a || x > b {
+                    return 0.0;
+                }
+                1.0 / (b - a)
+            }
+            Self::StudentT { df } => {
+                let ln_coeff = lgamma((df + 1.0) / 2.0) - lgamma(df / 2.0) - 0.5 * (df * PI).ln();
+                (ln_coeff - (df + 1.0) / 2.0 * (1.0 + x * x / df).ln()).exp()
+            }
+            Self::ChiSquared { df } => {
+                if x < 0.0 {
+                    return 0.0;
+                }
+                if x == 0.0 {
+                    return match df {
+                        _ if df < 2.0 => f64::INFINITY,
+                        _ if df == 2.0 => 0.5,
+                        _ => 0.0,
+                    };
+                }
+                let k = df / 2.0;
+                ((k - 1.0) * x.ln() - x / 2.0 - k * 2_f64.ln() - lgamma(k)).exp()
+            }
+        }
+    }
+
+    fn cumulative(&self, x: f64) -> f64 {
+        match *self {
+            Self::Normal { mu, sigma } => std_normal_cdf((x - mu) / sigma),
+            Self::Binomial { n, p } => {
+                if x < 0.0 {
+                    return 0.0;
+                }
+                let k = x.floor();
+                if k >= n as f64 {
+                    return 1.0;
+                }
+                beta_i(n as f64 - k, k + 1.0, 1.0 - p)
+            }
+            Self::Poisson { lambda } => {
+                if x < 0.0 {
+                    return 0.0;
+                }
+                gamma_q(x.floor() + 1.0, lambda)
+            }
+            Self::Exponential { lambda } => {
+                if x < 0.0 {
+                    return 0.0;
+                }
+                -(-lambda * x).exp_m1()
+            }
+            Self::Uniform { a, b } => ((x - a) / (b - a)).clamp(0.0, 1.0),
+            Self::StudentT { df } => {
+                let tail = 0.5 * beta_i(df / 2.0, 0.5, df / (df + x * x));
+                if x > 0.0 {
+                    1.0 - tail
+                } else {
+                    tail
+                }
+            }
+            Self::ChiSquared { df } => gamma_p(df / 2.0, x / 2.0),
+        }
+    }
+
+    // `p` should be in (0, 1)
+    fn inverse(&self, p: f64) -> f64 {
+        match *self {
+            Self::Normal { mu, sigma } => mu + sigma * std_normal_inv(p),
+            Self::Binomial { n, p: success } => {
+                let mean = n as f64 * success;
+                let sd = (mean * (1.0 - success)).sqrt();
+                let guess = (mean + sd * std_normal_inv(p)).round() as i64;
+                discrete_inv(|k| self.cumulative(k as f64), p, guess, n) as f64
+            }
+            Self::Poisson { lambda } => {
+                let guess = (lambda + lambda.sqrt() * std_normal_inv(p)).round() as i64;
+                discrete_inv(|k| self.cumulative(k as f64), p, guess, i64::MAX) as f64
+            }
+            Self::Exponential { lambda } => -(-p).ln_1p() / lambda,
+            Self::Uniform { a, b } => a + p * (b - a),
+            Self::StudentT { .. } => bisect_inv(|x| self.cumulative(x), p, -1.0, 1.0),
+            Self::ChiSquared { .. } => bisect_inv(|x| self.cumulative(x), p, 0.0, 1.0),
+        }
+    }
+}
+
+impl DistModule {
+    fn dist_fn(&self) -> DistFn {
+        match self {
+            Self::NORMALPDF
+            | Self::BINOMIALPMF
+            | Self::POISSONPMF
+            | Self::EXPONENTIALPDF
+            | Self::UNIFORMPDF
+            | Self::TPDF
+            | Self::CHI2PDF => DistFn::Density,
+
+            Self::NORMALCDF
+            | Self::BINOMIALCDF
+            | Self::POISSONCDF
+            | Self::EXPONENTIALCDF
+            | Self::UNIFORMCDF
+            | Self::TCDF
+            | Self::CHI2CDF => DistFn::Cumulative,
+
+            Self::NORMALINV
+            | Self::BINOMIALINV
+            | Self::POISSONINV
+            | Self::EXPONENTIALINV
+            | Self::UNIFORMINV
+            | Self::TINV
+            | Self::CHI2INV => DistFn::Inverse,
+        }
+    }
+
+    fn get_positive(val_name: &str, scope: &mut Scope) -> Result<f64, ()> {
+        let value = get_val(val_name, scope)?.get_f64()?;
+        if value.is_nan() || value <= 0.0 {
+            let msg = format!("param `{}` should be positive", val_name);
+            return Err(math_error(&msg)?);
+        }
+        return Ok(value);
+    }
+
+    // reads and validates the distribution params
+    fn distribution(&self, scope: &mut Scope) -> Result<Distribution, ()> {
+        let distribution = match self {
+            Self::NORMALPDF | Self::NORMALCDF | Self::NORMALINV => {
+                let mu = match get_optional_val("mu", scope) {
+                    Some(mu_value) => mu_value.get_f64()?,
+                    None => 0.0,
+                };
+                let sigma = match get_optional_val("sigma", scope) {
+                    Some(_) => Self::get_positive("sigma", scope)?,
+                    None => 1.0,
+                };
+                Distribution::Normal { mu, sigma }
+            }
+            Self::BINOMIALPMF | Self::BINOMIALCDF | Self::BINOMIALINV => {
+                let n = get_val("n", scope)?.get_i64()?;
+                let p = get_val("p", scope)?.get_f64()?;
+                if n < 0 {
+                    return Err(math_error("param `n` should not be negative")?);
+                }
+                if !(0.0..=1.0).contains(&p) {
+                    return Err(math_error("param `p` should be between 0 and 1")?);
+                }
+                Distribution::Binomial { n, p }
+            }
+            Self::POISSONPMF | Self::POISSONCDF | Self::POISSONINV => {
+                let lambda = Self::get_positive("lambda", scope)?;
+                Distribution::Poisson { lambda }
+            }
+            Self::EXPONENTIALPDF | Self::EXPONENTIALCDF | Self::EXPONENTIALINV => {
+                let lambda = Self::get_positive("lambda", scope)?;
+                Distribution::Exponential { lambda }
+            }
+            Self::UNIFORMPDF | Self::UNIFORMCDF | Self::UNIFORMINV => {
+                let a = get_val("a", scope)?.get_f64()?;
+                let b = get_val("b", scope)?.get_f64()?;
+                if a >= b {
+                    return Err(math_error("param `a` should be less than `b`")?);
+                }
+                Distribution::Uniform { a, b }
+            }
+            Self::TPDF | Self::TCDF | Self::TINV => {
+                let df = Self::get_positive("df", scope)?;
+                Distribution::StudentT { df }
+            }
+            Self::CHI2PDF | Self::CHI2CDF | Self::CHI2INV => {
+                let df = Self::get_positive("df", scope)?;
+                Distribution::ChiSquared { df }
+            }
+        };
+        return Ok(distribution);
+    }
+
+    // the density, cumulative and inverse functions of one distribution
+    fn dist_functions(
+        name: &str,
+        density_name: &str,
+        params: &[BuildInFnParam],
+        identies: [Self; 3],
+    ) -> Vec<(String, Value)> {
+        let [density, cumulative, inverse] = identies;
+        let function = |input: &'static str, identi: Self| {
+            let mut fn_params = vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, input),
+            ];
+            fn_params.extend_from_slice(params);
+            let build_in_fn = BuildInFunction {
+                params: fn_params,
+                identi: BuildInFnIdenti::Dist(identi),
+            };
+            return Value::from(build_in_fn);
+        };
+        return vec![
+            (format!("{}_{}", name, density_name), function("x", density)),
+            (format!("{}_cdf", name), function("x", cumulative)),
+            (format!("{}_inv", name), function("prob", inverse)),
+        ];
+    }
+}
+
+impl ObjectModule for DistModule {
+    fn module_object() -> Object {
+        let normal_params = [
+            BuildInFnParam(ValueType::Number, "mu?"),
+            BuildInFnParam(ValueType::Number, "sigma?"),
+        ];
+        let binomial_params = [
+            BuildInFnParam(ValueType::Number, "n"),
+            BuildInFnParam(ValueType::Number, "p"),
+        ];
+        let lambda_params = [BuildInFnParam(ValueType::Number, "lambda")];
+        let uniform_params = [
+            BuildInFnParam(ValueType::Number, "a"),
+            BuildInFnParam(ValueType::Number, "b"),
+        ];
+        let df_params = [BuildInFnParam(ValueType::Number, "df")];
+
+        let module_obj_props = [
+            Self::dist_functions(
+                "normal",
+                "pdf",
+                &normal_params,
+                [Self::NORMALPDF, Self::NORMALCDF, Self::NORMALINV],
+            ),
+            Self::dist_functions(
+                "binomial",
+                "pmf",
+                &binomial_params,
+                [Self::BINOMIALPMF, Self::BINOMIALCDF, Self::BINOMIALINV],
+            ),
+            Self::dist_functions(
+                "poisson",
+                "pmf",
+                &lambda_params,
+                [Self::POISSONPMF, Self::POISSONCDF, Self::POISSONINV],
+            ),
+            Self::dist_functions(
+                "exponential",
+                "pdf",
+                &lambda_params,
+                [
+                    Self::EXPONENTIALPDF,
+                    Self::EXPONENTIALCDF,
+                    Self::EXPONENTIALINV,
+                ],
+            ),
+            Self::dist_functions(
+                "uniform",
+                "pdf",
+                &uniform_params,
+                [Self::UNIFORMPDF, Self::UNIFORMCDF, Self::UNIFORMINV],
+            ),
+            Self::dist_functions("t", "pdf", &df_params, [Self::TPDF, Self::TCDF, Self::TINV]),
+            Self::dist_functions(
+                "chi2",
+                "pdf",
+                &df_params,
+                [Self::CHI2PDF, Self::CHI2CDF, Self::CHI2INV],
+            ),
+        ]
+        .concat();
+        return Object::new(module_obj_props, None);
+    }
+}
+
+impl BuildInFnCall for DistModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let distribution = self.distribution(scope)?;
+
+        let result = match self.dist_fn() {
+            DistFn::Density => {
+                let x = get_val("x", scope)?.get_f64()?;
+                distribution.density(x)
+            }
+            DistFn::Cumulative => {
+                let x = get_val("x", scope)?.get_f64()?;
+                distribution.cumulative(x)
+            }
+            DistFn::Inverse => {
+                let prob = get_val("prob", scope)?.get_f64()?;
+                if prob.is_nan() || prob <= 0.0 || prob >= 1.0 {
+                    return Err(math_error(
+                        "param `prob` should be between 0 and 1 (exclusive)",
+                    )?);
+                }
+                let x = distribution.inverse(prob);
+                // the inverse of discrete distribution is integer
+                if let Distribution::Binomial { .. } | Distribution::Poisson { .. } = distribution {
+                    return Ok(Value::from(x as i64));
+                }
+                x
+            }
+        };
+        return Ok(Value::from(result));
+    }
+}
@@ -0,0 +1,573 @@
+use std::collections::HashMap;
+
+use crate::public::env::{RoundingMode, ENV_OPTION};
+use crate::public::error::{math_error, syntax_error, type_error};
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::map::RawMap;
+use crate::public::value::number::Number;
+use crate::public::value::oop::object::Object;
+use crate::public::value::quantity::quotient;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_num_arr::get_num_arr;
+use super::super::utils::get_self_prop::get_self_prop;
+use super::super::utils::get_val::{get_optional_val, get_val};
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum FinanceModule {
+    PV,
+    FV,
+    PMT,
+    NPER,
+    RATE,
+
+    NPV,
+    IRR,
+    XIRR,
+    AMORTIZE,
+
+    ROUNDING,
+}
+
+const MAX_ITERATIONS: usize = 100;
+const RATE_TOLERANCE: f64 = 1e-12;
+// the value at the solved rate should be ZERO relative to the amounts
+const VALUE_TOLERANCE: f64 = 1e-9;
+const DEFAULT_GUESS: f64 = 0.1;
+// the rates to search the sign change of cash flow value
+// when Newton's method does not converge.
+const RATE_BRACKETS: [f64; 13] = [
+    -0.99, -0.9, -0.5, -0.2, 0.0, 0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 100.0,
+];
+// the day count of a year in `xirr`
+const YEAR_DAYS: f64 = 365.0;
+
+fn rounding_mode() -> RoundingMode {
+    return unsafe { ENV_OPTION.rounding_mode };
+}
+// the count of cents of money amount, rounded in current rounding mode
+fn to_cents(amount: &Number) -> Number {
+    let mode = rounding_mode();
+    if mode == RoundingMode::Unrounded {
+        return amount.clone() * Number::Int(100);
+    }
+    return amount.round_units(100, mode).unwrap_or(Number::NotANumber);
+}
+// the count of cents computed from cents, e.g. the interest of balance
+fn round_cents(cents: Number) -> Number {
+    let mode = rounding_mode();
+    if mode == RoundingMode::Unrounded {
+        return cents;
+    }
+    return cents.round_units(1, mode).unwrap_or(Number::NotANumber);
+}
+fn from_cents(cents: Number) -> Number {
+    return quotient(cents, &Number::Int(100));
+}
+// the money amount rounded to cents,
+// the fraction result is only kept in exact mode.
+fn money(amount: Number) -> Value {
+    let result = match rounding_mode() {
+        RoundingMode::Unrounded => quotient(amount, &Number::Int(1)),
+        _ => from_cents(to_cents(&amount)),
+    };
+    return Value::from(result);
+}
+
+// growth `(1 + rate) ^ nper` and the annuity factor
+// `(1 + rate * due) * (growth - 1) / rate`, which is `nper` when `rate` is ZERO.
+fn time_value(rate: &Number, nper: &Number, is_due: bool) -> (Number, Number) {
+    if rate.is_zero() {
+        return (Number::Int(1), nper.clone());
+    }
+    let base = Number::Int(1) + rate.clone();
+    let growth = Number::pow(base.clone(), nper.clone());
+    let timing = if is_due { base } else { Number::Int(1) };
+    let annuity = timing * (growth.clone() - Number::Int(1)).true_div(rate);
+    return (growth, annuity);
+}
+
+// solves `f(rate) == 0` with Newton's method and the numerical derivative,
+// the root is searched by bisection in the first bracket of sign change
+// when Newton's method does not converge, `scale` is the sum of the absolute amounts.
+fn solve_rate(f: impl Fn(f64) -> f64, guess: f64, scale: f64) -> Option<f64> {
+    // the converged rate may be not a root, e.g. approaching -1
+    let is_root = |rate: f64| f(rate).abs() <= VALUE_TOLERANCE * scale.max(1.0);
+    let mut rate = guess;
+    for _ in 0..MAX_ITERATIONS {
+        let value = f(rate);
+        let step = 1e-6 * (1.0 + rate.abs());
+        let slope = (f(rate + step) - f(rate - step)) / (2.0 * step);
+        if !value.is_finite() || !slope.is_finite() || slope == 0.0 {
+            break;
+        }
+        let mut next = rate - value / slope;
+        if next <= -1.0 {
+            // the rate should be greater than -1, the step is halved towards it
+            next = (rate - 1.0) / 2.0;
+        }
+        if (next - rate).abs() <= RATE_TOLERANCE * (1.0 + rate.abs()) {
+            if is_root(next) {
+                return Some(next);
+            }
+            break;
+        }
+        rate = next;
+    }
+
+    let (mut lower, mut upper) = RATE_BRACKETS
+        .windows(2)
+        .map(|bracket| (bracket[0], bracket[1]))
+        .find(|(lower, upper)| f(*lower) * f(*upper) <= 0.0)?;
+    for _ in 0..200 {
+        let middle = (lower + upper) / 2.0;
+        if middle == lower || middle == upper {
+            break;
+        }
+        if f(lower) * f(middle) <= 0.0 {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+    let root = (lower + upper) / 2.0;
+    return is_root(root).then_some(root);
+}
+
+// the days since 1970-01-01 of the date text "YYYY-MM-DD"
+fn parse_date(text: &str) -> Option<i64> {
+    let mut parts = text.trim().splitn(3, '-');
+    let year = parts.next()?.parse::<i64>().ok()?;
+    let month = parts.next()?.parse::<i64>().ok()?;
+    let day = parts.next()?.parse::<i64>().ok()?;
+
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let month_days = match month {
+        2 if is_leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    if day < 1 || day > month_days {
+        return None;
+    }
+
+    // the days from civil algorithm, the year starts from March
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    return Some(era * 146097 + day_of_era - 719468);
+}
+
+impl FinanceModule {
+    fn get_real(val_name: &str, scope: &mut Scope) -> Result<Number, ()> {
+        let Value::Number(num) = get_val(val_name, scope)? else {
+            unreachable!()
+        };
+        if let Number::Complex(_) | Number::NotANumber = num {
+            let msg = format!("`{}` should be a real number", val_name);
+            return Err(math_error(&msg)?);
+        }
+        return Ok(num);
+    }
+    fn get_optional_real(val_name: &str, scope: &mut Scope) -> Result<Number, ()> {
+        if get_optional_val(val_name, scope).is_none() {
+            return Ok(Number::Int(0));
+        }
+        return Self::get_real(val_name, scope);
+    }
+    fn get_due(scope: &mut Scope) -> bool {
+        return get_optional_val("due", scope).is_some_and(|due| due.get_bool());
+    }
+    fn get_guess(scope: &mut Scope) -> Result<f64, ()> {
+        let Some(guess) = get_optional_val("guess", scope) else {
+            return Ok(DEFAULT_GUESS);
+        };
+        let guess = guess.get_f64()?;
+        if guess <= -1.0 {
+            return Err(math_error("the guess of rate should be greater than -1")?);
+        }
+        return Ok(guess);
+    }
+
+    fn get_cashflows(scope: &mut Scope) -> Result<Vec<f64>, ()> {
+        let cashflows = get_num_arr("cashflows", scope)?;
+        let cashflows: Vec<f64> = cashflows.iter().map(Number::float_value).collect();
+        let has_positive = cashflows.iter().any(|cashflow| *cashflow > 0.0);
+        let has_negative = cashflows.iter().any(|cashflow| *cashflow < 0.0);
+        if !has_positive || !has_negative {
+            return Err(math_error(
+                "at least one positive and one negative cash flow are expected",
+            )?);
+        }
+        return Ok(cashflows);
+    }
+    // the dates are "YYYY-MM-DD" Strings or the Numbers of days
+    fn get_dates(scope: &mut Scope) -> Result<Vec<f64>, ()> {
+        let value = get_val("dates", scope)?;
+        let arr_value = match value {
+            Value::Object(_) => get_self_prop(&value, "v")?,
+            _ => value,
+        };
+        let Value::Array(arr) = arr_value else {
+            return Err(type_error(
+                Some("dates"),
+                vec![ValueType::Array],
+                arr_value.get_type(),
+            )?);
+        };
+
+        let mut result = vec![];
+        for element in arr.as_ref().borrow().iter() {
+            let days = match element {
+                Value::Number(num) => num.float_value(),
+                Value::String(_) => {
+                    let text = element.get_str()?;
+                    let Some(days) = parse_date(&text) else {
+                        let msg = format!("invalid date \"{}\", expected YYYY-MM-DD", text);
+                        return Err(syntax_error(&msg)?);
+                    };
+                    days as f64
+                }
+                _ => {
+                    return Err(type_error(
+                        Some("dates"),
+                        vec![ValueType::String, ValueType::Number],
+                        element.get_type(),
+                    )?)
+                }
+            };
+            result.push(days);
+        }
+        return Ok(result);
+    }
+}
+
+impl ObjectModule for FinanceModule {
+    fn module_object() -> Object {
+        let pv = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "rate"),
+                BuildInFnParam(ValueType::Number, "nper"),
+                BuildInFnParam(ValueType::Number, "pmt"),
+                BuildInFnParam(ValueType::Number, "fv?"),
+                BuildInFnParam(ValueType::Boolean, "due?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::PV),
+        };
+        let fv = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "rate"),
+                BuildInFnParam(ValueType::Number, "nper"),
+                BuildInFnParam(ValueType::Number, "pmt"),
+                BuildInFnParam(ValueType::Number, "pv?"),
+                BuildInFnParam(ValueType::Boolean, "due?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::FV),
+        };
+        let pmt = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "rate"),
+                BuildInFnParam(ValueType::Number, "nper"),
+                BuildInFnParam(ValueType::Number, "pv"),
+                BuildInFnParam(ValueType::Number, "fv?"),
+                BuildInFnParam(ValueType::Boolean, "due?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::PMT),
+        };
+        let nper = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "rate"),
+                BuildInFnParam(ValueType::Number, "pmt"),
+                BuildInFnParam(ValueType::Number, "pv"),
+                BuildInFnParam(ValueType::Number, "fv?"),
+                BuildInFnParam(ValueType::Boolean, "due?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::NPER),
+        };
+        let rate = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "nper"),
+                BuildInFnParam(ValueType::Number, "pmt"),
+                BuildInFnParam(ValueType::Number, "pv"),
+                BuildInFnParam(ValueType::Number, "fv?"),
+                BuildInFnParam(ValueType::Boolean, "due?"),
+                BuildInFnParam(ValueType::Number, "guess?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::RATE),
+        };
+
+        let npv = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "rate"),
+                BuildInFnParam(ValueType::Void, "cashflows"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::NPV),
+        };
+        let irr = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "cashflows"),
+                BuildInFnParam(ValueType::Number, "guess?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::IRR),
+        };
+        let xirr = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "cashflows"),
+                BuildInFnParam(ValueType::Void, "dates"),
+                BuildInFnParam(ValueType::Number, "guess?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::XIRR),
+        };
+        let amortize = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "rate"),
+                BuildInFnParam(ValueType::Number, "nper"),
+                BuildInFnParam(ValueType::Number, "pv"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::AMORTIZE),
+        };
+        let rounding = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::String, "mode?"),
+            ],
+            identi: BuildInFnIdenti::Finance(Self::ROUNDING),
+        };
+
+        let module_obj_props = vec![
+            (String::from("pv"), Value::from(pv)),
+            (String::from("fv"), Value::from(fv)),
+            (String::from("pmt"), Value::from(pmt)),
+            (String::from("nper"), Value::from(nper)),
+            (String::from("rate"), Value::from(rate)),
+            (String::from("npv"), Value::from(npv)),
+            (String::from("irr"), Value::from(irr)),
+            (String::from("xirr"), Value::from(xirr)),
+            (String::from("amortize"), Value::from(amortize)),
+            (String::from("rounding"), Value::from(rounding)),
+        ];
+        return Object::new(module_obj_props, None);
+    }
+}
+
+impl BuildInFnCall for FinanceModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let result = match self {
+            Self::PV | Self::FV | Self::PMT => {
+                let rate = Self::get_real("rate", scope)?;
+                let nper = Self::get_real("nper", scope)?;
+                let is_due = Self::get_due(scope);
+                let (growth, annuity) = time_value(&rate, &nper, is_due);
+
+                // pv * growth + pmt * annuity + fv == 0
+                let amount = match self {
+                    Self::PV => {
+                        let pmt = Self::get_real("pmt", scope)?;
+                        let fv = Self::get_optional_real("fv", scope)?;
+                        (fv + pmt * annuity).true_div(&growth)
+                    }
+                    Self::FV => {
+                        let pmt = Self::get_real("pmt", scope)?;
+                        let pv = Self::get_optional_real("pv", scope)?;
+                        pv * growth + pmt * annuity
+                    }
+                    Self::PMT => {
+                        let pv = Self::get_real("pv", scope)?;
+                        let fv = Self::get_optional_real("fv", scope)?;
+                        if annuity.is_zero() {
+                            return Err(math_error("the count of periods should not be ZERO")?);
+                        }
+                        (fv + pv * growth).true_div(&annuity)
+                    }
+                    _ => unreachable!(),
+                };
+                money(Number::Int(0) - amount)
+            }
+            Self::NPER => {
+                let rate = Self::get_real("rate", scope)?;
+                let pmt = Self::get_real("pmt", scope)?;
+                let pv = Self::get_real("pv", scope)?;
+                let fv = Self::get_optional_real("fv", scope)?;
+                let is_due = Self::get_due(scope);
+
+                if rate.is_zero() {
+                    if pmt.is_zero() {
+                        return Err(math_error("the payment should not be ZERO")?);
+                    }
+                    let periods = (Number::Int(0) - (fv + pv)).true_div(&pmt);
+                    return Ok(Value::from(quotient(periods, &Number::Int(1))));
+                }
+
+                // (1 + rate) ^ nper == (annuity_pv - fv) / (annuity_pv + pv)
+                let (rate, pmt, pv, fv) = (
+                    rate.float_value(),
+                    pmt.float_value(),
+                    pv.float_value(),
+                    fv.float_value(),
+                );
+                let timing = if is_due { 1.0 + rate } else { 1.0 };
+                let annuity_pv = pmt * timing / rate;
+                let growth = (annuity_pv - fv) / (annuity_pv + pv);
+                if growth.is_nan() || growth <= 0.0 || rate <= -1.0 {
+                    return Err(math_error(
+                        "the future value can not be reached with the payment",
+                    )?);
+                }
+                Value::from(growth.ln() / rate.ln_1p())
+            }
+            Self::RATE => {
+                let nper = Self::get_real("nper", scope)?.float_value();
+                let pmt = Self::get_real("pmt", scope)?.float_value();
+                let pv = Self::get_real("pv", scope)?.float_value();
+                let fv = Self::get_optional_real("fv", scope)?.float_value();
+                let is_due = Self::get_due(scope);
+                let guess = Self::get_guess(scope)?;
+
+                let balance = |rate: f64| {
+                    if rate == 0.0 {
+                        return pv + pmt * nper + fv;
+                    }
+                    let growth = (1.0 + rate).powf(nper);
+                    let timing = if is_due { 1.0 + rate } else { 1.0 };
+                    return pv * growth + pmt * timing * (growth - 1.0) / rate + fv;
+                };
+                let scale = pv.abs() + (pmt * nper).abs() + fv.abs();
+                let Some(rate) = solve_rate(balance, guess, scale) else {
+                    return Err(math_error("the rate is not found, try another guess")?);
+                };
+                Value::from(rate)
+            }
+
+            Self::NPV => {
+                let rate = Self::get_real("rate", scope)?;
+                let cashflows = get_num_arr("cashflows", scope)?;
+
+                // the cash flows are at the end of periods
+                let base = Number::Int(1) + rate;
+                let mut discount = Number::Int(1);
+                let mut npv = Number::Int(0);
+                for cashflow in cashflows {
+                    discount = discount * base.clone();
+                    npv = npv + cashflow.true_div(&discount);
+                }
+                money(npv)
+            }
+            Self::IRR | Self::XIRR => {
+                let cashflows = Self::get_cashflows(scope)?;
+                // the years since the first cash flow
+                let years: Vec<f64> = match self {
+                    Self::IRR => (0..cashflows.len()).map(|period| period as f64).collect(),
+                    _ => {
+                        let dates = Self::get_dates(scope)?;
+                        if dates.len() != cashflows.len() {
+                            return Err(math_error(
+                                "`cashflows` and `dates` should have the same length",
+                            )?);
+                        }
+                        dates
+                            .iter()
+                            .map(|date| (date - dates[0]) / YEAR_DAYS)
+                            .collect()
+                    }
+                };
+                let guess = Self::get_guess(scope)?;
+
+                let npv = |rate: f64| {
+                    cashflows
+                        .iter()
+                        .zip(&years)
+                        .map(|(cashflow, year)| cashflow / (1.0 + rate).powf(*year))
+                        .sum::<f64>()
+                };
+                let scale = cashflows.iter().map(|cashflow| cashflow.abs()).sum();
+                let Some(rate) = solve_rate(npv, guess, scale) else {
+                    return Err(math_error("the rate is not found, try another guess")?);
+                };
+                Value::from(rate)
+            }
+            Self::AMORTIZE => {
+                let rate = Self::get_real("rate", scope)?;
+                let nper = Self::get_real("nper", scope)?;
+                let pv = Self::get_real("pv", scope)?;
+                let Number::Int(period_count @ 1..) = nper else {
+                    return Err(math_error(
+                        "the count of periods should be a positive integer",
+                    )?);
+                };
+
+                // the amounts are counted in cents so that
+                // the rounded amounts are computed exactly.
+                let (growth, annuity) = time_value(&rate, &nper, false);
+                let payment = to_cents(&(pv.clone() * growth).true_div(&annuity));
+                let mut balance = to_cents(&pv);
+
+                let mut schedule = ArrayLiteral::new();
+                for period in 1..=period_count {
+                    let interest = round_cents(balance.clone() * rate.clone());
+                    let payment = match period == period_count {
+                        // the last payment clears the balance
+                        true => balance.clone() + interest.clone(),
+                        false => payment.clone(),
+                    };
+                    let principal = payment.clone() - interest.clone();
+                    balance = match period == period_count {
+                        true => Number::Int(0),
+                        false => balance - principal.clone(),
+                    };
+
+                    let row = HashMap::from([
+                        (String::from("period"), Value::from(period)),
+                        (String::from("payment"), Value::from(from_cents(payment))),
+                        (String::from("interest"), Value::from(from_cents(interest))),
+                        (
+                            String::from("principal"),
+                            Value::from(from_cents(principal)),
+                        ),
+                        (
+                            String::from("balance"),
+                            Value::from(from_cents(balance.clone())),
+                        ),
+                    ]);
+                    schedule.push_back(Value::from(RawMap::new(row)));
+                }
+                Value::from(schedule)
+            }
+
+            Self::ROUNDING => {
+                let Some(mode_value) = get_optional_val("mode", scope) else {
+                    // returns current rounding mode
+                    return Ok(Value::from(rounding_mode().to_string()));
+                };
+                let spec = mode_value.get_str()?;
+                let Some(mode) = RoundingMode::parse(&spec) else {
+                    let msg = format!(
+                        "invalid rounding mode \"{}\", expected half_up, half_down, half_even, up, down, ceil, floor or none",
+                        spec
+                    );
+                    return Err(syntax_error(&msg)?);
+                };
+                unsafe { ENV_OPTION.rounding_mode = mode };
+                return Ok(Value::EMPTY);
+            }
+        };
+        return Ok(result);
+    }
+}
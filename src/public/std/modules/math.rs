@@ -1,14 +1,32 @@
-use crate::public::error::math_error;
+use std::f64::consts::FRAC_PI_2;
+
+use crate::public::env::{AngleMode, ENV_OPTION};
+use crate::public::error::{math_error, syntax_error, type_error};
 use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::constants::precision_constants;
 use crate::public::run_time::scope::Scope;
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::big_decimal::BigDecimal;
+use crate::public::value::big_int::BigInt;
+use crate::public::value::complex::Complex;
 use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::interval::Interval;
+use crate::public::value::number::Number;
 use crate::public::value::oop::object::Object;
 use crate::public::value::value::{Value, ValueType};
 
-use super::super::utils::get_val::get_val;
+use super::super::symbolic::diff::differentiate;
+use super::super::symbolic::expr::Expr;
+use super::super::symbolic::simplify::simplify;
+use super::super::utils::get_val::{get_optional_val, get_val};
+use super::super::utils::special_fn;
 use super::{BuildInFnCall, ObjectModule};
 
+// the max significant digits of decimal numbers
+const MAX_DECIMAL_PRECISION: i64 = 1000;
+
 #[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum MathModule {
     SIN,
     COS,
@@ -30,14 +48,34 @@ pub enum MathModule {
     SQRT,
     FLOOR,
     ROUND,
+
+    GAMMA,
+    LGAMMA,
+    ERF,
+    ERFC,
+    BETA,
+
+    ARG,
+    CONJ,
+    RE,
+    IM,
+
+    CONTFRAC,
+    DIFF,
+    SIMPLIFY,
+    EXPAND,
+
+    ANGLEMODE,
+    PRECISION,
 }
 
 impl ObjectModule for MathModule {
     fn module_object() -> Object {
+        // the input is Number or Interval
         let sin = BuildInFunction {
             params: vec![
                 BuildInFnParam(ValueType::Object, "self"),
-                BuildInFnParam(ValueType::Number, "input"),
+                BuildInFnParam(ValueType::Void, "input"),
             ],
             identi: BuildInFnIdenti::Math(Self::SIN),
         };
@@ -59,6 +97,20 @@ impl ObjectModule for MathModule {
         let mut sqrt = sin.clone();
         let mut floor = sin.clone();
         let mut round = sin.clone();
+        let mut gamma = sin.clone();
+        let mut lgamma = sin.clone();
+        let mut erf = sin.clone();
+        let mut erfc = sin.clone();
+        let arg = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "input"),
+            ],
+            identi: BuildInFnIdenti::Math(Self::ARG),
+        };
+        let mut conj = arg.clone();
+        let mut re = arg.clone();
+        let mut im = arg.clone();
 
         cos.identi = BuildInFnIdenti::Math(Self::COS);
         tan.identi = BuildInFnIdenti::Math(Self::TAN);
@@ -78,6 +130,13 @@ impl ObjectModule for MathModule {
         sqrt.identi = BuildInFnIdenti::Math(Self::SQRT);
         floor.identi = BuildInFnIdenti::Math(Self::FLOOR);
         round.identi = BuildInFnIdenti::Math(Self::ROUND);
+        gamma.identi = BuildInFnIdenti::Math(Self::GAMMA);
+        lgamma.identi = BuildInFnIdenti::Math(Self::LGAMMA);
+        erf.identi = BuildInFnIdenti::Math(Self::ERF);
+        erfc.identi = BuildInFnIdenti::Math(Self::ERFC);
+        conj.identi = BuildInFnIdenti::Math(Self::CONJ);
+        re.identi = BuildInFnIdenti::Math(Self::RE);
+        im.identi = BuildInFnIdenti::Math(Self::IM);
 
         let log = BuildInFunction {
             params: vec![
@@ -87,6 +146,53 @@ impl ObjectModule for MathModule {
             ],
             identi: BuildInFnIdenti::Math(Self::LOG),
         };
+        let beta = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "a"),
+                BuildInFnParam(ValueType::Number, "b"),
+            ],
+            identi: BuildInFnIdenti::Math(Self::BETA),
+        };
+        let contfrac = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "input"),
+                BuildInFnParam(ValueType::Number, "n"),
+            ],
+            identi: BuildInFnIdenti::Math(Self::CONTFRAC),
+        };
+        let diff = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::LazyExpression, "expr"),
+                BuildInFnParam(ValueType::String, "var"),
+            ],
+            identi: BuildInFnIdenti::Math(Self::DIFF),
+        };
+        let simplify = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::LazyExpression, "expr"),
+            ],
+            identi: BuildInFnIdenti::Math(Self::SIMPLIFY),
+        };
+        let mut expand = simplify.clone();
+        expand.identi = BuildInFnIdenti::Math(Self::EXPAND);
+        let angle_mode = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::String, "mode?"),
+            ],
+            identi: BuildInFnIdenti::Math(Self::ANGLEMODE),
+        };
+        let precision = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "digits?"),
+            ],
+            identi: BuildInFnIdenti::Math(Self::PRECISION),
+        };
 
         let module_obj_props = vec![
             (String::from("sin"), Value::from(sin)),
@@ -109,11 +215,169 @@ impl ObjectModule for MathModule {
             (String::from("sqrt"), Value::from(sqrt)),
             (String::from("floor"), Value::from(floor)),
             (String::from("round"), Value::from(round)),
+            (String::from("gamma"), Value::from(gamma)),
+            (String::from("lgamma"), Value::from(lgamma)),
+            (String::from("beta"), Value::from(beta)),
+            (String::from("erf"), Value::from(erf)),
+            (String::from("erfc"), Value::from(erfc)),
+            (String::from("arg"), Value::from(arg)),
+            (String::from("conj"), Value::from(conj)),
+            (String::from("re"), Value::from(re)),
+            (String::from("im"), Value::from(im)),
+            (String::from("contfrac"), Value::from(contfrac)),
+            (String::from("diff"), Value::from(diff)),
+            (String::from("simplify"), Value::from(simplify)),
+            (String::from("expand"), Value::from(expand)),
+            (String::from("angle_mode"), Value::from(angle_mode)),
+            (String::from("precision"), Value::from(precision)),
         ];
         return Object::new(module_obj_props, None);
     }
 }
 
+impl MathModule {
+    // trigonometric functions with the angle in current angle mode
+    fn trigonometric(&self, angle: f64) -> Result<f64, ()> {
+        let angle_mode = unsafe { ENV_OPTION.angle_mode };
+        if let Some(turns) = angle_mode.quarter_turns(angle) {
+            // the exact values at the multiples of quarter turn
+            const SIN_VALUES: [f64; 4] = [0.0, 1.0, 0.0, -1.0];
+            let result = match self {
+                Self::SIN => SIN_VALUES[turns as usize],
+                Self::COS => SIN_VALUES[(turns as usize + 1) % 4],
+                Self::TAN if turns % 2 == 0 => 0.0,
+                Self::TAN => {
+                    return Err(math_error(
+                        "tangent is undefined for the odd multiples of right angle",
+                    )?)
+                }
+                _ => unreachable!(),
+            };
+            return Ok(result);
+        }
+
+        let radians = angle_mode.to_radians(angle);
+        let result = match self {
+            Self::SIN => radians.sin(),
+            Self::COS => radians.cos(),
+            Self::TAN => radians.tan(),
+            _ => unreachable!(),
+        };
+        return Ok(result);
+    }
+
+    // the functions computed in decimal mode, returns None
+    // when the function or the input is not supported.
+    fn decimal_call(&self, num: &Number, precision: usize) -> Option<Number> {
+        let decimal = num.decimal_value(precision)?;
+        let is_positive = !decimal.is_negative() && !decimal.is_zero();
+        let working = precision + 10;
+        let result = match self {
+            Self::SQRT if !decimal.is_negative() => decimal.sqrt(precision),
+            Self::EXP => decimal.exp(precision),
+            Self::LN if is_positive => decimal.ln(precision),
+            Self::LOG10 | Self::LOG2 if is_positive => {
+                let base = BigInt::from(if *self == Self::LOG10 { 10_i64 } else { 2 });
+                let base_ln = BigDecimal::from(base).ln(working);
+                decimal.ln(working).div(&base_ln, precision)
+            }
+
+            // the exact functions keep the decimal input exact
+            Self::ABS if matches!(num, Number::Decimal(_)) => decimal.abs(),
+            Self::FLOOR if matches!(num, Number::Decimal(_)) => {
+                return Some(Number::floor_div(num.clone(), Number::Int(1)));
+            }
+            Self::ROUND if matches!(num, Number::Decimal(_)) => {
+                return Some(Number::Decimal(decimal.quantize(0)).int());
+            }
+            _ => return None,
+        };
+        return Some(Number::Decimal(result));
+    }
+
+    fn complex_call(&self, z: Complex) -> Result<Value, ()> {
+        let result = match self {
+            Self::SIN => z.sin(),
+            Self::COS => z.cos(),
+            Self::TAN => z.tan(),
+            Self::SINH => z.sinh(),
+            Self::COSH => z.cosh(),
+            Self::TANH => z.tanh(),
+            Self::LOG10 => z.ln() / Complex::new(10_f64.ln(), 0.0),
+            Self::LOG2 => z.ln() / Complex::new(2_f64.ln(), 0.0),
+            Self::LN => z.ln(),
+            Self::EXP => z.exp(),
+            Self::SQRT => z.sqrt(),
+            Self::ABS => return Ok(Value::from(z.abs())),
+            _ => {
+                return Err(math_error(
+                    "complex number is not supported by this function",
+                )?)
+            }
+        };
+        return Ok(Value::from(Number::from_complex(result)));
+    }
+
+    // the functions computed with interval input,
+    // the angles are converted with current angle mode.
+    fn interval_call(&self, itv: Interval) -> Result<Value, ()> {
+        let angle_mode = unsafe { ENV_OPTION.angle_mode };
+        let to_radians = |itv: Interval| match angle_mode {
+            AngleMode::Radian => itv,
+            _ => itv.scale(angle_mode.to_radians(1.0)),
+        };
+        let from_radians = |itv: Interval| match angle_mode {
+            AngleMode::Radian => itv,
+            _ => itv.scale(angle_mode.to_angle(1.0)),
+        };
+        let check_domain = |is_valid: bool| {
+            if !is_valid {
+                return math_error("the interval is out of the domain of this function");
+            }
+            return Ok(());
+        };
+
+        let result = match self {
+            Self::SIN => to_radians(itv).periodic(f64::sin, FRAC_PI_2),
+            Self::COS => to_radians(itv).periodic(f64::cos, 0.0),
+            Self::TAN => to_radians(itv).tan()?,
+            Self::ASIN | Self::ACOS => {
+                check_domain(itv.lower() >= -1.0 && itv.upper() <= 1.0)?;
+                let radians = match self {
+                    Self::ASIN => itv.monotonic(f64::asin, true),
+                    _ => itv.monotonic(f64::acos, false),
+                };
+                from_radians(radians)
+            }
+            Self::ATAN => from_radians(itv.monotonic(f64::atan, true)),
+            Self::SINH => itv.monotonic(f64::sinh, true),
+            Self::COSH => itv.valley(f64::cosh, 0.0),
+            Self::TANH => itv.monotonic(f64::tanh, true),
+            Self::RAD => itv.scale(1_f64.to_radians()),
+            Self::DEG => itv.scale(1_f64.to_degrees()),
+            Self::LOG10 | Self::LOG2 | Self::LN => {
+                check_domain(itv.lower() > 0.0)?;
+                let f = match self {
+                    Self::LOG10 => f64::log10,
+                    Self::LOG2 => f64::log2,
+                    _ => f64::ln,
+                };
+                itv.monotonic(f, true)
+            }
+            Self::EXP => itv.monotonic(f64::exp, true).at_least(0.0),
+            Self::ABS => itv.abs(),
+            Self::SQRT => {
+                check_domain(itv.lower() >= 0.0)?;
+                itv.monotonic(f64::sqrt, true).at_least(0.0)
+            }
+            Self::FLOOR => itv.exact_monotonic(f64::floor),
+            Self::ROUND => itv.exact_monotonic(f64::round),
+            _ => return Err(math_error("interval is not supported by this function")?),
+        };
+        return Ok(Value::from(result));
+    }
+}
+
 impl BuildInFnCall for MathModule {
     fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
         let result = match self {
@@ -121,18 +385,176 @@ impl BuildInFnCall for MathModule {
                 let base = get_val("base", scope)?;
                 let natural = get_val("natural", scope)?;
 
-                let base_f = base.get_f64()?;
-                let nature_f = natural.get_f64()?;
-                nature_f.log(base_f)
+                // ln(natural) / ln(base) in decimal mode
+                let precision = unsafe { ENV_OPTION.decimal_precision };
+                if let (true, Value::Number(base_num), Value::Number(natural_num)) =
+                    (precision > 0, &base, &natural)
+                {
+                    let working = precision + 10;
+                    let base_ln = Self::LN.decimal_call(base_num, working);
+                    let natural_ln = Self::LN.decimal_call(natural_num, working);
+                    if let (Some(Number::Decimal(base_ln)), Some(Number::Decimal(natural_ln))) =
+                        (base_ln, natural_ln)
+                    {
+                        if !base_ln.is_zero() {
+                            let result = natural_ln.div(&base_ln, precision);
+                            return Ok(Value::from(Number::Decimal(result)));
+                        }
+                    }
+                }
+
+                let base_z = base.get_complex()?;
+                let nature_z = natural.get_complex()?;
+                if base_z.im != 0.0 || nature_z.im != 0.0 || base_z.re < 0.0 || nature_z.re < 0.0 {
+                    let result = nature_z.ln() / base_z.ln();
+                    return Ok(Value::from(Number::from_complex(result)));
+                }
+                nature_z.re.log(base_z.re)
+            }
+            Self::BETA => {
+                let a = get_val("a", scope)?.get_f64()?;
+                let b = get_val("b", scope)?.get_f64()?;
+                if a <= 0.0 || b <= 0.0 {
+                    return Err(math_error(
+                        "the params of beta function should be positive",
+                    )?);
+                }
+                special_fn::beta(a, b)
+            }
+            Self::CONTFRAC => {
+                let input = get_val("input", scope)?;
+                let n = get_val("n", scope)?;
+
+                let Value::Number(num) = input else {
+                    unreachable!()
+                };
+                let term_count = n.get_i64()?;
+                if term_count < 0 {
+                    return Err(math_error("the count of terms should not be negative")?);
+                }
+                let Some(terms) = num.continued_fraction(term_count as usize) else {
+                    return Err(math_error(
+                        "only real number can be expanded to continued fraction",
+                    )?);
+                };
+
+                let terms_arr: ArrayLiteral = terms.into_iter().map(Value::from).collect();
+                return Ok(Value::from(terms_arr));
+            }
+            Self::DIFF => {
+                let Value::LazyExpression(l_expr) = get_val("expr", scope)? else {
+                    unreachable!()
+                };
+                let var_value = get_val("var", scope)?;
+                let var = var_value.get_str()?;
+
+                let expr = Expr::from_node(&l_expr.borrow())?;
+                let derivative = differentiate(&expr, &var)?;
+                return Ok(Value::from(derivative.to_node()));
+            }
+            Self::SIMPLIFY | Self::EXPAND => {
+                let Value::LazyExpression(l_expr) = get_val("expr", scope)? else {
+                    unreachable!()
+                };
+                let expr = Expr::from_node(&l_expr.borrow())?;
+                let result = simplify(&expr, *self == Self::EXPAND);
+                return Ok(Value::from(result.to_node()));
+            }
+            Self::ANGLEMODE => {
+                let Some(mode_value) = get_optional_val("mode", scope) else {
+                    // returns current angle mode
+                    let angle_mode = unsafe { ENV_OPTION.angle_mode };
+                    return Ok(Value::from(angle_mode.to_string()));
+                };
+                let spec = mode_value.get_str()?;
+                let Some(mode) = AngleMode::parse(&spec) else {
+                    let msg = format!("invalid angle mode \"{}\", expected rad, deg or grad", spec);
+                    return Err(syntax_error(&msg)?);
+                };
+                unsafe { ENV_OPTION.angle_mode = mode };
+                return Ok(Value::EMPTY);
+            }
+            Self::PRECISION => {
+                let Some(digits_value) = get_optional_val("digits", scope) else {
+                    // returns current precision
+                    let precision = unsafe { ENV_OPTION.decimal_precision };
+                    return Ok(Value::from(precision as i64));
+                };
+                let digits = digits_value.get_i64()?;
+                if !(0..=MAX_DECIMAL_PRECISION).contains(&digits) {
+                    let msg = format!(
+                        "the precision should be in the range of 0 to {}",
+                        MAX_DECIMAL_PRECISION
+                    );
+                    return Err(math_error(&msg)?);
+                }
+                // ZERO precision disables the decimal mode
+                unsafe { ENV_OPTION.decimal_precision = digits as usize };
+                for (name, value) in precision_constants(digits as usize) {
+                    scope.global.variables.insert(String::from(name), value);
+                }
+                return Ok(Value::EMPTY);
+            }
+            Self::ARG | Self::CONJ | Self::RE | Self::IM => {
+                let input = get_val("input", scope)?;
+                let z = input.get_complex()?;
+
+                return Ok(match self {
+                    Self::ARG => Value::from(z.arg()),
+                    Self::CONJ => match input {
+                        Value::Number(Number::Complex(_)) => Value::from(Number::Complex(z.conj())),
+                        _ => input,
+                    },
+                    Self::RE => match input {
+                        Value::Number(Number::Complex(_)) => Value::from(z.re),
+                        _ => input,
+                    },
+                    Self::IM => match input {
+                        Value::Number(Number::Complex(_)) => Value::from(z.im),
+                        _ => Value::from(0),
+                    },
+                    _ => unreachable!(),
+                });
             }
             _ => {
                 let input = get_val("input", scope)?;
-                let f = input.get_f64()?;
+                match input {
+                    // the exact number is kept exact
+                    Value::Number(num) if *self == Self::ABS => return Ok(Value::from(num.abs())),
+                    Value::Number(_) => {}
+                    Value::Interval(itv) => return self.interval_call(itv),
+                    _ => {
+                        return Err(type_error(
+                            Some("input"),
+                            vec![ValueType::Number, ValueType::Interval],
+                            input.get_type(),
+                        )?)
+                    }
+                }
+
+                let precision = unsafe { ENV_OPTION.decimal_precision };
+                if let (true, Value::Number(num)) = (precision > 0, &input) {
+                    if let Some(result) = self.decimal_call(num, precision) {
+                        return Ok(Value::from(result));
+                    }
+                }
+
+                let z = input.get_complex()?;
+                let f = z.re;
+
+                // complex input, or negative input for functions
+                // whose real result is undefined, uses complex version.
+                let is_complex_domain = match self {
+                    Self::SQRT | Self::LN | Self::LOG10 | Self::LOG2 => f < 0.0,
+                    _ => false,
+                };
+                // the complex angle is always in radians
+                if z.im != 0.0 || is_complex_domain {
+                    return self.complex_call(z);
+                }
 
                 match self {
-                    Self::SIN => f.sin(),
-                    Self::COS => f.cos(),
-                    Self::TAN => f.tan(),
+                    Self::SIN | Self::COS | Self::TAN => self.trigonometric(f)?,
                     Self::SINH => f.sinh(),
                     Self::COSH => f.cosh(),
                     Self::TANH => f.tanh(),
@@ -146,9 +568,24 @@ impl BuildInFnCall for MathModule {
                     Self::SQRT => f.sqrt(),
                     Self::FLOOR => f.floor(),
                     Self::ROUND => f.round(),
+                    Self::GAMMA | Self::LGAMMA => {
+                        if f <= 0.0 && f == f.floor() {
+                            return Err(math_error(
+                                "gamma function is undefined for non-positive integers",
+                            )?);
+                        }
+                        match self {
+                            Self::GAMMA => special_fn::gamma(f),
+                            Self::LGAMMA => special_fn::lgamma(f),
+                            _ => unreachable!(),
+                        }
+                    }
+                    Self::ERF => special_fn::erf(f),
+                    Self::ERFC => special_fn::erfc(f),
 
                     Self::ASIN | Self::ACOS | Self::ATAN => {
-                        if f < -1.0 || f > 1.0 {
+                        // the domain of `atan` is all the real numbers
+                        if matches!(self, Self::ASIN | Self::ACOS) && (f < -1.0 || f > 1.0) {
                             // inverse trigonometric function error.
                             return Err(
                                 math_error(
@@ -156,12 +593,14 @@ impl BuildInFnCall for MathModule {
                                 )?
                             );
                         }
-                        match self {
+                        let radians = match self {
                             Self::ASIN => f.asin(),
                             Self::ACOS => f.acos(),
                             Self::ATAN => f.atan(),
                             _ => unreachable!(),
-                        }
+                        };
+                        // the result angle is in current angle mode
+                        unsafe { ENV_OPTION.angle_mode }.to_angle(radians)
                     }
                     _ => unreachable!(),
                 }
@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use crate::public::error::{internal_error, math_error, InternalComponent};
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::map::RawMap;
+use crate::public::value::matrix::Matrix;
+use crate::public::value::number::Number;
+use crate::public::value::oop::object::Object;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_num_arr::to_num_arr;
+use super::super::utils::get_val::get_val;
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum MatrixModule {
+    FROM,
+    IDENTITY,
+    ZEROS,
+
+    SHAPE,
+    TOARRAY,
+    TRANSPOSE,
+    DET,
+    INVERSE,
+    RANK,
+    LU,
+
+    SOLVE,
+}
+
+// the max row or column count of the created matrix
+const MAX_SIZE: i64 = 1000;
+
+impl MatrixModule {
+    fn get_matrix(val_name: &str, scope: &mut Scope) -> Result<Matrix, ()> {
+        let Value::Matrix(mat) = get_val(val_name, scope)? else {
+            // the param type is checked before invocation
+            return Err(internal_error(
+                InternalComponent::Std,
+                "invalid `MatrixModule::get_matrix` invocation",
+            )?);
+        };
+        let result = mat.borrow().clone();
+        return Ok(result);
+    }
+    fn get_square_matrix(val_name: &str, scope: &mut Scope) -> Result<Matrix, ()> {
+        let mat = Self::get_matrix(val_name, scope)?;
+        if !mat.is_square() {
+            let msg = format!("square matrix is expected for param `{}`", val_name);
+            return Err(math_error(&msg)?);
+        }
+        return Ok(mat);
+    }
+    fn get_size(val_name: &str, scope: &mut Scope) -> Result<usize, ()> {
+        let value = get_val(val_name, scope)?;
+        match value {
+            Value::Number(Number::Int(num)) if (1..=MAX_SIZE).contains(&num) => {
+                return Ok(num as usize)
+            }
+            _ => {
+                let msg = format!(
+                    "integer between 1 and {} is expected for param `{}`",
+                    MAX_SIZE, val_name
+                );
+                return Err(math_error(&msg)?);
+            }
+        }
+    }
+
+    fn to_nested_array(mat: &Matrix) -> Value {
+        let rows: ArrayLiteral = (0..mat.rows())
+            .map(|index| {
+                let row: ArrayLiteral = mat.row(index).into_iter().map(Value::from).collect();
+                Value::from(row)
+            })
+            .collect();
+        return Value::from(rows);
+    }
+}
+
+impl ObjectModule for MatrixModule {
+    fn module_object() -> Object {
+        let from = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Array, "rows"),
+            ],
+            identi: BuildInFnIdenti::Matrix(Self::FROM),
+        };
+        let identity = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "size"),
+            ],
+            identi: BuildInFnIdenti::Matrix(Self::IDENTITY),
+        };
+        let zeros = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "rows"),
+                BuildInFnParam(ValueType::Number, "cols"),
+            ],
+            identi: BuildInFnIdenti::Matrix(Self::ZEROS),
+        };
+
+        let shape = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Matrix, "mat"),
+            ],
+            identi: BuildInFnIdenti::Matrix(Self::SHAPE),
+        };
+        let mut to_array = shape.clone();
+        let mut transpose = shape.clone();
+        let mut det = shape.clone();
+        let mut inverse = shape.clone();
+        let mut rank = shape.clone();
+        let mut lu = shape.clone();
+        to_array.identi = BuildInFnIdenti::Matrix(Self::TOARRAY);
+        transpose.identi = BuildInFnIdenti::Matrix(Self::TRANSPOSE);
+        det.identi = BuildInFnIdenti::Matrix(Self::DET);
+        inverse.identi = BuildInFnIdenti::Matrix(Self::INVERSE);
+        rank.identi = BuildInFnIdenti::Matrix(Self::RANK);
+        lu.identi = BuildInFnIdenti::Matrix(Self::LU);
+
+        let solve = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Matrix, "mat"),
+                BuildInFnParam(ValueType::Void, "b"),
+            ],
+            identi: BuildInFnIdenti::Matrix(Self::SOLVE),
+        };
+
+        let module_obj_props = vec![
+            (String::from("from"), Value::from(from)),
+            (String::from("identity"), Value::from(identity)),
+            (String::from("zeros"), Value::from(zeros)),
+            (String::from("shape"), Value::from(shape)),
+            (String::from("to_array"), Value::from(to_array)),
+            (String::from("transpose"), Value::from(transpose)),
+            (String::from("det"), Value::from(det)),
+            (String::from("inverse"), Value::from(inverse)),
+            (String::from("rank"), Value::from(rank)),
+            (String::from("lu"), Value::from(lu)),
+            (String::from("solve"), Value::from(solve)),
+        ];
+        return Object::new(module_obj_props, None);
+    }
+}
+
+impl BuildInFnCall for MatrixModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let result = match self {
+            Self::FROM => {
+                let Value::Array(arr) = get_val("rows", scope)? else {
+                    unreachable!()
+                };
+                let mut rows = vec![];
+                for row in arr.borrow().iter() {
+                    rows.push(to_num_arr(row.clone(), "rows")?);
+                }
+                let Some(mat) = Matrix::from_rows(rows) else {
+                    return Err(math_error("all the rows should have the same length")?);
+                };
+                if mat.rows() == 0 || mat.cols() == 0 {
+                    return Err(math_error("matrix should not be empty")?);
+                }
+                Value::from(mat)
+            }
+            Self::IDENTITY => {
+                let size = Self::get_size("size", scope)?;
+                Value::from(Matrix::identity(size))
+            }
+            Self::ZEROS => {
+                let rows = Self::get_size("rows", scope)?;
+                let cols = Self::get_size("cols", scope)?;
+                Value::from(Matrix::zeros(rows, cols))
+            }
+
+            Self::SHAPE => {
+                let mat = Self::get_matrix("mat", scope)?;
+                let shape = ArrayLiteral::from([
+                    Value::from(mat.rows() as i64),
+                    Value::from(mat.cols() as i64),
+                ]);
+                Value::from(shape)
+            }
+            Self::TOARRAY => {
+                let mat = Self::get_matrix("mat", scope)?;
+                Self::to_nested_array(&mat)
+            }
+            Self::TRANSPOSE => {
+                let mat = Self::get_matrix("mat", scope)?;
+                Value::from(mat.transpose())
+            }
+            Self::RANK => {
+                let mat = Self::get_matrix("mat", scope)?;
+                Value::from(mat.rank() as i64)
+            }
+            Self::DET => {
+                let mat = Self::get_square_matrix("mat", scope)?;
+                Value::from(mat.det())
+            }
+            Self::INVERSE => {
+                let mat = Self::get_square_matrix("mat", scope)?;
+                let Some(inversed) = mat.inverse() else {
+                    return Err(math_error("singular matrix is not invertible")?);
+                };
+                Value::from(inversed)
+            }
+            Self::LU => {
+                let mat = Self::get_square_matrix("mat", scope)?;
+                let (permutation, lower, upper) = mat.lu();
+                let result_map = HashMap::from([
+                    (String::from("P"), Value::from(permutation)),
+                    (String::from("L"), Value::from(lower)),
+                    (String::from("U"), Value::from(upper)),
+                ]);
+                Value::from(RawMap::new(result_map))
+            }
+
+            Self::SOLVE => {
+                let mat = Self::get_square_matrix("mat", scope)?;
+                // `b` can be either a Matrix or an Array of Numbers,
+                // the result is in the same type as `b`.
+                let b_value = get_val("b", scope)?;
+                let (b, is_vector) = match b_value {
+                    Value::Matrix(b) => (b.borrow().clone(), false),
+                    _ => {
+                        let column = to_num_arr(b_value, "b")?;
+                        (Matrix::new(column.len(), 1, column), true)
+                    }
+                };
+                if b.rows() != mat.rows() {
+                    let msg = format!("{} rows are expected for param `b`", mat.rows());
+                    return Err(math_error(&msg)?);
+                }
+
+                let Some(solution) = mat.solve(&b) else {
+                    return Err(math_error("the coefficient matrix is singular")?);
+                };
+                if is_vector {
+                    let x: ArrayLiteral = solution
+                        .transpose()
+                        .row(0)
+                        .into_iter()
+                        .map(Value::from)
+                        .collect();
+                    Value::from(x)
+                } else {
+                    Value::from(solution)
+                }
+            }
+        };
+        return Ok(result);
+    }
+}
@@ -1,9 +1,19 @@
 pub mod array;
 pub mod basic;
 pub mod bit_ops;
+pub mod consts;
+pub mod dist;
 pub mod file_system;
+pub mod finance;
 pub mod map;
 pub mod math;
+pub mod matrix;
+pub mod num_theory;
+pub mod numeric;
+pub mod polynomial;
+pub mod random;
+pub mod series;
+pub mod stats;
 pub mod string;
 
 use std::rc::Rc;
@@ -17,7 +27,7 @@ use super::StdModules;
 
 pub fn import_resolver(scope: &mut Scope, target_module: &StdModules, module_name: &str) {
     match target_module {
-        StdModules::Basic | StdModules::BitOps => {
+        StdModules::Basic | StdModules::BitOps | StdModules::Series => {
             let fn_list = target_module.get_fn_list();
             if let Some(completer) = &mut scope.completer {
                 for (k, _) in &fn_list {
@@ -28,12 +38,21 @@ pub fn import_resolver(scope: &mut Scope, target_module: &StdModules, module_nam
             scope.global.variables.extend(fn_list);
         }
 
-        StdModules::FileSystem | StdModules::Math => {
+        StdModules::FileSystem
+        | StdModules::Math
+        | StdModules::NumTheory
+        | StdModules::Stats
+        | StdModules::Matrix
+        | StdModules::Numeric
+        | StdModules::Random
+        | StdModules::Dist
+        | StdModules::Const
+        | StdModules::Finance => {
             let module_obj = target_module.get_obj_entry();
             scope.assign(String::from(module_name), Value::from(module_obj));
         }
 
-        StdModules::String | StdModules::Array | StdModules::Map => {
+        StdModules::String | StdModules::Array | StdModules::Map | StdModules::Polynomial => {
             let module_cls = target_module.get_cls_entry();
             scope.assign(String::from(module_name), Value::Class(module_cls));
         }
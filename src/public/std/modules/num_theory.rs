@@ -0,0 +1,439 @@
+use crate::public::error::math_error;
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::number::Number;
+use crate::public::value::oop::object::Object;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_val::get_val;
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum NumTheoryModule {
+    GCD,
+    LCM,
+    ISPRIME,
+    FACTORIZE,
+    NEXTPRIME,
+    MODPOW,
+    MODINV,
+    TOTIENT,
+    BINOMIAL,
+    FACTORIAL,
+    DIVISORS,
+}
+
+// the max input of `factorial`,
+// to avoid too long computing time.
+const MAX_FACTORIAL_INPUT: i64 = 10000;
+
+fn mul_mod(num1: u64, num2: u64, modulus: u64) -> u64 {
+    return ((num1 as u128) * (num2 as u128) % (modulus as u128)) as u64;
+}
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    return result;
+}
+
+// the modular functions of arbitrary-precision integers,
+// the Numbers are Int or BigInt and the modulus is positive.
+fn big_gcd(num1: Number, num2: Number) -> Number {
+    let (mut num1, mut num2) = (num1.abs(), num2.abs());
+    while !num2.is_zero() {
+        (num1, num2) = (num2.clone(), Number::modulo(num1, num2));
+    }
+    return num1;
+}
+fn big_pow_mod(base: Number, mut exp: Number, modulus: &Number) -> Number {
+    let two = Number::Int(2);
+    let mut result = Number::modulo(Number::Int(1), modulus.clone());
+    let mut base = Number::modulo(base, modulus.clone());
+    while !exp.is_zero() {
+        if !Number::modulo(exp.clone(), two.clone()).is_zero() {
+            result = Number::modulo(result * base.clone(), modulus.clone());
+        }
+        base = Number::modulo(base.clone() * base, modulus.clone());
+        exp = Number::floor_div(exp, two.clone());
+    }
+    return result;
+}
+fn big_modinv(num: Number, modulus: &Number) -> Option<Number> {
+    // extended Euclidean algorithm
+    let (mut old_r, mut r) = (Number::modulo(num, modulus.clone()), modulus.clone());
+    let (mut old_s, mut s) = (Number::Int(1), Number::Int(0));
+    while !r.is_zero() {
+        let quotient = Number::floor_div(old_r.clone(), r.clone());
+        (old_r, r) = (r.clone(), old_r - quotient.clone() * r);
+        (old_s, s) = (s.clone(), old_s - quotient * s);
+    }
+    if old_r != Number::Int(1) {
+        return None;
+    }
+    return Some(Number::modulo(old_s, modulus.clone()));
+}
+
+// deterministic Miller-Rabin test for 64-bit integers
+fn is_prime(num: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if num < 2 {
+        return false;
+    }
+    for base in BASES {
+        if num.is_multiple_of(base) {
+            return num == base;
+        }
+    }
+
+    let mut odd_part = num - 1;
+    let mut twos = 0;
+    while odd_part.is_multiple_of(2) {
+        odd_part /= 2;
+        twos += 1;
+    }
+    'witness: for base in BASES {
+        let mut x = pow_mod(base, odd_part, num);
+        if x == 1 || x == num - 1 {
+            continue;
+        }
+        for _ in 1..twos {
+            x = mul_mod(x, x, num);
+            if x == num - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    return true;
+}
+
+// find a nontrivial divisor of composite number
+fn pollard_rho(num: u64) -> u64 {
+    if num.is_multiple_of(2) {
+        return 2;
+    }
+    let mut increment = 1;
+    loop {
+        let next = |x: u64| (mul_mod(x, x, num) + increment) % num;
+        let (mut slow, mut fast, mut divisor) = (2, 2, 1);
+        while divisor == 1 {
+            slow = next(slow);
+            fast = next(next(fast));
+            divisor = Number::gcd(slow.abs_diff(fast) as i128, num as i128) as u64;
+        }
+        if divisor != num {
+            return divisor;
+        }
+        increment += 1;
+    }
+}
+
+// returns sorted (prime, exponent) pairs
+fn factorize(mut num: u64) -> Vec<(u64, u32)> {
+    let mut primes = vec![];
+    // trial division for small factors
+    for divisor in 2..1000 {
+        while num.is_multiple_of(divisor) {
+            primes.push(divisor);
+            num /= divisor;
+        }
+    }
+
+    let mut rest = vec![num];
+    while let Some(current) = rest.pop() {
+        if current == 1 {
+            continue;
+        }
+        if is_prime(current) {
+            primes.push(current);
+            continue;
+        }
+        let divisor = pollard_rho(current);
+        rest.push(divisor);
+        rest.push(current / divisor);
+    }
+    primes.sort();
+
+    let mut result: Vec<(u64, u32)> = vec![];
+    for prime in primes {
+        match result.last_mut() {
+            Some((last, exponent)) if *last == prime => *exponent += 1,
+            _ => result.push((prime, 1)),
+        }
+    }
+    return result;
+}
+
+impl NumTheoryModule {
+    // is also used by the postfix factorial operator
+    pub fn factorial(input: i64) -> Result<Number, ()> {
+        if input < 0 {
+            return Err(math_error("the input of factorial should not be negative")?);
+        }
+        if input > MAX_FACTORIAL_INPUT {
+            let msg = format!(
+                "the input of factorial should not be greater than {}",
+                MAX_FACTORIAL_INPUT
+            );
+            return Err(math_error(&msg)?);
+        }
+        let mut result = Number::Int(1);
+        for i in 2..=input {
+            result = result * Number::Int(i);
+        }
+        return Ok(result);
+    }
+
+    // the integer of any size
+    fn get_integer(val_name: &str, scope: &mut Scope) -> Result<Number, ()> {
+        let value = get_val(val_name, scope)?;
+        let Value::Number(num @ (Number::Int(_) | Number::BigInt(_))) = value else {
+            let msg = format!("integer is expected for param `{}`", val_name);
+            return Err(math_error(&msg)?);
+        };
+        return Ok(num);
+    }
+    fn get_positive_integer(val_name: &str, scope: &mut Scope) -> Result<Number, ()> {
+        let num = Self::get_integer(val_name, scope)?;
+        if num < Number::Int(1) {
+            let msg = format!("positive integer is expected for param `{}`", val_name);
+            return Err(math_error(&msg)?);
+        }
+        return Ok(num);
+    }
+    // the 64-bit integer for the functions based on prime factorization
+    fn get_int(val_name: &str, scope: &mut Scope) -> Result<i64, ()> {
+        let num = Self::get_integer(val_name, scope)?;
+        let Number::Int(num) = num else {
+            let msg = format!(
+                "param `{}` should be in the range of 64-bit integer [-2^63, 2^63 - 1]",
+                val_name
+            );
+            return Err(math_error(&msg)?);
+        };
+        return Ok(num);
+    }
+    fn get_positive_int(val_name: &str, scope: &mut Scope) -> Result<u64, ()> {
+        let num = Self::get_int(val_name, scope)?;
+        if num < 1 {
+            let msg = format!("positive integer is expected for param `{}`", val_name);
+            return Err(math_error(&msg)?);
+        }
+        return Ok(num as u64);
+    }
+}
+
+impl ObjectModule for NumTheoryModule {
+    fn module_object() -> Object {
+        let gcd = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "num1"),
+                BuildInFnParam(ValueType::Number, "num2"),
+            ],
+            identi: BuildInFnIdenti::NumTheory(Self::GCD),
+        };
+        let mut lcm = gcd.clone();
+        lcm.identi = BuildInFnIdenti::NumTheory(Self::LCM);
+
+        let is_prime = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "input"),
+            ],
+            identi: BuildInFnIdenti::NumTheory(Self::ISPRIME),
+        };
+        let mut factorize = is_prime.clone();
+        let mut next_prime = is_prime.clone();
+        let mut totient = is_prime.clone();
+        let mut factorial = is_prime.clone();
+        let mut divisors = is_prime.clone();
+        factorize.identi = BuildInFnIdenti::NumTheory(Self::FACTORIZE);
+        next_prime.identi = BuildInFnIdenti::NumTheory(Self::NEXTPRIME);
+        totient.identi = BuildInFnIdenti::NumTheory(Self::TOTIENT);
+        factorial.identi = BuildInFnIdenti::NumTheory(Self::FACTORIAL);
+        divisors.identi = BuildInFnIdenti::NumTheory(Self::DIVISORS);
+
+        let modpow = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "base"),
+                BuildInFnParam(ValueType::Number, "exp"),
+                BuildInFnParam(ValueType::Number, "modulus"),
+            ],
+            identi: BuildInFnIdenti::NumTheory(Self::MODPOW),
+        };
+        let modinv = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "input"),
+                BuildInFnParam(ValueType::Number, "modulus"),
+            ],
+            identi: BuildInFnIdenti::NumTheory(Self::MODINV),
+        };
+        let binomial = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "n"),
+                BuildInFnParam(ValueType::Number, "k"),
+            ],
+            identi: BuildInFnIdenti::NumTheory(Self::BINOMIAL),
+        };
+
+        let module_obj_props = vec![
+            (String::from("gcd"), Value::from(gcd)),
+            (String::from("lcm"), Value::from(lcm)),
+            (String::from("is_prime"), Value::from(is_prime)),
+            (String::from("factorize"), Value::from(factorize)),
+            (String::from("next_prime"), Value::from(next_prime)),
+            (String::from("modpow"), Value::from(modpow)),
+            (String::from("modinv"), Value::from(modinv)),
+            (String::from("totient"), Value::from(totient)),
+            (String::from("binomial"), Value::from(binomial)),
+            (String::from("factorial"), Value::from(factorial)),
+            (String::from("divisors"), Value::from(divisors)),
+        ];
+        return Object::new(module_obj_props, None);
+    }
+}
+
+impl BuildInFnCall for NumTheoryModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let result = match self {
+            Self::GCD | Self::LCM => {
+                let num1 = Self::get_integer("num1", scope)?;
+                let num2 = Self::get_integer("num2", scope)?;
+                let gcd = big_gcd(num1.clone(), num2.clone());
+                let result = match self {
+                    Self::GCD => gcd,
+                    Self::LCM if gcd.is_zero() => Number::Int(0),
+                    Self::LCM => Number::floor_div(num1.abs(), gcd) * num2.abs(),
+                    _ => unreachable!(),
+                };
+                Value::from(result)
+            }
+            Self::MODPOW => {
+                let base = Self::get_integer("base", scope)?;
+                let exp = Self::get_integer("exp", scope)?;
+                let modulus = Self::get_positive_integer("modulus", scope)?;
+
+                // negative exponent uses the modular inverse as base
+                let is_negative = exp < Number::Int(0);
+                let base = if is_negative {
+                    let Some(inverse) = big_modinv(base, &modulus) else {
+                        return Err(math_error("the base is not invertible for the modulus")?);
+                    };
+                    inverse
+                } else {
+                    base
+                };
+                Value::from(big_pow_mod(base, exp.abs(), &modulus))
+            }
+            Self::MODINV => {
+                let input = Self::get_integer("input", scope)?;
+                let modulus = Self::get_positive_integer("modulus", scope)?;
+                let Some(inverse) = big_modinv(input, &modulus) else {
+                    return Err(math_error("the input is not invertible for the modulus")?);
+                };
+                Value::from(inverse)
+            }
+            Self::BINOMIAL => {
+                let n = Self::get_int("n", scope)?;
+                let k = Self::get_int("k", scope)?;
+                if n < 0 {
+                    return Err(math_error("`n` should not be negative")?);
+                }
+                if k < 0 || k > n {
+                    return Ok(Value::from(0));
+                }
+
+                // C(n, k) = C(n, n - k)
+                let k = k.min(n - k);
+                let mut result = Number::Int(1);
+                for i in 1..=k {
+                    // every intermediate result is an integer
+                    result = result * Number::Int(n - k + i);
+                    result = result.exact_quotient(&Number::Int(i));
+                }
+                Value::from(result)
+            }
+            Self::FACTORIAL => {
+                let input = Self::get_int("input", scope)?;
+                Value::from(Self::factorial(input)?)
+            }
+            Self::ISPRIME => {
+                let input = Self::get_int("input", scope)?;
+                Value::from(input > 0 && is_prime(input as u64))
+            }
+            Self::NEXTPRIME => {
+                let input = Self::get_int("input", scope)?;
+                let mut current = input.max(1) as u64 + 1;
+                while !is_prime(current) {
+                    current += 1;
+                }
+                if current > i64::MAX as u64 {
+                    return Err(math_error("the next prime is too large")?);
+                }
+                Value::from(current as i64)
+            }
+            Self::FACTORIZE | Self::TOTIENT | Self::DIVISORS => {
+                let input = Self::get_positive_int("input", scope)?;
+                let factors = factorize(input);
+
+                match self {
+                    Self::FACTORIZE => {
+                        let mut pairs = ArrayLiteral::new();
+                        for (prime, exponent) in factors {
+                            let pair = ArrayLiteral::from([
+                                Value::from(prime as i64),
+                                Value::from(exponent as i64),
+                            ]);
+                            pairs.push_back(Value::from(pair));
+                        }
+                        Value::from(pairs)
+                    }
+                    Self::TOTIENT => {
+                        let mut result = input;
+                        for (prime, _) in factors {
+                            result = result / prime * (prime - 1);
+                        }
+                        Value::from(result as i64)
+                    }
+                    Self::DIVISORS => {
+                        let mut divisors = vec![1_u64];
+                        for (prime, exponent) in factors {
+                            let mut multiplied = vec![];
+                            for divisor in &divisors {
+                                let mut current = *divisor;
+                                for _ in 0..exponent {
+                                    current *= prime;
+                                    multiplied.push(current);
+                                }
+                            }
+                            divisors.extend(multiplied);
+                        }
+                        divisors.sort();
+
+                        let divisors_arr: ArrayLiteral = divisors
+                            .into_iter()
+                            .map(|d| Value::from(d as i64))
+                            .collect();
+                        Value::from(divisors_arr)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        };
+        return Ok(result);
+    }
+}
@@ -0,0 +1,402 @@
+use crate::computer::computer::callback;
+use crate::public::error::{math_error, type_error};
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::number::Number;
+use crate::public::value::oop::object::Object;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_val::get_val;
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum NumericModule {
+    ROOT,
+    NEWTON,
+    INTEGRATE,
+    DERIVATIVE,
+    MINIMIZE,
+    RK4,
+}
+
+// the tolerance of the iterative methods
+const TOLERANCE: f64 = 1e-12;
+const INTEGRATE_TOLERANCE: f64 = 1e-10;
+const MAX_ITERATION: usize = 200;
+// the max recursion depth of adaptive Simpson integration
+const MAX_DEPTH: usize = 50;
+// the max step count of ODE solving
+const MAX_STEPS: i64 = 1_000_000;
+
+// the Lazy-Expression callback uses `x` as its variable,
+// except that ODE uses `t` and `y`.
+const LAZY_VARS: [&str; 1] = ["x"];
+const ODE_LAZY_VARS: [&str; 2] = ["t", "y"];
+
+// the interval in adaptive Simpson integration
+struct Segment {
+    start: (f64, f64),
+    middle: (f64, f64),
+    end: (f64, f64),
+    area: f64,
+}
+impl Segment {
+    fn new(start: (f64, f64), middle: (f64, f64), end: (f64, f64)) -> Self {
+        let area = (end.0 - start.0) / 6.0 * (start.1 + 4.0 * middle.1 + end.1);
+        Self {
+            start,
+            middle,
+            end,
+            area,
+        }
+    }
+}
+
+impl NumericModule {
+    fn get_callable(scope: &mut Scope) -> Result<Value, ()> {
+        let f = get_val("f", scope)?;
+        match f {
+            Value::Function(_) | Value::LazyExpression(_) => Ok(f),
+            _ => Err(type_error(
+                Some("f"),
+                vec![ValueType::Function, ValueType::LazyExpression],
+                f.get_type(),
+            )?),
+        }
+    }
+    fn get_finite(val_name: &str, scope: &mut Scope) -> Result<f64, ()> {
+        let num = get_val(val_name, scope)?.get_f64()?;
+        if !num.is_finite() {
+            let msg = format!("finite number is expected for param `{}`", val_name);
+            return Err(math_error(&msg)?);
+        }
+        return Ok(num);
+    }
+
+    // compute the callback and expect a Number result
+    fn eval(f: &Value, args: &[f64], lazy_vars: &[&str], scope: &mut Scope) -> Result<f64, ()> {
+        let args: Vec<Number> = args.iter().map(|arg| Number::Float(*arg)).collect();
+        let result = callback(f, &args, lazy_vars, scope)?;
+        let Value::Number(num) = result else {
+            return Err(type_error(
+                Some("callback result"),
+                vec![ValueType::Number],
+                result.get_type(),
+            )?);
+        };
+        // the poles should not be computed as finite values
+        let value = num.float_value();
+        if matches!(num, Number::NotANumber | Number::Complex(_)) || !value.is_finite() {
+            let point: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            let msg = format!(
+                "the function value is not a finite real number at ({})",
+                point.join(", ")
+            );
+            return Err(math_error(&msg)?);
+        }
+        return Ok(value);
+    }
+    fn eval_x(f: &Value, x: f64, scope: &mut Scope) -> Result<f64, ()> {
+        Self::eval(f, &[x], &LAZY_VARS, scope)
+    }
+
+    // --- --- --- --- --- ---
+
+    // Brent's method, combines bisection, secant and inverse quadratic interpolation
+    fn brent_root(f: &Value, a: f64, b: f64, scope: &mut Scope) -> Result<f64, ()> {
+        let (mut a, mut b) = (a, b);
+        let mut fa = Self::eval_x(f, a, scope)?;
+        let mut fb = Self::eval_x(f, b, scope)?;
+        if fa == 0.0 {
+            return Ok(a);
+        }
+        if fb == 0.0 {
+            return Ok(b);
+        }
+        if fa.signum() == fb.signum() {
+            return Err(math_error("`f(a)` and `f(b)` should have different signs")?);
+        }
+
+        let (mut c, mut fc) = (a, fa);
+        let mut step = b - a;
+        let mut last_step = step;
+        for _ in 0..MAX_ITERATION {
+            if fb.signum() == fc.signum() {
+                // keep the root between `b` and `c`
+                (c, fc) = (a, fa);
+                step = b - a;
+                last_step = step;
+            }
+            if fc.abs() < fb.abs() {
+                // `b` is always the best estimate
+                (a, b, c) = (b, c, b);
+                (fa, fb, fc) = (fb, fc, fb);
+            }
+
+            let tolerance = 2.0 * f64::EPSILON * b.abs() + 0.5 * TOLERANCE;
+            let half_width = 0.5 * (c - b);
+            if half_width.abs() <= tolerance || fb == 0.0 {
+                return Ok(b);
+            }
+
+            if last_step.abs() >= tolerance && fa.abs() > fb.abs() {
+                // try interpolation
+                let s = fb / fa;
+                let (mut p, mut q) = if a == c {
+                    (2.0 * half_width * s, 1.0 - s)
+                } else {
+                    let q = fa / fc;
+                    let r = fb / fc;
+                    let p = s * (2.0 * half_width * q * (q - r) - (b - a) * (r - 1.0));
+                    (p, (q - 1.0) * (r - 1.0) * (s - 1.0))
+                };
+                if p > 0.0 {
+                    q = -q;
+                } else {
+                    p = -p;
+                }
+                let limit =
+                    (3.0 * half_width * q - (tolerance * q).abs()).min((last_step * q).abs());
+                if 2.0 * p < limit {
+                    last_step = step;
+                    step = p / q;
+                } else {
+                    // fall back to bisection
+                    step = half_width;
+                    last_step = step;
+                }
+            } else {
+                step = half_width;
+                last_step = step;
+            }
+
+            (a, fa) = (b, fb);
+            b += if step.abs() > tolerance {
+                step
+            } else {
+                tolerance.copysign(half_width)
+            };
+            fb = Self::eval_x(f, b, scope)?;
+        }
+        return Err(math_error("root finding does not converge")?);
+    }
+
+    // five-point central difference
+    fn central_derivative(f: &Value, x: f64, scope: &mut Scope) -> Result<f64, ()> {
+        let h = 1e-3 * x.abs().max(1.0);
+        let f1 = Self::eval_x(f, x + h, scope)?;
+        let f2 = Self::eval_x(f, x + 2.0 * h, scope)?;
+        let b1 = Self::eval_x(f, x - h, scope)?;
+        let b2 = Self::eval_x(f, x - 2.0 * h, scope)?;
+        return Ok((8.0 * (f1 - b1) - (f2 - b2)) / (12.0 * h));
+    }
+
+    fn newton(f: &Value, x0: f64, scope: &mut Scope) -> Result<f64, ()> {
+        let mut x = x0;
+        for _ in 0..MAX_ITERATION {
+            let fx = Self::eval_x(f, x, scope)?;
+            if fx == 0.0 {
+                return Ok(x);
+            }
+            let dfx = Self::central_derivative(f, x, scope)?;
+            if dfx == 0.0 || !dfx.is_finite() {
+                return Err(math_error("zero derivative is met in Newton's method")?);
+            }
+
+            let next = x - fx / dfx;
+            if !next.is_finite() {
+                break;
+            }
+            if (next - x).abs() <= TOLERANCE * next.abs().max(1.0) {
+                return Ok(next);
+            }
+            x = next;
+        }
+        return Err(math_error("Newton's method does not converge")?);
+    }
+
+    fn adaptive_simpson(
+        f: &Value,
+        segment: Segment,
+        tolerance: f64,
+        depth: usize,
+        scope: &mut Scope,
+    ) -> Result<f64, ()> {
+        let Segment {
+            start,
+            middle,
+            end,
+            area,
+        } = segment;
+        let left_x = (start.0 + middle.0) / 2.0;
+        let right_x = (middle.0 + end.0) / 2.0;
+        let left_middle = (left_x, Self::eval_x(f, left_x, scope)?);
+        let right_middle = (right_x, Self::eval_x(f, right_x, scope)?);
+
+        let left = Segment::new(start, left_middle, middle);
+        let right = Segment::new(middle, right_middle, end);
+        let delta = left.area + right.area - area;
+        if depth == 0 || delta.abs() <= 15.0 * tolerance {
+            // Richardson extrapolation
+            return Ok(left.area + right.area + delta / 15.0);
+        }
+
+        let left_area = Self::adaptive_simpson(f, left, tolerance / 2.0, depth - 1, scope)?;
+        let right_area = Self::adaptive_simpson(f, right, tolerance / 2.0, depth - 1, scope)?;
+        return Ok(left_area + right_area);
+    }
+
+    // golden section search for the minimum point in [a, b]
+    fn golden_section(f: &Value, a: f64, b: f64, scope: &mut Scope) -> Result<f64, ()> {
+        let ratio = (5_f64.sqrt() - 1.0) / 2.0;
+        let (mut a, mut b) = (a.min(b), a.max(b));
+        let mut x1 = b - ratio * (b - a);
+        let mut x2 = a + ratio * (b - a);
+        let mut f1 = Self::eval_x(f, x1, scope)?;
+        let mut f2 = Self::eval_x(f, x2, scope)?;
+
+        for _ in 0..MAX_ITERATION {
+            if (b - a).abs() <= 1e-10 * (1.0 + x1.abs()) {
+                break;
+            }
+            if f1 < f2 {
+                (b, x2, f2) = (x2, x1, f1);
+                x1 = b - ratio * (b - a);
+                f1 = Self::eval_x(f, x1, scope)?;
+            } else {
+                (a, x1, f1) = (x1, x2, f2);
+                x2 = a + ratio * (b - a);
+                f2 = Self::eval_x(f, x2, scope)?;
+            }
+        }
+        return Ok((a + b) / 2.0);
+    }
+
+    // classic fourth-order Runge-Kutta method for `y' = f(t, y)`
+    fn rk4(
+        f: &Value,
+        y0: f64,
+        (t0, t1): (f64, f64),
+        steps: i64,
+        scope: &mut Scope,
+    ) -> Result<f64, ()> {
+        let h = (t1 - t0) / steps as f64;
+        let (mut t, mut y) = (t0, y0);
+        for _ in 0..steps {
+            let k1 = Self::eval(f, &[t, y], &ODE_LAZY_VARS, scope)?;
+            let k2 = Self::eval(f, &[t + h / 2.0, y + h * k1 / 2.0], &ODE_LAZY_VARS, scope)?;
+            let k3 = Self::eval(f, &[t + h / 2.0, y + h * k2 / 2.0], &ODE_LAZY_VARS, scope)?;
+            let k4 = Self::eval(f, &[t + h, y + h * k3], &ODE_LAZY_VARS, scope)?;
+            y += h / 6.0 * (k1 + 2.0 * k2 + 2.0 * k3 + k4);
+            t += h;
+        }
+        return Ok(y);
+    }
+}
+
+impl ObjectModule for NumericModule {
+    fn module_object() -> Object {
+        let root = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "f"),
+                BuildInFnParam(ValueType::Number, "a"),
+                BuildInFnParam(ValueType::Number, "b"),
+            ],
+            identi: BuildInFnIdenti::Numeric(Self::ROOT),
+        };
+        let mut integrate = root.clone();
+        let mut minimize = root.clone();
+        integrate.identi = BuildInFnIdenti::Numeric(Self::INTEGRATE);
+        minimize.identi = BuildInFnIdenti::Numeric(Self::MINIMIZE);
+
+        let newton = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "f"),
+                BuildInFnParam(ValueType::Number, "x0"),
+            ],
+            identi: BuildInFnIdenti::Numeric(Self::NEWTON),
+        };
+        let derivative = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "f"),
+                BuildInFnParam(ValueType::Number, "x"),
+            ],
+            identi: BuildInFnIdenti::Numeric(Self::DERIVATIVE),
+        };
+        let rk4 = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "f"),
+                BuildInFnParam(ValueType::Number, "y0"),
+                BuildInFnParam(ValueType::Number, "t0"),
+                BuildInFnParam(ValueType::Number, "t1"),
+                BuildInFnParam(ValueType::Number, "steps"),
+            ],
+            identi: BuildInFnIdenti::Numeric(Self::RK4),
+        };
+
+        let module_obj_props = vec![
+            (String::from("root"), Value::from(root)),
+            (String::from("newton"), Value::from(newton)),
+            (String::from("integrate"), Value::from(integrate)),
+            (String::from("derivative"), Value::from(derivative)),
+            (String::from("minimize"), Value::from(minimize)),
+            (String::from("rk4"), Value::from(rk4)),
+        ];
+        return Object::new(module_obj_props, None);
+    }
+}
+
+impl BuildInFnCall for NumericModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let f = Self::get_callable(scope)?;
+
+        let result = match self {
+            Self::ROOT | Self::INTEGRATE | Self::MINIMIZE => {
+                let a = Self::get_finite("a", scope)?;
+                let b = Self::get_finite("b", scope)?;
+                match self {
+                    Self::ROOT => Self::brent_root(&f, a, b, scope)?,
+                    Self::MINIMIZE => Self::golden_section(&f, a, b, scope)?,
+                    Self::INTEGRATE => {
+                        let middle = (a + b) / 2.0;
+                        let segment = Segment::new(
+                            (a, Self::eval_x(&f, a, scope)?),
+                            (middle, Self::eval_x(&f, middle, scope)?),
+                            (b, Self::eval_x(&f, b, scope)?),
+                        );
+                        Self::adaptive_simpson(&f, segment, INTEGRATE_TOLERANCE, MAX_DEPTH, scope)?
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            Self::NEWTON => {
+                let x0 = Self::get_finite("x0", scope)?;
+                Self::newton(&f, x0, scope)?
+            }
+            Self::DERIVATIVE => {
+                let x = Self::get_finite("x", scope)?;
+                Self::central_derivative(&f, x, scope)?
+            }
+            Self::RK4 => {
+                let y0 = Self::get_finite("y0", scope)?;
+                let t0 = Self::get_finite("t0", scope)?;
+                let t1 = Self::get_finite("t1", scope)?;
+                let Value::Number(Number::Int(steps)) = get_val("steps", scope)? else {
+                    return Err(math_error("integer is expected for param `steps`")?);
+                };
+                if !(1..=MAX_STEPS).contains(&steps) {
+                    let msg = format!("`steps` should be between 1 and {}", MAX_STEPS);
+                    return Err(math_error(&msg)?);
+                }
+                Self::rk4(&f, y0, (t0, t1), steps, scope)?
+            }
+        };
+        return Ok(Value::from(result));
+    }
+}
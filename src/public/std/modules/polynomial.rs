@@ -0,0 +1,383 @@
+use std::rc::Rc;
+
+use crate::public::error::{math_error, syntax_error, type_error};
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::std::utils::get_self_prop::get_self_prop;
+use crate::public::std::{ModuleClass, EMPTY_MODULE_CLASS};
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction, Function};
+use crate::public::value::number::Number;
+use crate::public::value::oop::class::{Class, Property};
+use crate::public::value::symbols::Symbols;
+use crate::public::value::value::{Value, ValueType};
+use crate::public::value::GetAddr;
+
+use super::super::symbolic::expr::Expr;
+use super::super::symbolic::polynomial::Polynomial;
+use super::super::utils::get_val::get_val;
+use super::{BuildInFnCall, ClassModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PolynomialModule {
+    EVAL,
+    DERIV,
+    INTEG,
+    DIVMOD,
+    ROOTS,
+    DEGREE,
+    COEFFS,
+}
+
+// the variable name used to display the polynomial
+// which is not built from lazy expression.
+const DEFAULT_VAR: &str = "x";
+
+// the only variable in the expression, `Some(None)` for constant expression,
+// returns None when there are multiple variables or unanalyzable nodes.
+fn find_variable(expr: &Expr) -> Option<Option<String>> {
+    let result = match expr {
+        Expr::Number(_) => None,
+        Expr::Variable(name) => Some(name.clone()),
+        Expr::Binary(_, left, right) => match (find_variable(left)?, find_variable(right)?) {
+            (Some(var1), Some(var2)) if var1 != var2 => return None,
+            (var1, var2) => var1.or(var2),
+        },
+        Expr::Function(_, _) | Expr::Other(_) => return None,
+    };
+    return Some(result);
+}
+
+// computes the expression consisting of numbers
+fn constant_value(expr: &Expr) -> Result<Number, ()> {
+    let result = match expr {
+        Expr::Number(num) => num.clone(),
+        Expr::Binary(symbol, left, right) => {
+            let (left, right) = (constant_value(left)?, constant_value(right)?);
+            match symbol {
+                Symbols::Plus => left + right,
+                Symbols::Minus => left - right,
+                Symbols::Multiply => left * right,
+                Symbols::Divide if !right.is_zero() => left / right,
+                Symbols::Power => Number::pow(left, right),
+                _ => return Err(()),
+            }
+        }
+        _ => return Err(()),
+    };
+    return Ok(result);
+}
+
+// converts the value of instance to polynomial and its variable name,
+// returns None when the value is invalid.
+fn value_to_polynomial(value: &Value) -> Option<(Polynomial, String)> {
+    match value {
+        Value::LazyExpression(l_expr) => {
+            let expr = Expr::from_node(&l_expr.borrow()).ok()?;
+            let var = find_variable(&expr)?.unwrap_or(String::from(DEFAULT_VAR));
+            let poly = Polynomial::from_expr(&expr, &var, &mut constant_value).ok()??;
+            return Some((poly, var));
+        }
+        Value::Array(arr) => {
+            // the coefficients are ordered from the highest degree
+            let mut coeffs = vec![];
+            for element in arr.borrow().iter().rev() {
+                let Value::Number(num) = element else {
+                    return None;
+                };
+                coeffs.push(num.clone());
+            }
+            return Some((Polynomial::new(coeffs), String::from(DEFAULT_VAR)));
+        }
+        _ => return None,
+    }
+}
+
+// the text like `3x^2 + 2x - 1`
+fn polynomial_text(poly: &Polynomial, var: &str) -> String {
+    let Some(degree) = poly.degree() else {
+        return String::from("0");
+    };
+
+    let mut text = String::new();
+    for i in (0..=degree).rev() {
+        let coeff = &poly.coeffs()[i];
+        if coeff.is_zero() {
+            continue;
+        }
+        let is_negative = !matches!(coeff, Number::Complex(_)) && coeff < &Number::Int(0);
+        let abs_coeff = if is_negative {
+            Number::Int(0) - coeff.clone()
+        } else {
+            coeff.clone()
+        };
+
+        let power = match i {
+            0 => String::new(),
+            1 => String::from(var),
+            _ => format!("{}^{}", var, i),
+        };
+        let term = if i > 0 && abs_coeff == Number::Int(1) {
+            power
+        } else {
+            abs_coeff.to_string() + &power
+        };
+
+        let sign = match (text.is_empty(), is_negative) {
+            (true, true) => "-",
+            (true, false) => "",
+            (false, true) => " - ",
+            (false, false) => " + ",
+        };
+        text.push_str(sign);
+        text.push_str(&term);
+    }
+    return text;
+}
+
+impl PolynomialModule {
+    pub fn is_instance(value: &Value) -> bool {
+        let Value::Object(obj) = value else {
+            return false;
+        };
+        let Some(proto) = obj.borrow().get_proto() else {
+            return false;
+        };
+        return proto.get_addr() == Self::module_class().get_addr();
+    }
+
+    // the instance should be created from coefficient Array
+    // or polynomial lazy expression of single variable.
+    pub fn check_instantiation(class: &Rc<Class>, params: &ArrayLiteral) -> Result<(), ()> {
+        if class.get_addr() != Self::module_class().get_addr() {
+            return Ok(());
+        }
+        let value = params.front().cloned().unwrap_or(Value::EMPTY);
+        if let Value::Array(arr) = &value {
+            for element in arr.borrow().iter() {
+                if !matches!(element, Value::Number(_)) {
+                    return Err(type_error(
+                        Some("coefficient"),
+                        vec![ValueType::Number],
+                        element.get_type(),
+                    )?);
+                }
+            }
+        }
+        if value_to_polynomial(&value).is_none() {
+            // e.g. the lazy expression of multiple variables or non-polynomial functions
+            let param = match value {
+                Value::LazyExpression(_) => "Polynomial of single variable",
+                _ => "Polynomial",
+            };
+            return Err(type_error(
+                Some(param),
+                vec![ValueType::Array, ValueType::LazyExpression],
+                value.get_type(),
+            )?);
+        }
+        return Ok(());
+    }
+
+    // converts the instance, Number, coefficient Array or lazy expression
+    // to polynomial, returns the polynomial and its variable name.
+    fn to_polynomial(value: &Value) -> Result<(Polynomial, String), ()> {
+        let inner_value = match value {
+            Value::Number(num) => {
+                return Ok((Polynomial::constant(num.clone()), String::from(DEFAULT_VAR)));
+            }
+            Value::Array(_) | Value::LazyExpression(_) => value.clone(),
+            _ if Self::is_instance(value) => get_self_prop(value, "v")?,
+            _ => return Err(syntax_error("invalid computing expression")?),
+        };
+        let Some(result) = value_to_polynomial(&inner_value) else {
+            return Err(math_error(
+                "Polynomial should be created from coefficient Array or polynomial lazy expression of single variable",
+            )?);
+        };
+        return Ok(result);
+    }
+
+    // the coefficients Array ordered from the highest degree
+    fn coeffs_array(poly: &Polynomial) -> ArrayLiteral {
+        let mut coeffs: ArrayLiteral = poly
+            .coeffs()
+            .iter()
+            .rev()
+            .cloned()
+            .map(Value::from)
+            .collect();
+        if coeffs.is_empty() {
+            coeffs.push_back(Value::from(0));
+        }
+        return coeffs;
+    }
+    fn from_polynomial(poly: &Polynomial) -> Result<Value, ()> {
+        let params = ArrayLiteral::from([Value::from(Self::coeffs_array(poly))]);
+        let obj = Class::instantiate(Self::module_class(), params)?;
+        return Ok(Value::from(obj));
+    }
+
+    // the text to display the instance, returns None for other values
+    pub fn display_text(value: &Value) -> Option<String> {
+        if !Self::is_instance(value) {
+            return None;
+        }
+        let inner_value = get_self_prop(value, "v").ok()?;
+        let (poly, var) = value_to_polynomial(&inner_value)?;
+        return Some(polynomial_text(&poly, &var));
+    }
+
+    // the arithmetic between instances and Numbers
+    pub fn operate(val1: &Value, val2: &Value, operator: Symbols) -> Result<Value, ()> {
+        let (poly1, _) = Self::to_polynomial(val1)?;
+
+        let result = match (operator, val2) {
+            (Symbols::Divide, Value::Number(num)) => {
+                if num.is_zero() {
+                    return Err(math_error("the divisor should not to be ZERO")?);
+                }
+                poly1.scale(&Number::Int(1).true_div(num))
+            }
+            (Symbols::Divide, _) => {
+                return Err(math_error(
+                    "polynomial can only be divided by number, use `divmod` instead",
+                )?);
+            }
+            (Symbols::Power, Value::Number(Number::Int(exp))) if *exp >= 0 => {
+                poly1.pow(*exp as usize)
+            }
+            (Symbols::Power, _) => {
+                return Err(math_error(
+                    "polynomial can only be raised to a non-negative integer power",
+                )?);
+            }
+            _ => {
+                let (poly2, _) = Self::to_polynomial(val2)?;
+                match operator {
+                    Symbols::Plus => poly1.add(&poly2),
+                    Symbols::Minus => poly1.sub(&poly2),
+                    Symbols::Multiply => poly1.mul(&poly2),
+                    _ => return Err(syntax_error("invalid computing expression")?),
+                }
+            }
+        };
+        return Self::from_polynomial(&result);
+    }
+}
+
+static mut MODULE_CLASS: ModuleClass = EMPTY_MODULE_CLASS;
+impl ClassModule for PolynomialModule {
+    fn __static_class__() -> Class {
+        let eval = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "x"),
+            ],
+            identi: BuildInFnIdenti::Polynomial(Self::EVAL),
+        };
+        let deriv = BuildInFunction {
+            params: vec![BuildInFnParam(ValueType::Object, "self")],
+            identi: BuildInFnIdenti::Polynomial(Self::DERIV),
+        };
+        // `deriv` as function template
+        let mut integ = deriv.clone();
+        let mut roots = deriv.clone();
+        let mut degree = deriv.clone();
+        let mut coeffs = deriv.clone();
+        integ.identi = BuildInFnIdenti::Polynomial(Self::INTEG);
+        roots.identi = BuildInFnIdenti::Polynomial(Self::ROOTS);
+        degree.identi = BuildInFnIdenti::Polynomial(Self::DEGREE);
+        coeffs.identi = BuildInFnIdenti::Polynomial(Self::COEFFS);
+
+        let divmod = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "divisor"),
+            ],
+            identi: BuildInFnIdenti::Polynomial(Self::DIVMOD),
+        };
+
+        return Class::new(
+            vec![Property(ValueType::Void, String::from("v"))],
+            vec![
+                (String::from("eval"), Function::from(eval)),
+                (String::from("deriv"), Function::from(deriv)),
+                (String::from("integ"), Function::from(integ)),
+                (String::from("divmod"), Function::from(divmod)),
+                (String::from("roots"), Function::from(roots)),
+                (String::from("degree"), Function::from(degree)),
+                (String::from("coeffs"), Function::from(coeffs)),
+            ],
+        );
+    }
+    fn module_class() -> Rc<Class> {
+        let class = unsafe {
+            MODULE_CLASS.is_some_or_init(Self::__static_class__);
+            MODULE_CLASS.unwrap()
+        };
+        return class;
+    }
+}
+
+impl BuildInFnCall for PolynomialModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let self_value = get_val("self", scope)?;
+        let (poly, _) = Self::to_polynomial(&self_value)?;
+
+        let result = match self {
+            Self::EVAL => {
+                let Value::Number(x) = get_val("x", scope)? else {
+                    unreachable!()
+                };
+                Value::from(poly.eval(&x))
+            }
+            Self::DERIV => {
+                let coeffs = poly
+                    .coeffs()
+                    .iter()
+                    .enumerate()
+                    .skip(1)
+                    .map(|(i, c)| c.clone() * Number::Int(i as i64))
+                    .collect();
+                Self::from_polynomial(&Polynomial::new(coeffs))?
+            }
+            Self::INTEG => {
+                // the constant term of the integral is ZERO
+                let mut coeffs = vec![Number::Int(0)];
+                for (i, c) in poly.coeffs().iter().enumerate() {
+                    coeffs.push(c.true_div(&Number::Int(i as i64 + 1)));
+                }
+                Self::from_polynomial(&Polynomial::new(coeffs))?
+            }
+            Self::DIVMOD => {
+                let divisor_value = get_val("divisor", scope)?;
+                let (divisor, _) = Self::to_polynomial(&divisor_value)?;
+                if divisor.is_zero() {
+                    return Err(math_error("the divisor should not to be ZERO")?);
+                }
+                let (quotient, remainder) = poly.div_rem(&divisor);
+                let result_arr = ArrayLiteral::from([
+                    Self::from_polynomial(&quotient)?,
+                    Self::from_polynomial(&remainder)?,
+                ]);
+                Value::from(result_arr)
+            }
+            Self::ROOTS => {
+                if poly.is_zero() {
+                    return Err(math_error("every number is the root of ZERO polynomial")?);
+                }
+                let roots: ArrayLiteral = poly.roots().into_iter().map(Value::from).collect();
+                Value::from(roots)
+            }
+            Self::DEGREE => {
+                // the degree of ZERO polynomial is regarded as 0
+                let degree = poly.degree().unwrap_or(0);
+                Value::from(degree as i64)
+            }
+            Self::COEFFS => Value::from(Self::coeffs_array(&poly)),
+        };
+        return Ok(result);
+    }
+}
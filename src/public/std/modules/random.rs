@@ -0,0 +1,275 @@
+use std::f64::consts::PI;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::public::error::math_error;
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::number::Number;
+use crate::public::value::oop::object::Object;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_val::get_val;
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum RandomModule {
+    SEED,
+    RANDOM,
+    RANDINT,
+    UNIFORM,
+    NORMAL,
+
+    CHOICE,
+    SHUFFLE,
+    SAMPLE,
+}
+
+// xoshiro256** generator, the state is initialized by splitmix64,
+// so that the same seed produces the same sequence on every platform.
+#[derive(Clone, Copy)]
+struct Generator {
+    state: [u64; 4],
+}
+
+impl Generator {
+    fn from_seed(seed: u64) -> Self {
+        let mut x = seed;
+        let mut splitmix64 = || {
+            x = x.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            return z ^ (z >> 31);
+        };
+        let state = [splitmix64(), splitmix64(), splitmix64(), splitmix64()];
+        return Self { state };
+    }
+    fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+        return Self::from_seed(nanos);
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        return result;
+    }
+    // uniformly distributed in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        // the highest 53 bits are used as the mantissa
+        return (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64);
+    }
+    // uniformly distributed in [0, bound), `bound` should be positive
+    fn below(&mut self, bound: u64) -> u64 {
+        // reject the values in the incomplete last range to avoid modulo bias
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+}
+
+// the generator is seeded with current time when it is first used
+static mut GENERATOR: Option<Generator> = None;
+
+fn with_generator<T>(action: impl FnOnce(&mut Generator) -> T) -> T {
+    let mut generator = unsafe { GENERATOR }.unwrap_or_else(Generator::from_time);
+    let result = action(&mut generator);
+    unsafe { GENERATOR = Some(generator) };
+    return result;
+}
+
+impl RandomModule {
+    fn get_array(val_name: &str, scope: &mut Scope) -> Result<Vec<Value>, ()> {
+        let Value::Array(arr) = get_val(val_name, scope)? else {
+            unreachable!()
+        };
+        let elements = arr.borrow().iter().cloned().collect();
+        return Ok(elements);
+    }
+
+    // partial Fisher-Yates shuffle, the first `count` elements
+    // are the uniformly chosen ones.
+    fn shuffle_front(elements: &mut [Value], count: usize) {
+        with_generator(|generator| {
+            let len = elements.len();
+            for i in 0..count.min(len.saturating_sub(1)) {
+                let j = i + generator.below((len - i) as u64) as usize;
+                elements.swap(i, j);
+            }
+        });
+    }
+}
+
+impl ObjectModule for RandomModule {
+    fn module_object() -> Object {
+        let seed = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "seed"),
+            ],
+            identi: BuildInFnIdenti::Random(Self::SEED),
+        };
+        let random = BuildInFunction {
+            params: vec![BuildInFnParam(ValueType::Object, "self")],
+            identi: BuildInFnIdenti::Random(Self::RANDOM),
+        };
+        let randint = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "start"),
+                BuildInFnParam(ValueType::Number, "end"),
+            ],
+            identi: BuildInFnIdenti::Random(Self::RANDINT),
+        };
+        let mut uniform = randint.clone();
+        uniform.identi = BuildInFnIdenti::Random(Self::UNIFORM);
+        let normal = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Number, "mu"),
+                BuildInFnParam(ValueType::Number, "sigma"),
+            ],
+            identi: BuildInFnIdenti::Random(Self::NORMAL),
+        };
+
+        let choice = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Array, "arr"),
+            ],
+            identi: BuildInFnIdenti::Random(Self::CHOICE),
+        };
+        let mut shuffle = choice.clone();
+        shuffle.identi = BuildInFnIdenti::Random(Self::SHUFFLE);
+        let sample = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Array, "arr"),
+                BuildInFnParam(ValueType::Number, "k"),
+            ],
+            identi: BuildInFnIdenti::Random(Self::SAMPLE),
+        };
+
+        let module_obj_props = vec![
+            (String::from("seed"), Value::from(seed)),
+            (String::from("random"), Value::from(random)),
+            (String::from("randint"), Value::from(randint)),
+            (String::from("uniform"), Value::from(uniform)),
+            (String::from("normal"), Value::from(normal)),
+            (String::from("choice"), Value::from(choice)),
+            (String::from("shuffle"), Value::from(shuffle)),
+            (String::from("sample"), Value::from(sample)),
+        ];
+        return Object::new(module_obj_props, None);
+    }
+}
+
+impl BuildInFnCall for RandomModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let result = match self {
+            Self::SEED => {
+                let seed_value = get_val("seed", scope)?;
+                let Value::Number(Number::Int(seed)) = seed_value else {
+                    return Err(math_error("integer is expected for param `seed`")?);
+                };
+                unsafe { GENERATOR = Some(Generator::from_seed(seed as u64)) };
+                Value::EMPTY
+            }
+            Self::RANDOM => Value::from(with_generator(Generator::next_f64)),
+            Self::RANDINT => {
+                let start_value = get_val("start", scope)?;
+                let end_value = get_val("end", scope)?;
+                let (Value::Number(Number::Int(start)), Value::Number(Number::Int(end))) =
+                    (start_value, end_value)
+                else {
+                    return Err(math_error("integers are expected for `randint`")?);
+                };
+                if start > end {
+                    return Err(math_error("`start` should not be greater than `end`")?);
+                }
+
+                // the count of the integers in [start, end] may overflow i64
+                let span = (end as i128 - start as i128 + 1) as u128;
+                let offset = if span > u64::MAX as u128 {
+                    with_generator(Generator::next_u64)
+                } else {
+                    with_generator(|generator| generator.below(span as u64))
+                };
+                Value::from((start as i128 + offset as i128) as i64)
+            }
+            Self::UNIFORM => {
+                let start = get_val("start", scope)?.get_f64()?;
+                let end = get_val("end", scope)?.get_f64()?;
+                let ratio = with_generator(Generator::next_f64);
+                Value::from(start + (end - start) * ratio)
+            }
+            Self::NORMAL => {
+                let mu = get_val("mu", scope)?.get_f64()?;
+                let sigma = get_val("sigma", scope)?.get_f64()?;
+                if sigma < 0.0 {
+                    return Err(math_error("`sigma` should not be negative")?);
+                }
+                // Box-Muller transform, `u1` is in (0, 1] to avoid `ln(0)`
+                let (u1, u2) =
+                    with_generator(|generator| (1.0 - generator.next_f64(), generator.next_f64()));
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+                Value::from(mu + sigma * z)
+            }
+
+            Self::CHOICE => {
+                let elements = Self::get_array("arr", scope)?;
+                if elements.is_empty() {
+                    return Err(math_error("can not choose from an empty Array")?);
+                }
+                let index = with_generator(|generator| generator.below(elements.len() as u64));
+                elements[index as usize].clone()
+            }
+            Self::SHUFFLE => {
+                // shuffle the Array in place
+                let arr_value = get_val("arr", scope)?;
+                let Value::Array(arr) = &arr_value else {
+                    unreachable!()
+                };
+                let mut elements: Vec<Value> = arr.borrow().iter().cloned().collect();
+                let len = elements.len();
+                Self::shuffle_front(&mut elements, len);
+
+                for (index, element) in elements.into_iter().enumerate() {
+                    arr.borrow_mut()[index] = element;
+                }
+                arr_value
+            }
+            Self::SAMPLE => {
+                let mut elements = Self::get_array("arr", scope)?;
+                let k_value = get_val("k", scope)?;
+                let k = k_value.get_i64()?;
+                if k < 0 || k as usize > elements.len() {
+                    let msg = format!("`k` should be between 0 and {}", elements.len());
+                    return Err(math_error(&msg)?);
+                }
+
+                let count = k as usize;
+                Self::shuffle_front(&mut elements, count);
+                let samples: ArrayLiteral = elements.into_iter().take(count).collect();
+                Value::from(samples)
+            }
+        };
+        return Ok(result);
+    }
+}
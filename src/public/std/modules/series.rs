@@ -0,0 +1,311 @@
+use crate::computer::computer::callback;
+use crate::public::error::{math_error, type_error};
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::number::Number;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_val::get_val;
+use super::{BuildInFnCall, FunctionModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum SeriesModule {
+    SUM,
+    PROD,
+    SEQ,
+    LIMIT,
+    SERIESSUM,
+}
+
+// the Lazy-Expression callback uses `n` as the index variable,
+// except that `limit` uses `x`.
+const INDEX_VARS: [&str; 1] = ["n"];
+const LIMIT_VARS: [&str; 1] = ["x"];
+
+// the max count of terms in `sum`, `prod` and `seq`
+const MAX_TERMS: i64 = 1_000_000;
+// the count of terms used to accelerate the infinite series
+const SERIES_TERMS: usize = 40;
+const SERIES_TOLERANCE: f64 = 1e-9;
+// the count of points approaching the limit point
+const LIMIT_STEPS: usize = 16;
+const LIMIT_TOLERANCE: f64 = 1e-7;
+// the relative precision of the estimated results
+const PRECISION: f64 = 1e-12;
+
+// the estimate is rounded to the decimal place of its error, the error is at least
+// `PRECISION` relatively since it is estimated from the successive estimates,
+// e.g. `4.000000000000017` with the error `8e-15` is `4`.
+fn round_to_error(estimate: f64, error: f64) -> f64 {
+    let error = error.max(PRECISION * estimate.abs().max(1.0));
+    let scale = 10_f64.powi(-error.log10().floor() as i32);
+    if !scale.is_finite() {
+        return estimate;
+    }
+    return (estimate * scale).round() / scale;
+}
+
+impl SeriesModule {
+    fn get_callable(scope: &mut Scope) -> Result<Value, ()> {
+        let f = get_val("f", scope)?;
+        match f {
+            Value::Function(_) | Value::LazyExpression(_) => Ok(f),
+            _ => Err(type_error(
+                Some("f"),
+                vec![ValueType::Function, ValueType::LazyExpression],
+                f.get_type(),
+            )?),
+        }
+    }
+    fn get_int(val_name: &str, scope: &mut Scope) -> Result<i64, ()> {
+        let Value::Number(Number::Int(num)) = get_val(val_name, scope)? else {
+            let msg = format!("integer is expected for param `{}`", val_name);
+            return Err(math_error(&msg)?);
+        };
+        return Ok(num);
+    }
+
+    // compute the callback and expect a Number result
+    fn eval(f: &Value, arg: Number, lazy_vars: &[&str], scope: &mut Scope) -> Result<Number, ()> {
+        let result = callback(f, &[arg], lazy_vars, scope)?;
+        let Value::Number(num) = result else {
+            return Err(type_error(
+                Some("callback result"),
+                vec![ValueType::Number],
+                result.get_type(),
+            )?);
+        };
+        return Ok(num);
+    }
+
+    // --- --- --- --- --- ---
+
+    // the limit from one side, `f` is computed at the points whose
+    // distance to `x0` is halved each step (or `x` is doubled when `x0` is infinite),
+    // and the values are accelerated by Richardson extrapolation.
+    fn one_sided_limit(
+        f: &Value,
+        x0: f64,
+        side: f64,
+        scope: &mut Scope,
+    ) -> Result<Option<f64>, ()> {
+        let scale = match x0.is_infinite() {
+            true => 1.0,
+            false => 0.1 * x0.abs().max(1.0),
+        };
+        let mut last_row: Vec<f64> = vec![];
+        let mut best: Option<(f64, f64)> = None;
+        for step in 0..LIMIT_STEPS {
+            let distance = scale / 2_f64.powi(step as i32);
+            let x = match x0.is_infinite() {
+                true => x0.signum() / distance,
+                false => x0 + side * distance,
+            };
+            let value = Self::eval(f, Number::Float(x), &LIMIT_VARS, scope)?.float_value();
+            if !value.is_finite() {
+                return Ok(None);
+            }
+
+            let mut row = vec![value];
+            for column in 1..=step {
+                let factor = 2_f64.powi(column as i32) - 1.0;
+                let estimate = row[column - 1] + (row[column - 1] - last_row[column - 1]) / factor;
+                row.push(estimate);
+            }
+            if step > 0 {
+                // the difference of the last two diagonal estimates
+                let error = (row[step] - last_row[step - 1]).abs();
+                if best.is_none_or(|(_, best_error)| error < best_error) {
+                    best = Some((row[step], error));
+                }
+            }
+            last_row = row;
+        }
+
+        let result = best.and_then(|(estimate, error)| {
+            let is_converged = error <= LIMIT_TOLERANCE * estimate.abs().max(1.0);
+            is_converged.then_some(round_to_error(estimate, error))
+        });
+        return Ok(result);
+    }
+
+    // the sum of infinite series, returns directly when the terms are negligible,
+    // otherwise the partial sums are accelerated by Levin u-transformation.
+    fn infinite_sum(f: &Value, start: i64, scope: &mut Scope) -> Result<Option<f64>, ()> {
+        let mut terms = vec![];
+        let mut sums = vec![];
+        let mut sum = 0.0;
+        for index in 0..SERIES_TERMS {
+            let term = Self::eval(f, Number::Int(start + index as i64), &INDEX_VARS, scope)?;
+            let term = term.float_value();
+            if !term.is_finite() {
+                return Ok(None);
+            }
+            sum += term;
+            terms.push(term);
+            sums.push(sum);
+
+            let is_negligible = |term: &f64| term.abs() <= f64::EPSILON * sum.abs();
+            if index >= 2 && terms[index - 2..].iter().all(is_negligible) {
+                return Ok(Some(sum));
+            }
+        }
+        // the terms should tend to ZERO, e.g. the sum of `(-1)^n` is not
+        // accepted although the transformation results in 1/2.
+        if terms[SERIES_TERMS - 1].abs() >= terms[SERIES_TERMS / 2].abs() {
+            return Ok(None);
+        }
+        // the transformation divides by the terms, so that
+        // only the terms after the last ZERO are used.
+        let offset = terms
+            .iter()
+            .rposition(|term| *term == 0.0)
+            .map_or(0, |index| index + 1);
+        if offset > SERIES_TERMS / 2 {
+            return Ok(None);
+        }
+        let (terms, sums) = (&terms[offset..], &sums[offset..]);
+
+        let mut best: Option<(f64, f64)> = None;
+        let mut last_estimate = f64::NAN;
+        for order in 1..terms.len() {
+            let (mut numerator, mut denominator) = (0.0, 0.0);
+            let mut binomial = 1.0;
+            for j in 0..=order {
+                let weight = ((j + 1) as f64 / (order + 1) as f64).powi(order as i32 - 1);
+                let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+                let coefficient = sign * binomial * weight / ((j + 1) as f64 * terms[j]);
+                numerator += coefficient * sums[j];
+                denominator += coefficient;
+                binomial = binomial * (order - j) as f64 / (j + 1) as f64;
+            }
+            let estimate = numerator / denominator;
+            let error = (estimate - last_estimate).abs();
+            if error.is_finite() && best.is_none_or(|(_, best_error)| error < best_error) {
+                best = Some((estimate, error));
+            }
+            last_estimate = estimate;
+        }
+
+        let result = best.and_then(|(estimate, error)| {
+            let is_converged = error <= SERIES_TOLERANCE * estimate.abs().max(1.0);
+            is_converged.then_some(round_to_error(estimate, error))
+        });
+        return Ok(result);
+    }
+}
+
+impl FunctionModule for SeriesModule {
+    fn function_list() -> Vec<(String, Value)> {
+        let sum = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Void, "f"),
+                BuildInFnParam(ValueType::Number, "a"),
+                BuildInFnParam(ValueType::Number, "b"),
+            ],
+            identi: BuildInFnIdenti::Series(Self::SUM),
+        };
+        let mut prod = sum.clone();
+        let mut seq = sum.clone();
+        prod.identi = BuildInFnIdenti::Series(Self::PROD);
+        seq.identi = BuildInFnIdenti::Series(Self::SEQ);
+
+        let limit = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Void, "f"),
+                BuildInFnParam(ValueType::Number, "x0"),
+            ],
+            identi: BuildInFnIdenti::Series(Self::LIMIT),
+        };
+        let series_sum = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Void, "f"),
+                BuildInFnParam(ValueType::Number, "a"),
+            ],
+            identi: BuildInFnIdenti::Series(Self::SERIESSUM),
+        };
+
+        return vec![
+            (String::from("sum"), Value::from(sum)),
+            (String::from("prod"), Value::from(prod)),
+            (String::from("seq"), Value::from(seq)),
+            (String::from("limit"), Value::from(limit)),
+            (String::from("series_sum"), Value::from(series_sum)),
+        ];
+    }
+}
+
+impl BuildInFnCall for SeriesModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let f = Self::get_callable(scope)?;
+
+        let result = match self {
+            Self::SUM | Self::PROD | Self::SEQ => {
+                let start = Self::get_int("a", scope)?;
+                let end = Self::get_int("b", scope)?;
+                if end.saturating_sub(start) >= MAX_TERMS {
+                    let msg = format!("at most {} terms are allowed", MAX_TERMS);
+                    return Err(math_error(&msg)?);
+                }
+
+                // the empty range results in the empty sum | product | sequence
+                match self {
+                    Self::SEQ => {
+                        let mut terms = ArrayLiteral::new();
+                        for index in start..=end {
+                            let args = [Number::Int(index)];
+                            terms.push_back(callback(&f, &args, &INDEX_VARS, scope)?);
+                        }
+                        Value::from(terms)
+                    }
+                    _ => {
+                        let mut result = Number::Int(if *self == Self::SUM { 0 } else { 1 });
+                        for index in start..=end {
+                            let term = Self::eval(&f, Number::Int(index), &INDEX_VARS, scope)?;
+                            result = match self {
+                                Self::SUM => result + term,
+                                _ => result * term,
+                            };
+                        }
+                        Value::from(result)
+                    }
+                }
+            }
+            Self::LIMIT => {
+                let x0 = get_val("x0", scope)?.get_f64()?;
+                if x0.is_nan() {
+                    return Err(math_error("the limit point should be a real number")?);
+                }
+
+                let right = Self::one_sided_limit(&f, x0, 1.0, scope)?;
+                let left = match x0.is_infinite() {
+                    true => right,
+                    false => Self::one_sided_limit(&f, x0, -1.0, scope)?,
+                };
+                let (Some(left), Some(right)) = (left, right) else {
+                    return Err(math_error("the limit does not converge")?);
+                };
+                if (left - right).abs() > LIMIT_TOLERANCE * left.abs().max(1.0) {
+                    let msg = format!(
+                        "the left limit {} and the right limit {} are different",
+                        Number::Float(left),
+                        Number::Float(right)
+                    );
+                    return Err(math_error(&msg)?);
+                }
+                Value::from((left + right) / 2.0)
+            }
+            Self::SERIESSUM => {
+                let start = Self::get_int("a", scope)?;
+                let Some(sum) = Self::infinite_sum(&f, start, scope)? else {
+                    return Err(math_error("the series does not converge")?);
+                };
+                Value::from(sum)
+            }
+        };
+        return Ok(result);
+    }
+}
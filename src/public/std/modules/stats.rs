@@ -0,0 +1,292 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::public::error::{math_error, real_number_error};
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::map::RawMap;
+use crate::public::value::number::Number;
+use crate::public::value::oop::object::Object;
+use crate::public::value::value::{Value, ValueType};
+
+use super::super::utils::get_num_arr::get_num_arr;
+use super::super::utils::get_val::get_val;
+use super::{BuildInFnCall, ObjectModule};
+
+#[derive(PartialEq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum StatsModule {
+    SUM,
+    MEAN,
+    MEDIAN,
+    MODE,
+    VARIANCE,
+    PVARIANCE,
+    STDEV,
+    PSTDEV,
+    MIN,
+    MAX,
+
+    PERCENTILE,
+
+    COVARIANCE,
+    CORRELATION,
+    LINREG,
+}
+
+fn mean(data: &[f64]) -> f64 {
+    return data.iter().sum::<f64>() / data.len() as f64;
+}
+// sum of (x - mean(x)) * (y - mean(y))
+fn co_deviation(x: &[f64], y: &[f64]) -> f64 {
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    return x
+        .iter()
+        .zip(y)
+        .map(|(xi, yi)| (xi - x_mean) * (yi - y_mean))
+        .sum();
+}
+
+// sort Numbers, returns None if there is uncomparable Number
+fn sorted(mut data: Vec<Number>) -> Option<Vec<Number>> {
+    let mut is_comparable = true;
+    data.sort_by(|a, b| {
+        a.partial_cmp(b).unwrap_or_else(|| {
+            is_comparable = false;
+            Ordering::Equal
+        })
+    });
+    return if is_comparable { Some(data) } else { None };
+}
+
+impl StatsModule {
+    fn get_data(val_name: &str, scope: &mut Scope, min_len: usize) -> Result<Vec<Number>, ()> {
+        let data = get_num_arr(val_name, scope)?;
+        for num in &data {
+            match num {
+                Number::Complex(_) => return Err(real_number_error(val_name, "Complex")?),
+                Number::NotANumber => return Err(real_number_error(val_name, "NaN")?),
+                _ => {}
+            }
+        }
+        if data.len() < min_len {
+            let msg = format!(
+                "at least {} numbers are expected in `{}`",
+                min_len, val_name
+            );
+            return Err(math_error(&msg)?);
+        }
+        return Ok(data);
+    }
+    fn get_sorted_data(val_name: &str, scope: &mut Scope) -> Result<Vec<Number>, ()> {
+        let data = Self::get_data(val_name, scope, 1)?;
+        let Some(sorted_data) = sorted(data) else {
+            return Err(math_error("complex number is not comparable")?);
+        };
+        return Ok(sorted_data);
+    }
+    fn get_paired_data(scope: &mut Scope) -> Result<(Vec<f64>, Vec<f64>), ()> {
+        let x = Self::get_data("x", scope, 2)?;
+        let y = Self::get_data("y", scope, 2)?;
+        if x.len() != y.len() {
+            return Err(math_error("`x` and `y` should have the same length")?);
+        }
+        let x = x.iter().map(Number::float_value).collect();
+        let y = y.iter().map(Number::float_value).collect();
+        return Ok((x, y));
+    }
+}
+
+impl ObjectModule for StatsModule {
+    fn module_object() -> Object {
+        let sum = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "data"),
+            ],
+            identi: BuildInFnIdenti::Stats(Self::SUM),
+        };
+        let mut mean = sum.clone();
+        let mut median = sum.clone();
+        let mut mode = sum.clone();
+        let mut variance = sum.clone();
+        let mut pvariance = sum.clone();
+        let mut stdev = sum.clone();
+        let mut pstdev = sum.clone();
+        let mut min = sum.clone();
+        let mut max = sum.clone();
+        mean.identi = BuildInFnIdenti::Stats(Self::MEAN);
+        median.identi = BuildInFnIdenti::Stats(Self::MEDIAN);
+        mode.identi = BuildInFnIdenti::Stats(Self::MODE);
+        variance.identi = BuildInFnIdenti::Stats(Self::VARIANCE);
+        pvariance.identi = BuildInFnIdenti::Stats(Self::PVARIANCE);
+        stdev.identi = BuildInFnIdenti::Stats(Self::STDEV);
+        pstdev.identi = BuildInFnIdenti::Stats(Self::PSTDEV);
+        min.identi = BuildInFnIdenti::Stats(Self::MIN);
+        max.identi = BuildInFnIdenti::Stats(Self::MAX);
+
+        let percentile = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "data"),
+                BuildInFnParam(ValueType::Number, "p"),
+            ],
+            identi: BuildInFnIdenti::Stats(Self::PERCENTILE),
+        };
+
+        let covariance = BuildInFunction {
+            params: vec![
+                BuildInFnParam(ValueType::Object, "self"),
+                BuildInFnParam(ValueType::Void, "x"),
+                BuildInFnParam(ValueType::Void, "y"),
+            ],
+            identi: BuildInFnIdenti::Stats(Self::COVARIANCE),
+        };
+        let mut correlation = covariance.clone();
+        let mut linreg = covariance.clone();
+        correlation.identi = BuildInFnIdenti::Stats(Self::CORRELATION);
+        linreg.identi = BuildInFnIdenti::Stats(Self::LINREG);
+
+        let module_obj_props = vec![
+            (String::from("sum"), Value::from(sum)),
+            (String::from("mean"), Value::from(mean)),
+            (String::from("median"), Value::from(median)),
+            (String::from("mode"), Value::from(mode)),
+            (String::from("variance"), Value::from(variance)),
+            (String::from("pvariance"), Value::from(pvariance)),
+            (String::from("stdev"), Value::from(stdev)),
+            (String::from("pstdev"), Value::from(pstdev)),
+            (String::from("min"), Value::from(min)),
+            (String::from("max"), Value::from(max)),
+            (String::from("percentile"), Value::from(percentile)),
+            (String::from("covariance"), Value::from(covariance)),
+            (String::from("correlation"), Value::from(correlation)),
+            (String::from("linreg"), Value::from(linreg)),
+        ];
+        return Object::new(module_obj_props, None);
+    }
+}
+
+impl BuildInFnCall for StatsModule {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let result = match self {
+            Self::SUM => {
+                let data = Self::get_data("data", scope, 0)?;
+                let sum = data.into_iter().fold(Number::Int(0), |acc, x| acc + x);
+                Value::from(sum)
+            }
+            Self::MIN | Self::MAX => {
+                let mut data = Self::get_sorted_data("data", scope)?;
+                let target = match self {
+                    Self::MIN => data.swap_remove(0),
+                    Self::MAX => data.pop().unwrap(),
+                    _ => unreachable!(),
+                };
+                Value::from(target)
+            }
+            Self::MEDIAN => {
+                let mut data = Self::get_sorted_data("data", scope)?;
+                let middle = data.len() / 2;
+                if data.len() % 2 == 1 {
+                    Value::from(data.swap_remove(middle))
+                } else {
+                    let sum = data[middle - 1].float_value() + data[middle].float_value();
+                    Value::from(sum / 2.0)
+                }
+            }
+            Self::MODE => {
+                // the smallest one of the most frequent numbers
+                let data = Self::get_sorted_data("data", scope)?;
+                let mut mode_index = 0;
+                let mut mode_count = 0;
+                let mut run_start = 0;
+                for index in 1..=data.len() {
+                    if index < data.len() && data[index] == data[run_start] {
+                        continue;
+                    }
+                    if index - run_start > mode_count {
+                        mode_index = run_start;
+                        mode_count = index - run_start;
+                    }
+                    run_start = index;
+                }
+                Value::from(data[mode_index].clone())
+            }
+            Self::PERCENTILE => {
+                let data = Self::get_sorted_data("data", scope)?;
+                let p = get_val("p", scope)?.get_f64()?;
+                if !(0.0..=100.0).contains(&p) {
+                    return Err(math_error("`p` should be between 0 and 100")?);
+                }
+
+                // linear interpolation between closest ranks
+                let rank = p / 100.0 * (data.len() - 1) as f64;
+                let lower_index = rank.floor() as usize;
+                let upper_index = rank.ceil() as usize;
+                let lower = data[lower_index].float_value();
+                let upper = data[upper_index].float_value();
+                Value::from(lower + (upper - lower) * (rank - lower_index as f64))
+            }
+
+            Self::MEAN | Self::VARIANCE | Self::PVARIANCE | Self::STDEV | Self::PSTDEV => {
+                let min_len = match self {
+                    Self::VARIANCE | Self::STDEV => 2,
+                    _ => 1,
+                };
+                let data = Self::get_data("data", scope, min_len)?;
+                let data: Vec<f64> = data.iter().map(Number::float_value).collect();
+                if *self == Self::MEAN {
+                    return Ok(Value::from(mean(&data)));
+                }
+
+                let square_sum = co_deviation(&data, &data);
+                let variance = match self {
+                    Self::VARIANCE | Self::STDEV => square_sum / (data.len() - 1) as f64,
+                    _ => square_sum / data.len() as f64,
+                };
+                match self {
+                    Self::VARIANCE | Self::PVARIANCE => Value::from(variance),
+                    _ => Value::from(variance.sqrt()),
+                }
+            }
+
+            Self::COVARIANCE => {
+                let (x, y) = Self::get_paired_data(scope)?;
+                Value::from(co_deviation(&x, &y) / (x.len() - 1) as f64)
+            }
+            Self::CORRELATION | Self::LINREG => {
+                let (x, y) = Self::get_paired_data(scope)?;
+                let sxx = co_deviation(&x, &x);
+                let syy = co_deviation(&y, &y);
+                let sxy = co_deviation(&x, &y);
+                if sxx == 0.0 {
+                    return Err(math_error("all the values in `x` are the same")?);
+                }
+
+                if *self == Self::CORRELATION {
+                    if syy == 0.0 {
+                        return Err(math_error("all the values in `y` are the same")?);
+                    }
+                    return Ok(Value::from(sxy / (sxx * syy).sqrt()));
+                }
+
+                let slope = sxy / sxx;
+                let intercept = mean(&y) - slope * mean(&x);
+                // coefficient of determination
+                let r2 = if syy == 0.0 {
+                    1.0
+                } else {
+                    sxy * sxy / (sxx * syy)
+                };
+                let result_map = HashMap::from([
+                    (String::from("slope"), Value::from(slope)),
+                    (String::from("intercept"), Value::from(intercept)),
+                    (String::from("r2"), Value::from(r2)),
+                ]);
+                Value::from(RawMap::new(result_map))
+            }
+        };
+        return Ok(result);
+    }
+}
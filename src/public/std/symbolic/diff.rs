@@ -0,0 +1,93 @@
+use crate::public::env::{AngleMode, ENV_OPTION};
+use crate::public::error::math_error;
+use crate::public::value::number::Number;
+use crate::public::value::symbols::Symbols;
+
+use super::expr::Expr;
+
+// the derivative of trigonometric function is scaled by
+// the radians of unit angle in degree or gradian mode.
+fn angle_scale(derivative: Expr) -> Expr {
+    let angle_mode = unsafe { ENV_OPTION.angle_mode };
+    if angle_mode == AngleMode::Radian {
+        return derivative;
+    }
+    let factor = Expr::Number(Number::Float(angle_mode.to_radians(1.0)));
+    return derivative.mul(factor);
+}
+
+// symbolic derivative of `expr` with respect to `var`
+pub fn differentiate(expr: &Expr, var: &str) -> Result<Expr, ()> {
+    if !expr.contains(var) {
+        return Ok(Expr::int(0));
+    }
+
+    let result = match expr {
+        // the variable is the only leaf which contains `var`
+        Expr::Number(_) | Expr::Variable(_) => Expr::int(1),
+
+        Expr::Binary(symbol, left, right) => {
+            let (u, v) = (left.as_ref(), right.as_ref());
+            let du = differentiate(u, var)?;
+            let dv = differentiate(v, var)?;
+            match symbol {
+                Symbols::Plus => du.add(dv),
+                Symbols::Minus => du.sub(dv),
+                Symbols::Multiply => du.mul(v.clone()).add(u.clone().mul(dv)),
+                Symbols::Divide => {
+                    if !v.contains(var) {
+                        du.div(v.clone())
+                    } else {
+                        // (u'v - uv') / v^2
+                        let numerator = du.mul(v.clone()).sub(u.clone().mul(dv));
+                        numerator.div(v.clone().pow(Expr::int(2)))
+                    }
+                }
+                Symbols::Power => {
+                    if !v.contains(var) {
+                        // v * u^(v - 1) * u'
+                        let reduced = u.clone().pow(v.clone().sub(Expr::int(1)));
+                        v.clone().mul(reduced).mul(du)
+                    } else if !u.contains(var) {
+                        // u^v * ln(u) * v'
+                        let ln_u = Expr::call("ln", u.clone());
+                        expr.clone().mul(ln_u).mul(dv)
+                    } else {
+                        // u^v * (v' * ln(u) + v * u' / u)
+                        let ln_u = Expr::call("ln", u.clone());
+                        let inner = dv.mul(ln_u).add(v.clone().mul(du).div(u.clone()));
+                        expr.clone().mul(inner)
+                    }
+                }
+                _ => {
+                    let msg = format!("unable to differentiate `{}` operation", symbol);
+                    return Err(math_error(&msg)?);
+                }
+            }
+        }
+        Expr::Function(name, param) => {
+            let u = param.as_ref();
+            let du = differentiate(u, var)?;
+            // chain rule
+            match name.as_str() {
+                "sin" => angle_scale(Expr::call("cos", u.clone())).mul(du),
+                "cos" => angle_scale(Expr::call("sin", u.clone())).mul(du).neg(),
+                "tan" => angle_scale(du.div(Expr::call("cos", u.clone()).pow(Expr::int(2)))),
+                "exp" => Expr::call("exp", u.clone()).mul(du),
+                "ln" => du.div(u.clone()),
+                "sqrt" => du.div(Expr::int(2).mul(Expr::call("sqrt", u.clone()))),
+                _ => {
+                    let msg = format!("unable to differentiate function `Math.{}`", name);
+                    return Err(math_error(&msg)?);
+                }
+            }
+        }
+        Expr::Other(_) => {
+            return Err(math_error(&format!(
+                "unable to differentiate the part which contains `{}`",
+                var
+            ))?)
+        }
+    };
+    return Ok(result);
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+
+use crate::public::compile_time::ast::ast_enum::ASTNode;
+use crate::public::compile_time::ast::types::ExpressionNode;
+use crate::public::value::number::Number;
+use crate::public::value::symbols::Symbols;
+
+use super::expr::Expr;
+
+// the priority of the operand which never needs parentheses
+const ATOM_PRIORITY: i8 = 11;
+// unary minus is displayed with the same priority as `+` and `-`
+const NEGATIVE_PRIORITY: i8 = 6;
+
+fn symbol_info(symbol: Symbols) -> Option<(&'static str, i8)> {
+    let result = match symbol {
+        Symbols::Plus => ("+", 6),
+        Symbols::Minus => ("-", 6),
+        Symbols::Multiply => ("*", 7),
+        Symbols::Divide => ("/", 7),
+        Symbols::FloorDivide => ("//", 7),
+        Symbols::Mod => ("%", 7),
+        Symbols::Power => ("^", 8),
+        Symbols::Not => ("!", 9),
+        Symbols::BitNot => ("bnot ", 9),
+        Symbols::Factorial => ("!", 10),
+        Symbols::Percent => ("%", 10),
+        Symbols::PlusMinus => ("±", 6),
+        Symbols::ShiftLeft => ("<<", 5),
+        Symbols::ShiftRight => (">>", 5),
+        Symbols::BitAnd => ("band", 4),
+        Symbols::BitXor => ("bxor", 3),
+        Symbols::BitOr => ("bor", 2),
+        Symbols::LessThan => ("<", 1),
+        Symbols::MoreThan => (">", 1),
+        Symbols::LessThanEqual => ("<=", 1),
+        Symbols::MoreThanEqual => (">=", 1),
+        Symbols::CompareEqual => ("==", 1),
+        Symbols::NotEqual => ("!=", 1),
+        Symbols::AndSign => ("&", 0),
+        Symbols::OrSign => ("|", 0),
+        Symbols::Convert => ("->", -1),
+        _ => return None,
+    };
+    return Some(result);
+}
+
+fn wrap(text: String, priority: i8, min_priority: i8) -> String {
+    if priority < min_priority {
+        return format!("({})", text);
+    }
+    return text;
+}
+
+fn params_display(params: &[ExpressionNode]) -> Option<String> {
+    let mut texts = vec![];
+    for param in params {
+        texts.push(expression_display(param)?);
+    }
+    return Some(texts.join(", "));
+}
+
+// returns the text and its priority
+fn node_display(node: &ASTNode) -> Option<(String, i8)> {
+    let text = match node {
+        ASTNode::NumberLiteral(num) => {
+            let priority = match num {
+                Number::Int(_) | Number::Float(_) | Number::BigInt(_) | Number::Decimal(_)
+                    if num < &Number::Int(0) =>
+                {
+                    NEGATIVE_PRIORITY
+                }
+                _ => ATOM_PRIORITY,
+            };
+            return Some((num.to_string(), priority));
+        }
+        ASTNode::StringLiteral(str) => format!("{:?}", str),
+        ASTNode::Variable(var_node) => var_node.name.clone(),
+        ASTNode::Expression(expr_node) => return rpn_display(expr_node),
+        ASTNode::LazyExpression(l_expr_node) => lazy_expr_display(&l_expr_node.sub_sequence)?,
+        ASTNode::ArrayLiteral(arr_node) => format!("[{}]", params_display(&arr_node.elements)?),
+        ASTNode::ElementReading(reading_node) => {
+            let (target, priority) = node_display(&reading_node.target_node)?;
+            let index = expression_display(&reading_node.index_node)?;
+            format!("{}[{}]", wrap(target, priority, ATOM_PRIORITY), index)
+        }
+        ASTNode::ObjectReading(reading_node) => {
+            let (obj, priority) = node_display(&reading_node.obj_node)?;
+            format!(
+                "{}.{}",
+                wrap(obj, priority, ATOM_PRIORITY),
+                reading_node.property
+            )
+        }
+        ASTNode::Invocation(invoke_node) => {
+            let (caller, priority) = node_display(&invoke_node.caller)?;
+            // the `self` param inserted in method invocation is omitted
+            let mut params = invoke_node.params.as_slice();
+            if let (ASTNode::ObjectReading(reading_node), Some(first)) =
+                (&invoke_node.caller, params.first())
+            {
+                if first.elements == [reading_node.obj_node.clone()] {
+                    params = &params[1..];
+                }
+            }
+            let caller = wrap(caller, priority, ATOM_PRIORITY);
+            format!("{}({})", caller, params_display(params)?)
+        }
+        _ => return None,
+    };
+    return Some((text, ATOM_PRIORITY));
+}
+
+// infix text of the RPN expression and its priority
+fn rpn_display(node: &ExpressionNode) -> Option<(String, i8)> {
+    let mut stack = Vec::<(String, i8)>::new();
+
+    for element in &node.elements {
+        let current = match element {
+            ASTNode::SymbolLiteral(symbol @ (Symbols::Not | Symbols::BitNot)) => {
+                let (operand, priority) = stack.pop()?;
+                let (op_text, op_priority) = symbol_info(*symbol)?;
+                let text = format!("{}{}", op_text, wrap(operand, priority, op_priority + 1));
+                (text, op_priority)
+            }
+            ASTNode::SymbolLiteral(symbol @ (Symbols::Factorial | Symbols::Percent)) => {
+                let (operand, priority) = stack.pop()?;
+                let (op_text, op_priority) = symbol_info(*symbol)?;
+                let text = format!("{}{}", wrap(operand, priority, op_priority + 1), op_text);
+                (text, op_priority)
+            }
+            ASTNode::SymbolLiteral(symbol) => {
+                let (right, right_priority) = stack.pop()?;
+                let (left, left_priority) = stack.pop()?;
+                let (op_text, op_priority) = symbol_info(*symbol)?;
+
+                // `0 - x` is displayed as `-x`
+                if *symbol == Symbols::Minus && left == "0" {
+                    let text = format!("-{}", wrap(right, right_priority, NEGATIVE_PRIORITY + 1));
+                    stack.push((text, NEGATIVE_PRIORITY));
+                    continue;
+                }
+
+                // the expression is computed from left to right,
+                // the right operand with the same priority needs parentheses.
+                let left = wrap(left, left_priority, op_priority);
+                let right = wrap(right, right_priority, op_priority + 1);
+                (format!("{} {} {}", left, op_text, right), op_priority)
+            }
+            _ => node_display(element)?,
+        };
+        stack.push(current);
+    }
+
+    if stack.len() != 1 {
+        return None;
+    }
+    return stack.pop();
+}
+
+// returns None when the expression contains undisplayable nodes
+pub fn expression_display(node: &ExpressionNode) -> Option<String> {
+    return rpn_display(node).map(|(text, _)| text);
+}
+
+// the text of lazy expression displayed as `{expr}`
+pub fn lazy_expr_display(node: &ASTNode) -> Option<String> {
+    let ASTNode::Expression(expr_node) = node else {
+        return None;
+    };
+    return Some(format!("{{{}}}", expression_display(expr_node)?));
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ASTNode::Expression(expr_node) = self.to_node() else {
+            unreachable!()
+        };
+        match expression_display(&expr_node) {
+            Some(text) => write!(f, "{}", text),
+            None => write!(f, "..."),
+        }
+    }
+}
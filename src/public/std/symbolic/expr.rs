@@ -0,0 +1,245 @@
+use crate::public::compile_time::ast::ast_enum::{ASTNode, ASTVec};
+use crate::public::compile_time::ast::types::{
+    ExpressionNode, InvocationNode, ObjectReadingNode, VariableNode,
+};
+use crate::public::error::syntax_error;
+use crate::public::value::number::Number;
+use crate::public::value::symbols::Symbols;
+
+const MATH_MODULE_NAME: &str = "Math";
+
+// expression tree converted from the RPN `ExpressionNode`
+// in lazy expression, used in symbolic computing.
+#[derive(PartialEq, Clone)]
+pub enum Expr {
+    Number(Number),
+    Variable(String),
+    Binary(Symbols, Box<Expr>, Box<Expr>),
+    // single param function in Math module, e.g. `Math.sin(x)`
+    Function(String, Box<Expr>),
+    // the AST node which is not analyzable, kept as it is
+    Other(ASTNode),
+}
+
+fn node_contains(node: &ASTNode, var: &str) -> bool {
+    let elements_contain = |elements: &ASTVec| elements.iter().any(|e| node_contains(e, var));
+    match node {
+        ASTNode::Variable(var_node) => var_node.name == var,
+        ASTNode::Expression(expr_node) => elements_contain(&expr_node.elements),
+        ASTNode::Invocation(invoke_node) => {
+            node_contains(&invoke_node.caller, var)
+                || invoke_node
+                    .params
+                    .iter()
+                    .any(|param| elements_contain(&param.elements))
+        }
+        ASTNode::ElementReading(reading_node) => {
+            node_contains(&reading_node.target_node, var)
+                || elements_contain(&reading_node.index_node.elements)
+        }
+        ASTNode::ObjectReading(reading_node) => node_contains(&reading_node.obj_node, var),
+        ASTNode::ArrayLiteral(arr_node) => arr_node
+            .elements
+            .iter()
+            .any(|element| elements_contain(&element.elements)),
+        _ => false,
+    }
+}
+
+// returns the function name and the param node
+// if the invocation is like `Math.sin(x)`.
+fn math_invocation(invoke_node: &InvocationNode) -> Option<(&str, &ExpressionNode)> {
+    let ASTNode::ObjectReading(reading_node) = &invoke_node.caller else {
+        return None;
+    };
+    let ASTNode::Variable(obj_var) = &reading_node.obj_node else {
+        return None;
+    };
+    // the first param is the inserted `self`
+    if obj_var.name != MATH_MODULE_NAME || invoke_node.params.len() != 2 {
+        return None;
+    }
+    return Some((&reading_node.property, &invoke_node.params[1]));
+}
+
+impl Expr {
+    pub fn from_node(node: &ASTNode) -> Result<Self, ()> {
+        let ASTNode::Expression(expr_node) = node else {
+            return Err(syntax_error(
+                "lazy expression should contain an expression",
+            )?);
+        };
+        return Self::from_expression(expr_node);
+    }
+    pub fn from_expression(node: &ExpressionNode) -> Result<Self, ()> {
+        let mut stack = Vec::<Self>::new();
+
+        for element in &node.elements {
+            let current = match element {
+                ASTNode::NumberLiteral(num) => Self::Number(num.clone()),
+                ASTNode::Variable(var_node) => Self::Variable(var_node.name.clone()),
+                ASTNode::Expression(sub_node) => Self::from_expression(sub_node)?,
+                ASTNode::SymbolLiteral(
+                    symbol @ (Symbols::Not
+                    | Symbols::BitNot
+                    | Symbols::Factorial
+                    | Symbols::Percent),
+                ) => {
+                    let msg = format!("{} operator is not supported in symbolic computing", symbol);
+                    return Err(syntax_error(&msg)?);
+                }
+                ASTNode::SymbolLiteral(symbol) => {
+                    let (Some(right), Some(left)) = (stack.pop(), stack.pop()) else {
+                        return Err(syntax_error(
+                            "invalid expression as operating number missing",
+                        )?);
+                    };
+                    Self::Binary(*symbol, Box::new(left), Box::new(right))
+                }
+                ASTNode::Invocation(invoke_node) => match math_invocation(invoke_node) {
+                    Some((name, param)) => {
+                        let param_expr = Self::from_expression(param)?;
+                        Self::Function(name.to_string(), Box::new(param_expr))
+                    }
+                    None => Self::Other(element.clone()),
+                },
+                _ => Self::Other(element.clone()),
+            };
+            stack.push(current);
+        }
+
+        if stack.len() != 1 {
+            return Err(syntax_error("invalid expression for symbolic computing")?);
+        }
+        return Ok(stack.pop().unwrap());
+    }
+
+    pub fn to_node(&self) -> ASTNode {
+        let mut elements = ASTVec::new();
+        self.push_elements(&mut elements);
+        return ASTNode::Expression(ExpressionNode { elements }.into());
+    }
+    fn push_elements(&self, elements: &mut ASTVec) {
+        match self {
+            Self::Number(num) => elements.push(ASTNode::NumberLiteral(num.clone())),
+            Self::Variable(name) => {
+                let var_node = VariableNode { name: name.clone() };
+                elements.push(ASTNode::Variable(var_node.into()));
+            }
+            Self::Binary(symbol, left, right) => {
+                left.push_elements(elements);
+                right.push_elements(elements);
+                elements.push(ASTNode::SymbolLiteral(*symbol));
+            }
+            Self::Function(name, param) => {
+                let math_var = ASTNode::Variable(
+                    VariableNode {
+                        name: String::from(MATH_MODULE_NAME),
+                    }
+                    .into(),
+                );
+                let caller = ObjectReadingNode {
+                    obj_node: math_var.clone(),
+                    property: name.clone(),
+                };
+                let mut param_elements = ASTVec::new();
+                param.push_elements(&mut param_elements);
+
+                let invoke_node = InvocationNode {
+                    caller: ASTNode::ObjectReading(caller.into()),
+                    params: vec![
+                        ExpressionNode {
+                            elements: vec![math_var],
+                        },
+                        ExpressionNode {
+                            elements: param_elements,
+                        },
+                    ],
+                };
+                elements.push(ASTNode::Invocation(invoke_node.into()));
+            }
+            Self::Other(node) => elements.push(node.clone()),
+        }
+    }
+
+    // --- --- --- --- --- ---
+
+    pub fn int(num: i64) -> Self {
+        Self::Number(Number::Int(num))
+    }
+    pub fn is_int(&self, target: i64) -> bool {
+        match self {
+            Self::Number(num) => *num == Number::Int(target),
+            _ => false,
+        }
+    }
+    pub fn contains(&self, var: &str) -> bool {
+        match self {
+            Self::Number(_) => false,
+            Self::Variable(name) => name == var,
+            Self::Binary(_, left, right) => left.contains(var) || right.contains(var),
+            Self::Function(_, param) => param.contains(var),
+            Self::Other(node) => node_contains(node, var),
+        }
+    }
+
+    // the constructors below do basic simplification,
+    // such as removing `0 +` and `1 *` terms and folding numbers.
+
+    pub fn add(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Number(num1), Self::Number(num2)) => Self::Number(num1 + num2),
+            (expr, zero) | (zero, expr) if zero.is_int(0) => expr,
+            (expr1, expr2) => Self::Binary(Symbols::Plus, expr1.into(), expr2.into()),
+        }
+    }
+    pub fn sub(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Number(num1), Self::Number(num2)) => Self::Number(num1 - num2),
+            (expr, zero) if zero.is_int(0) => expr,
+            (expr1, expr2) if expr1 == expr2 => Self::int(0),
+            (expr1, expr2) => Self::Binary(Symbols::Minus, expr1.into(), expr2.into()),
+        }
+    }
+    pub fn neg(self) -> Self {
+        match self {
+            Self::Number(num) => Self::Number(Number::Int(0) - num),
+            Self::Binary(Symbols::Minus, zero, expr) if zero.is_int(0) => *expr,
+            expr => Self::Binary(Symbols::Minus, Self::int(0).into(), expr.into()),
+        }
+    }
+    pub fn mul(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Number(num1), Self::Number(num2)) => Self::Number(num1 * num2),
+            (_, zero) | (zero, _) if zero.is_int(0) => Self::int(0),
+            (expr, one) | (one, expr) if one.is_int(1) => expr,
+            (expr, minus_one) | (minus_one, expr) if minus_one.is_int(-1) => expr.neg(),
+            (expr1, expr2) => Self::Binary(Symbols::Multiply, expr1.into(), expr2.into()),
+        }
+    }
+    pub fn div(self, other: Self) -> Self {
+        match (self, other) {
+            // folded with the same division as the evaluator,
+            // the integer division is truncated when not in exact mode.
+            (Self::Number(num1), Self::Number(num2)) if !num2.is_zero() => {
+                Self::Number(num1 / num2)
+            }
+            (zero, _) if zero.is_int(0) => Self::int(0),
+            (expr, one) if one.is_int(1) => expr,
+            (expr1, expr2) => Self::Binary(Symbols::Divide, expr1.into(), expr2.into()),
+        }
+    }
+    pub fn pow(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Number(Number::Int(base)), Self::Number(Number::Int(exp))) if exp >= 0 => {
+                Self::Number(Number::pow(Number::Int(base), Number::Int(exp)))
+            }
+            (_, zero) if zero.is_int(0) => Self::int(1),
+            (expr, one) if one.is_int(1) => expr,
+            (expr1, expr2) => Self::Binary(Symbols::Power, expr1.into(), expr2.into()),
+        }
+    }
+    pub fn call(name: &str, param: Self) -> Self {
+        Self::Function(String::from(name), param.into())
+    }
+}
@@ -0,0 +1,6 @@
+pub mod diff;
+pub mod display;
+pub mod expr;
+pub mod polynomial;
+pub mod simplify;
+pub mod solve;
@@ -0,0 +1,444 @@
+use std::cmp::Ordering;
+
+use crate::public::error::math_error;
+use crate::public::value::complex::Complex;
+use crate::public::value::number::Number;
+use crate::public::value::symbols::Symbols;
+
+use super::expr::Expr;
+
+// the power whose result degree is greater than this
+// is not supported in polynomial.
+const MAX_DEGREE: usize = 64;
+
+// the iteration count and the tolerance of Durand-Kerner method
+const MAX_ITERATION: usize = 500;
+const TOLERANCE: f64 = 1e-14;
+// the imaginary part of numeric root whose modulus is
+// less than this (relatively) is regarded as ZERO.
+const IMAGINARY_EPSILON: f64 = 1e-10;
+
+// the max modulus of the integer coefficients
+// whose divisors are tried as rational roots.
+const MAX_DIVISOR_SEARCH: i128 = 1_000_000;
+
+fn is_exact(num: &Number) -> bool {
+    !matches!(
+        num,
+        Number::Float(_) | Number::Decimal(_) | Number::Complex(_) | Number::NotANumber
+    )
+}
+
+// positive divisors of a non-zero integer
+fn divisors(num: i128) -> Vec<i128> {
+    let num = num.abs();
+    let mut result = vec![];
+    let mut i = 1;
+    while i * i <= num {
+        if num % i == 0 {
+            result.push(i);
+            if i * i != num {
+                result.push(num / i);
+            }
+        }
+        i += 1;
+    }
+    return result;
+}
+
+// polynomial of single variable,
+// the coefficients are ordered from the lowest degree.
+#[derive(PartialEq, Clone)]
+pub struct Polynomial {
+    coeffs: Vec<Number>,
+}
+
+impl Polynomial {
+    pub fn new(mut coeffs: Vec<Number>) -> Self {
+        // remove the ZERO coefficients of the highest degrees
+        while coeffs.last().is_some_and(Number::is_zero) {
+            coeffs.pop();
+        }
+        return Self { coeffs };
+    }
+    pub fn constant(num: Number) -> Self {
+        return Self::new(vec![num]);
+    }
+    fn monomial(degree: usize) -> Self {
+        let mut coeffs = vec![Number::Int(0); degree];
+        coeffs.push(Number::Int(1));
+        return Self::new(coeffs);
+    }
+
+    #[inline]
+    pub fn coeffs(&self) -> &[Number] {
+        &self.coeffs
+    }
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+    // the degree of ZERO polynomial is None
+    pub fn degree(&self) -> Option<usize> {
+        return self.coeffs.len().checked_sub(1);
+    }
+    fn as_constant(&self) -> Option<Number> {
+        match self.coeffs.len() {
+            0 => Some(Number::Int(0)),
+            1 => Some(self.coeffs[0].clone()),
+            _ => None,
+        }
+    }
+
+    // --- --- --- --- --- ---
+
+    pub fn add(&self, other: &Self) -> Self {
+        let len = self.coeffs.len().max(other.coeffs.len());
+        let zero = Number::Int(0);
+        let coeffs = (0..len)
+            .map(|i| {
+                let c1 = self.coeffs.get(i).unwrap_or(&zero).clone();
+                let c2 = other.coeffs.get(i).unwrap_or(&zero).clone();
+                c1 + c2
+            })
+            .collect();
+        return Self::new(coeffs);
+    }
+    pub fn scale(&self, factor: &Number) -> Self {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|c| c.clone() * factor.clone())
+            .collect();
+        return Self::new(coeffs);
+    }
+    pub fn sub(&self, other: &Self) -> Self {
+        return self.add(&other.scale(&Number::Int(-1)));
+    }
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::new(vec![]);
+        }
+        let mut coeffs = vec![Number::Int(0); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, c1) in self.coeffs.iter().enumerate() {
+            for (j, c2) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].clone() + c1.clone() * c2.clone();
+            }
+        }
+        return Self::new(coeffs);
+    }
+    pub fn pow(&self, mut exp: usize) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::constant(Number::Int(1));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(&base);
+            }
+            base = base.mul(&base);
+            exp >>= 1;
+        }
+        return result;
+    }
+
+    // Horner's method
+    pub fn eval(&self, x: &Number) -> Number {
+        let mut result = Number::Int(0);
+        for c in self.coeffs.iter().rev() {
+            result = result * x.clone() + c.clone();
+        }
+        return result;
+    }
+    // divide by `(x - root)` with synthetic division,
+    // the remainder is dropped.
+    fn deflate(&self, root: &Number) -> Self {
+        let mut quotient = vec![Number::Int(0); self.coeffs.len() - 1];
+        let mut carry = Number::Int(0);
+        for i in (1..self.coeffs.len()).rev() {
+            carry = carry * root.clone() + self.coeffs[i].clone();
+            quotient[i - 1] = carry.clone();
+        }
+        return Self::new(quotient);
+    }
+
+    // polynomial long division, returns (quotient, remainder).
+    // the divisor should not be ZERO polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor.coeffs.len() - 1;
+        if self.coeffs.len() <= divisor_degree {
+            return (Self::new(vec![]), self.clone());
+        }
+        let leading = &divisor.coeffs[divisor_degree];
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![Number::Int(0); self.coeffs.len() - divisor_degree];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor_degree].true_div(leading);
+            for (j, c) in divisor.coeffs.iter().enumerate() {
+                remainder[i + j] = remainder[i + j].clone() - factor.clone() * c.clone();
+            }
+            // avoid the rounding residue of float
+            remainder[i + divisor_degree] = Number::Int(0);
+            quotient[i] = factor;
+        }
+        return (Self::new(quotient), Self::new(remainder));
+    }
+    // the monic greatest common divisor with Euclidean algorithm
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, remainder) = a.div_rem(&b);
+            (a, b) = (b, remainder);
+        }
+        let Some(leading) = a.coeffs.last().cloned() else {
+            return a;
+        };
+        return a.scale(&Number::Int(1).true_div(&leading));
+    }
+
+    // --- --- --- --- --- ---
+
+    // convert the expression into polynomial of `var`,
+    // the sub-expressions without `var` are computed by `eval_const`.
+    // returns None when the expression is not polynomial.
+    pub fn from_expr(
+        expr: &Expr,
+        var: &str,
+        eval_const: &mut impl FnMut(&Expr) -> Result<Number, ()>,
+    ) -> Result<Option<Self>, ()> {
+        if let Expr::Number(num) = expr {
+            return Ok(Some(Self::constant(num.clone())));
+        }
+        if !expr.contains(var) {
+            let num = eval_const(expr)?;
+            return Ok(Some(Self::constant(num)));
+        }
+
+        let result = match expr {
+            Expr::Variable(_) => Some(Self::monomial(1)),
+            Expr::Binary(symbol, left, right) => {
+                let Some(left) = Self::from_expr(left, var, eval_const)? else {
+                    return Ok(None);
+                };
+                let Some(right) = Self::from_expr(right, var, eval_const)? else {
+                    return Ok(None);
+                };
+                match symbol {
+                    Symbols::Plus => Some(left.add(&right)),
+                    Symbols::Minus => Some(left.sub(&right)),
+                    Symbols::Multiply => Some(left.mul(&right)),
+                    Symbols::Divide => match right.as_constant() {
+                        Some(divisor) if !divisor.is_zero() => {
+                            let reciprocal = Number::Int(1).true_div(&divisor);
+                            Some(left.scale(&reciprocal))
+                        }
+                        _ => None,
+                    },
+                    Symbols::Power => match (right.as_constant(), left.degree()) {
+                        (Some(Number::Int(exp)), degree) if exp >= 0 => {
+                            let result_degree = degree.unwrap_or(0).checked_mul(exp as usize);
+                            if result_degree.is_some_and(|d| d <= MAX_DEGREE) {
+                                Some(left.pow(exp as usize))
+                            } else {
+                                // the roots would be incomplete if solved as non-polynomial
+                                let msg = format!(
+                                    "polynomial of degree greater than {} is not supported",
+                                    MAX_DEGREE
+                                );
+                                return Err(math_error(&msg)?);
+                            }
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        return Ok(result);
+    }
+
+    // --- --- --- --- --- ---
+
+    // all the roots of the polynomial, the repeated roots are listed once.
+    // rational roots are exact when all the coefficients are exact,
+    // the others are computed numerically.
+    pub fn roots(&self) -> Vec<Number> {
+        let mut poly = self.clone();
+        let mut roots = vec![];
+
+        // the ZERO roots
+        if poly.coeffs.len() > 1 && poly.coeffs[0].is_zero() {
+            roots.push(Number::Int(0));
+            let zero_count = poly.coeffs.iter().take_while(|c| c.is_zero()).count();
+            poly = Self::new(poly.coeffs[zero_count..].to_vec());
+        }
+
+        if poly.degree().is_some_and(|degree| degree > 2) {
+            for candidate in poly.rational_candidates() {
+                let mut is_root = false;
+                while poly.degree().is_some_and(|degree| degree > 0)
+                    && poly.eval(&candidate).is_zero()
+                {
+                    poly = poly.deflate(&candidate);
+                    is_root = true;
+                }
+                if is_root {
+                    roots.push(candidate);
+                }
+            }
+        }
+
+        match poly.degree() {
+            None | Some(0) => {}
+            Some(1) => {
+                let root = (Number::Int(0) - poly.coeffs[0].clone()).true_div(&poly.coeffs[1]);
+                roots.push(root);
+            }
+            Some(2) => roots.extend(poly.quadratic_roots()),
+            Some(_) => roots.extend(poly.numeric_roots()),
+        }
+
+        // the real roots are listed ascendingly before the complex ones
+        let mut result = Vec::<Number>::new();
+        for root in roots {
+            if !result.contains(&root) {
+                result.push(root);
+            }
+        }
+        result.sort_by(|root1, root2| match (root1, root2) {
+            (Number::Complex(_), Number::Complex(_)) => Ordering::Equal,
+            (Number::Complex(_), _) => Ordering::Greater,
+            (_, Number::Complex(_)) => Ordering::Less,
+            _ => root1.partial_cmp(root2).unwrap_or(Ordering::Equal),
+        });
+        return result;
+    }
+
+    // the rational root theorem, the candidates are `±p/q`
+    // where `p` divides the constant term and `q` divides the leading one.
+    fn rational_candidates(&self) -> Vec<Number> {
+        if !self.coeffs.iter().all(is_exact) {
+            return vec![];
+        }
+        let mut ratios = vec![];
+        for c in &self.coeffs {
+            let ratio = match c {
+                Number::Int(num) => (*num as i128, 1),
+                Number::Fraction(upper, lower) => (*upper as i128, *lower as i128),
+                // too large to search divisors
+                _ => return vec![],
+            };
+            ratios.push(ratio);
+        }
+
+        // scale the coefficients to integers
+        let mut common_lower = 1_i128;
+        for (_, lower) in &ratios {
+            common_lower = Number::lcm(common_lower, *lower);
+            if common_lower > MAX_DIVISOR_SEARCH {
+                return vec![];
+            }
+        }
+        let constant = ratios[0].0 * (common_lower / ratios[0].1);
+        let (lead_upper, lead_lower) = ratios[ratios.len() - 1];
+        let leading = lead_upper * (common_lower / lead_lower);
+        if constant == 0
+            || constant.abs() > MAX_DIVISOR_SEARCH
+            || leading.abs() > MAX_DIVISOR_SEARCH
+        {
+            return vec![];
+        }
+
+        let mut candidates = Vec::<Number>::new();
+        for p in divisors(constant) {
+            for q in divisors(leading) {
+                for candidate in [
+                    Number::from_small_ratio(p, q),
+                    Number::from_small_ratio(-p, q),
+                ] {
+                    if !candidates.contains(&candidate) {
+                        candidates.push(candidate);
+                    }
+                }
+            }
+        }
+        return candidates;
+    }
+
+    fn quadratic_roots(&self) -> Vec<Number> {
+        let (c, b, a) = (&self.coeffs[0], &self.coeffs[1], &self.coeffs[2]);
+        let discriminant = b.clone() * b.clone() - Number::Int(4) * a.clone() * c.clone();
+        let minus_b = Number::Int(0) - b.clone();
+        let double_a = Number::Int(2) * a.clone();
+
+        if self.coeffs.iter().all(is_exact) {
+            if let Some(root) = discriminant.exact_sqrt() {
+                let root1 = (minus_b.clone() - root.clone()).true_div(&double_a);
+                let root2 = (minus_b + root).true_div(&double_a);
+                return vec![root1, root2];
+            }
+        }
+        let is_real = !matches!(discriminant, Number::Complex(_));
+        if is_real && discriminant.float_value() >= 0.0 {
+            let root = discriminant.float_value().sqrt();
+            let (minus_b, double_a) = (minus_b.float_value(), double_a.float_value());
+            let root1 = (minus_b - root) / double_a;
+            let root2 = (minus_b + root) / double_a;
+            return vec![Number::Float(root1), Number::Float(root2)];
+        }
+
+        let root = discriminant.complex_value().sqrt();
+        let (minus_b, double_a) = (minus_b.complex_value(), double_a.complex_value());
+        let root1 = (minus_b - root) / double_a;
+        let root2 = (minus_b + root) / double_a;
+        return vec![Number::from_complex(root1), Number::from_complex(root2)];
+    }
+
+    // Durand-Kerner method, finds all the roots simultaneously
+    fn numeric_roots(&self) -> Vec<Number> {
+        let leading = self.coeffs[self.coeffs.len() - 1].complex_value();
+        let monic: Vec<Complex> = self
+            .coeffs
+            .iter()
+            .map(|c| c.complex_value() / leading)
+            .collect();
+        let eval = |z: Complex| {
+            let mut result = Complex::new(0.0, 0.0);
+            for c in monic.iter().rev() {
+                result = result * z + *c;
+            }
+            return result;
+        };
+
+        let degree = monic.len() - 1;
+        let seed = Complex::new(0.4, 0.9);
+        let mut roots: Vec<Complex> = (0..degree).map(|i| seed.powi(i as i64)).collect();
+        for _ in 0..MAX_ITERATION {
+            let mut max_delta = 0.0_f64;
+            for i in 0..degree {
+                let mut denominator = Complex::new(1.0, 0.0);
+                for j in 0..degree {
+                    if i != j {
+                        denominator = denominator * (roots[i] - roots[j]);
+                    }
+                }
+                let delta = eval(roots[i]) / denominator;
+                roots[i] = roots[i] - delta;
+                max_delta = max_delta.max(delta.abs());
+            }
+            if max_delta < TOLERANCE {
+                break;
+            }
+        }
+
+        return roots
+            .into_iter()
+            .map(|z| {
+                if z.im.abs() < IMAGINARY_EPSILON * z.re.abs().max(1.0) {
+                    Number::Float(z.re)
+                } else {
+                    Number::from_complex(z)
+                }
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,457 @@
+use std::cmp::Ordering;
+
+use crate::public::env::ENV_OPTION;
+use crate::public::value::number::Number;
+use crate::public::value::symbols::Symbols;
+
+use super::expr::Expr;
+use super::polynomial::Polynomial;
+
+// the max exponent of the power of sum which is expanded
+const MAX_EXPAND_EXPONENT: i64 = 32;
+// the product of sums resulting in more terms than this is not expanded
+const MAX_EXPAND_TERMS: usize = 1000;
+
+// the base expression with its text, the text is used to
+// identify and sort the factors.
+#[derive(Clone)]
+struct Factor {
+    key: String,
+    base: Expr,
+    exp: Number,
+}
+
+// `coeff * base1 ^ exp1 * base2 ^ exp2 * ...`,
+// the factors are sorted by key and the exponents are not ZERO.
+#[derive(Clone)]
+struct Term {
+    coeff: Number,
+    factors: Vec<Factor>,
+}
+
+// the sum of terms without like terms,
+// the coefficients are not ZERO.
+type Sum = Vec<Term>;
+
+fn is_negative(num: &Number) -> bool {
+    !matches!(num, Number::Complex(_)) && num < &Number::Int(0)
+}
+
+impl Term {
+    fn constant(num: Number) -> Self {
+        Self {
+            coeff: num,
+            factors: vec![],
+        }
+    }
+    fn atom(base: Expr, exp: Number) -> Self {
+        Self {
+            coeff: Number::Int(1),
+            factors: vec![Factor {
+                key: base.to_string(),
+                base,
+                exp,
+            }],
+        }
+    }
+    fn is_like(&self, other: &Self) -> bool {
+        self.factors.len() == other.factors.len()
+            && self
+                .factors
+                .iter()
+                .zip(&other.factors)
+                .all(|(f1, f2)| f1.key == f2.key && f1.exp == f2.exp)
+    }
+    fn degree(&self) -> f64 {
+        return self.factors.iter().map(|f| f.exp.float_value()).sum();
+    }
+    fn key(&self) -> String {
+        let keys: Vec<&str> = self.factors.iter().map(|f| f.key.as_str()).collect();
+        return keys.join("*");
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut factors = self.factors.clone();
+        for factor in &other.factors {
+            match factors.iter().position(|f| f.key == factor.key) {
+                Some(index) => {
+                    let exp = factors[index].exp.clone() + factor.exp.clone();
+                    if exp.is_zero() {
+                        factors.remove(index);
+                    } else {
+                        factors[index].exp = exp;
+                    }
+                }
+                None => factors.push(factor.clone()),
+            }
+        }
+        factors.sort_by(|f1, f2| f1.key.cmp(&f2.key));
+        return Self {
+            coeff: self.coeff.clone() * other.coeff.clone(),
+            factors,
+        };
+    }
+    // returns None when the coefficient is ZERO
+    fn pow(&self, exp: i64) -> Option<Self> {
+        // the negative power is float when not in exact mode
+        let coeff = if exp >= 0 || !unsafe { ENV_OPTION.exact_mode } {
+            Number::pow(self.coeff.clone(), Number::Int(exp))
+        } else {
+            if self.coeff.is_zero() {
+                return None;
+            }
+            let power = Number::pow(self.coeff.clone(), Number::Int(-exp));
+            Number::Int(1).true_div(&power)
+        };
+        let factors = self
+            .factors
+            .iter()
+            .map(|f| Factor {
+                exp: f.exp.clone() * Number::Int(exp),
+                ..f.clone()
+            })
+            .collect();
+        return Some(Self { coeff, factors });
+    }
+
+    fn to_expr(&self) -> Expr {
+        // the fraction coefficient is split into numerator and denominator
+        let (upper, lower) = match self.coeff {
+            Number::Fraction(upper, lower) => (Number::Int(upper), Number::Int(lower)),
+            _ => (self.coeff.clone(), Number::Int(1)),
+        };
+        if self.factors.is_empty() {
+            return Expr::Number(self.coeff.clone());
+        }
+
+        let mut numerator = Expr::Number(upper);
+        let mut denominator = Expr::Number(lower);
+        for factor in &self.factors {
+            // the division is truncated when not in exact mode,
+            // so that the negative power is kept as it is.
+            if is_negative(&factor.exp) && unsafe { ENV_OPTION.exact_mode } {
+                let exp = Number::Int(0) - factor.exp.clone();
+                denominator = denominator.mul(factor.base.clone().pow(Expr::Number(exp)));
+            } else {
+                numerator =
+                    numerator.mul(factor.base.clone().pow(Expr::Number(factor.exp.clone())));
+            }
+        }
+        return numerator.div(denominator);
+    }
+}
+
+// --- --- --- --- --- ---
+
+fn constant_of(sum: &Sum) -> Option<Number> {
+    match sum.as_slice() {
+        [] => Some(Number::Int(0)),
+        [term] if term.factors.is_empty() => Some(term.coeff.clone()),
+        _ => None,
+    }
+}
+
+fn add_sums(mut sum1: Sum, sum2: Sum) -> Sum {
+    for term in sum2 {
+        match sum1.iter().position(|t| t.is_like(&term)) {
+            Some(index) => {
+                let coeff = sum1[index].coeff.clone() + term.coeff;
+                if coeff.is_zero() {
+                    sum1.remove(index);
+                } else {
+                    sum1[index].coeff = coeff;
+                }
+            }
+            None => {
+                if !term.coeff.is_zero() {
+                    sum1.push(term);
+                }
+            }
+        }
+    }
+    return sum1;
+}
+fn scale_sum(sum: Sum, factor: &Term) -> Sum {
+    let terms = sum.iter().map(|term| term.mul(factor)).collect();
+    // the products may become like terms
+    return add_sums(vec![], terms);
+}
+
+// the sum with more than one term is regarded
+// as a whole factor when it is not expanded.
+fn as_term(sum: Sum) -> Term {
+    if sum.len() == 1 {
+        return sum.into_iter().next().unwrap();
+    }
+    return Term::atom(rebuild(sum), Number::Int(1));
+}
+
+fn mul_sums(sum1: Sum, sum2: Sum, expand: bool) -> Sum {
+    if sum1.is_empty() || sum2.is_empty() {
+        return vec![];
+    }
+    if expand && sum1.len() * sum2.len() <= MAX_EXPAND_TERMS {
+        let mut result = vec![];
+        for term in &sum2 {
+            result = add_sums(result, scale_sum(sum1.clone(), term));
+        }
+        return result;
+    }
+    return add_sums(vec![], vec![as_term(sum1).mul(&as_term(sum2))]);
+}
+
+fn pow_sum(base: Sum, exp: &Number, expand: bool) -> Sum {
+    let Number::Int(exp) = *exp else {
+        let power = Expr::Binary(
+            Symbols::Power,
+            rebuild(base).into(),
+            Expr::Number(exp.clone()).into(),
+        );
+        return vec![Term::atom(power, Number::Int(1))];
+    };
+    if exp == 0 {
+        return vec![Term::constant(Number::Int(1))];
+    }
+    if base.is_empty() && exp > 0 {
+        return vec![];
+    }
+    if let [term] = base.as_slice() {
+        if let Some(power) = term.pow(exp) {
+            return vec![power];
+        }
+    }
+    if expand && exp > 0 && exp <= MAX_EXPAND_EXPONENT {
+        let mut result = base.clone();
+        for _ in 1..exp {
+            result = mul_sums(result, base.clone(), true);
+        }
+        return result;
+    }
+    return vec![Term::atom(rebuild(base), Number::Int(exp))];
+}
+
+// the variable and the polynomial when the sum is
+// a polynomial of single variable with exact coefficients.
+fn as_polynomial(sum: &Sum, var: &mut Option<String>) -> Option<Polynomial> {
+    let mut coeffs = vec![];
+    for term in sum {
+        if matches!(
+            term.coeff,
+            Number::Float(_) | Number::Decimal(_) | Number::Complex(_)
+        ) {
+            return None;
+        }
+        let degree = match term.factors.as_slice() {
+            [] => 0,
+            [Factor {
+                base: Expr::Variable(name),
+                exp: Number::Int(exp),
+                ..
+            }] if *exp > 0 && var.as_ref().is_none_or(|v| v == name) => {
+                *var = Some(name.clone());
+                *exp as usize
+            }
+            _ => return None,
+        };
+        if coeffs.len() <= degree {
+            coeffs.resize(degree + 1, Number::Int(0));
+        }
+        coeffs[degree] = coeffs[degree].clone() + term.coeff.clone();
+    }
+    return Some(Polynomial::new(coeffs));
+}
+fn from_polynomial(poly: &Polynomial, var: &str) -> Sum {
+    let mut result = vec![];
+    for (degree, coeff) in poly.coeffs().iter().enumerate() {
+        if coeff.is_zero() {
+            continue;
+        }
+        let mut term = Term::constant(coeff.clone());
+        if degree > 0 {
+            let base = Expr::Variable(String::from(var));
+            term = term.mul(&Term::atom(base, Number::Int(degree as i64)));
+        }
+        result.push(term);
+    }
+    return result;
+}
+
+fn div_sums(mut dividend: Sum, mut divisor: Sum, expand: bool) -> Sum {
+    if let Some(divisor_num) = constant_of(&divisor) {
+        if divisor_num.is_zero() {
+            // keep the division by ZERO as it is
+            let quotient = Expr::Binary(
+                Symbols::Divide,
+                rebuild(dividend).into(),
+                Expr::int(0).into(),
+            );
+            return vec![Term::atom(quotient, Number::Int(1))];
+        }
+    }
+    if divisor.len() == 1 {
+        let reciprocal = divisor[0].pow(-1).unwrap();
+        return mul_sums(dividend, vec![reciprocal], expand);
+    }
+
+    // cancel the common factor of polynomials
+    let mut var = None;
+    if let (Some(poly1), Some(poly2)) = (
+        as_polynomial(&dividend, &mut var),
+        as_polynomial(&divisor, &mut var),
+    ) {
+        let var = var.unwrap_or_default();
+        let common = poly1.gcd(&poly2);
+        if common.degree().is_some_and(|degree| degree > 0) {
+            dividend = from_polynomial(&poly1.div_rem(&common).0, &var);
+            divisor = from_polynomial(&poly2.div_rem(&common).0, &var);
+            if divisor.len() == 1 {
+                return div_sums(dividend, divisor, expand);
+            }
+        }
+    }
+    if rebuild(dividend.clone()) == rebuild(divisor.clone()) {
+        return vec![Term::constant(Number::Int(1))];
+    }
+
+    let reciprocal = Term::atom(rebuild(divisor), Number::Int(-1));
+    return mul_sums(dividend, vec![reciprocal], expand);
+}
+
+// the integer division is truncated when not in exact mode, e.g. `x / 2 * 2`,
+// so that the division is only distributed by the divisor not truncating.
+fn is_distributive(divisor: &Option<Number>) -> bool {
+    if unsafe { ENV_OPTION.exact_mode } {
+        return true;
+    }
+    return match divisor {
+        Some(Number::Int(1 | -1)) => true,
+        Some(num) => matches!(
+            num,
+            Number::Float(_) | Number::Decimal(_) | Number::Complex(_)
+        ),
+        None => false,
+    };
+}
+
+fn to_sum(expr: &Expr, expand: bool) -> Sum {
+    let result = match expr {
+        Expr::Number(num) => {
+            if num.is_zero() {
+                return vec![];
+            }
+            vec![Term::constant(num.clone())]
+        }
+        Expr::Binary(symbol, left, right) => {
+            let left_sum = to_sum(left, expand);
+            let right_sum = to_sum(right, expand);
+            match symbol {
+                Symbols::Plus => add_sums(left_sum, right_sum),
+                Symbols::Minus => {
+                    let minus_one = Term::constant(Number::Int(-1));
+                    add_sums(left_sum, scale_sum(right_sum, &minus_one))
+                }
+                Symbols::Multiply => mul_sums(left_sum, right_sum, expand),
+                Symbols::Divide => match (constant_of(&left_sum), constant_of(&right_sum)) {
+                    (Some(dividend), Some(divisor)) if !divisor.is_zero() => {
+                        to_sum(&Expr::Number(dividend / divisor), expand)
+                    }
+                    (_, divisor) if is_distributive(&divisor) => {
+                        div_sums(left_sum, right_sum, expand)
+                    }
+                    _ => {
+                        let quotient = rebuild(left_sum).div(rebuild(right_sum));
+                        vec![Term::atom(quotient, Number::Int(1))]
+                    }
+                },
+                Symbols::Power => match (constant_of(&left_sum), constant_of(&right_sum)) {
+                    (Some(base), Some(exp)) if !matches!(exp, Number::Int(_)) => {
+                        to_sum(&Expr::Number(Number::pow(base, exp)), expand)
+                    }
+                    (_, Some(exp)) => pow_sum(left_sum, &exp, expand),
+                    _ => {
+                        let power = rebuild(left_sum).pow(rebuild(right_sum));
+                        vec![Term::atom(power, Number::Int(1))]
+                    }
+                },
+                Symbols::Mod => match (constant_of(&left_sum), constant_of(&right_sum)) {
+                    (Some(dividend), Some(divisor)) if !divisor.is_zero() => {
+                        to_sum(&Expr::Number(Number::modulo(dividend, divisor)), expand)
+                    }
+                    _ => {
+                        let modulo = Expr::Binary(
+                            Symbols::Mod,
+                            rebuild(left_sum).into(),
+                            rebuild(right_sum).into(),
+                        );
+                        vec![Term::atom(modulo, Number::Int(1))]
+                    }
+                },
+                _ => {
+                    let other =
+                        Expr::Binary(*symbol, rebuild(left_sum).into(), rebuild(right_sum).into());
+                    vec![Term::atom(other, Number::Int(1))]
+                }
+            }
+        }
+        Expr::Function(name, param) => {
+            let function = Expr::call(name, simplify(param, expand));
+            vec![Term::atom(function, Number::Int(1))]
+        }
+        Expr::Variable(_) | Expr::Other(_) => vec![Term::atom(expr.clone(), Number::Int(1))],
+    };
+    return result;
+}
+
+// the terms are sorted by degree descendingly except that
+// the leading term is positive, the negative terms are subtracted.
+fn rebuild(mut sum: Sum) -> Expr {
+    sum.sort_by(|term1, term2| {
+        term2
+            .degree()
+            .partial_cmp(&term1.degree())
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| term1.key().cmp(&term2.key()))
+    });
+    // prefer `1 - x` to `-x + 1`
+    if let Some(index) = sum.iter().position(|term| !is_negative(&term.coeff)) {
+        let term = sum.remove(index);
+        sum.insert(0, term);
+    }
+
+    let mut result: Option<Expr> = None;
+    for mut term in sum {
+        let is_subtracted = is_negative(&term.coeff);
+        if is_subtracted {
+            term.coeff = Number::Int(0) - term.coeff;
+        }
+        let term_expr = term.to_expr();
+        result = Some(match (result, is_subtracted) {
+            (None, false) => term_expr,
+            (None, true) => term_expr.neg(),
+            (Some(acc), false) => acc.add(term_expr),
+            (Some(acc), true) => acc.sub(term_expr),
+        });
+    }
+    return result.unwrap_or(Expr::int(0));
+}
+
+// fold the constants and combine the like terms,
+// the products and integer powers of sums are distributed when `expand` is true.
+pub fn simplify(expr: &Expr, expand: bool) -> Expr {
+    if let Expr::Binary(symbol, left, right) = expr {
+        if !matches!(
+            symbol,
+            Symbols::Plus
+                | Symbols::Minus
+                | Symbols::Multiply
+                | Symbols::Divide
+                | Symbols::Power
+                | Symbols::Mod
+        ) {
+            let left = simplify(left, expand);
+            let right = simplify(right, expand);
+            return Expr::Binary(*symbol, left.into(), right.into());
+        }
+    }
+    return rebuild(to_sum(expr, expand));
+}
@@ -0,0 +1,345 @@
+use crate::computer::computer::callback;
+use crate::public::error::{math_error, silently, type_error};
+use crate::public::run_time::scope::Scope;
+use crate::public::value::matrix::Matrix;
+use crate::public::value::number::Number;
+use crate::public::value::symbols::Symbols;
+use crate::public::value::value::{Value, ValueType};
+
+use super::expr::Expr;
+use super::polynomial::Polynomial;
+
+// the default interval and the step count to scan sign changes
+// for the non-polynomial equation.
+pub const SCAN_RANGE: (f64, f64) = (-100.0, 100.0);
+const SCAN_STEPS: usize = 2000;
+const BISECTION_ITERATION: usize = 100;
+// the root whose residual is greater than this is dropped,
+// it is usually a pole of the function, e.g. `tan(x)` at `pi/2`.
+const MAX_RESIDUAL: f64 = 1e-6;
+
+const NEWTON_ITERATION: usize = 100;
+const NEWTON_TOLERANCE: f64 = 1e-12;
+// the start points of Newton's method are `start * (1 + index * START_SKEW)`,
+// the skew avoids the singular Jacobian of the symmetric equations at start.
+const NEWTON_STARTS: [f64; 6] = [1.0, -1.0, 0.5, 3.0, -3.0, 10.0];
+const START_SKEW: f64 = 0.37;
+
+// the expression `lhs - rhs` for equation `lhs == rhs`,
+// the expression without `==` is regarded as `expr == 0`.
+pub fn equation_expr(value: &Value) -> Result<Expr, ()> {
+    let Value::LazyExpression(l_expr) = value else {
+        return Err(type_error(
+            Some("equation"),
+            vec![ValueType::LazyExpression],
+            value.get_type(),
+        )?);
+    };
+    let expr = Expr::from_node(&l_expr.borrow())?;
+    match expr {
+        Expr::Binary(Symbols::CompareEqual, left, right) => return Ok(left.sub(*right)),
+        _ => return Ok(expr),
+    }
+}
+
+fn eval(f: &Value, args: &[Number], vars: &[&str], scope: &mut Scope) -> Result<Number, ()> {
+    let result = callback(f, args, vars, scope)?;
+    let Value::Number(num) = result else {
+        return Err(type_error(
+            Some("equation result"),
+            vec![ValueType::Number],
+            result.get_type(),
+        )?);
+    };
+    return Ok(num);
+}
+fn eval_const(expr: &Expr, scope: &mut Scope) -> Result<Number, ()> {
+    return eval(&Value::from(expr.to_node()), &[], &[], scope);
+}
+// evaluates at the sample point without printing the errors,
+// the error, undefined or complex value is NaN.
+fn eval_sample(f: &Value, args: &[Number], vars: &[&str], scope: &mut Scope) -> f64 {
+    match silently(|| eval(f, args, vars, scope)) {
+        Ok(Number::NotANumber | Number::Complex(_)) | Err(_) => return f64::NAN,
+        Ok(result) => return result.float_value(),
+    }
+}
+
+// --- --- --- --- --- ---
+
+// solve equation `expr == 0` of single variable, returns all the solutions found,
+// the non-polynomial equation is solved numerically in `range`.
+pub fn solve_equation(
+    expr: &Expr,
+    var: &str,
+    range: (f64, f64),
+    scope: &mut Scope,
+) -> Result<Vec<Number>, ()> {
+    let poly = Polynomial::from_expr(expr, var, &mut |e| eval_const(e, scope))?;
+    if let Some(poly) = poly {
+        if poly.is_zero() {
+            let msg = format!("the equation holds for any value of `{}`", var);
+            return Err(math_error(&msg)?);
+        }
+        return Ok(poly.roots());
+    }
+
+    let f = Value::from(expr.to_node());
+    // the poles are NaN, so that they are skipped instead of being found as roots
+    let mut eval_x = |x: f64| eval_sample(&f, &[Number::Float(x)], &[var], scope);
+
+    // scan the sign changes and refine them with bisection
+    let (start, end) = range;
+    let step = (end - start) / (SCAN_STEPS as f64);
+    let mut roots = Vec::<f64>::new();
+    let mut a = start;
+    let mut fa = eval_x(a);
+    let mut is_defined = !fa.is_nan();
+    for i in 1..=SCAN_STEPS {
+        let b = start + step * (i as f64);
+        let fb = eval_x(b);
+        is_defined |= !fb.is_nan();
+        // the sample point of exact ZERO is a root, the NaN of pole is never ZERO
+        if fa == 0.0 {
+            roots.push(a);
+        } else if fa.is_finite() && fb.is_finite() && fa * fb < 0.0 {
+            let (mut low, mut high, mut f_low) = (a, b, fa);
+            for _ in 0..BISECTION_ITERATION {
+                let mid = (low + high) / 2.0;
+                let f_mid = eval_x(mid);
+                if f_mid == 0.0 {
+                    (low, high) = (mid, mid);
+                    break;
+                }
+                if f_low * f_mid < 0.0 {
+                    high = mid;
+                } else {
+                    (low, f_low) = (mid, f_mid);
+                }
+            }
+            let root = (low + high) / 2.0;
+            if eval_x(root).abs() <= MAX_RESIDUAL {
+                roots.push(root);
+            }
+        }
+        (a, fa) = (b, fb);
+    }
+    if fa == 0.0 {
+        roots.push(a);
+    }
+
+    if !is_defined {
+        // reports the error of the equation itself, e.g. undefined variable
+        eval(&f, &[Number::Float(start)], &[var], scope)?;
+    }
+    if roots.is_empty() {
+        return Err(math_error("no solution is found for the equation")?);
+    }
+    return Ok(roots.into_iter().map(Number::Float).collect());
+}
+
+// --- --- --- --- --- ---
+
+// `coeffs · vars + constant`
+struct LinearForm {
+    coeffs: Vec<Number>,
+    constant: Number,
+}
+
+impl LinearForm {
+    fn constant(size: usize, num: Number) -> Self {
+        Self {
+            coeffs: vec![Number::Int(0); size],
+            constant: num,
+        }
+    }
+    fn as_constant(&self) -> Option<Number> {
+        if self.coeffs.iter().all(Number::is_zero) {
+            return Some(self.constant.clone());
+        }
+        return None;
+    }
+    fn combine(self, other: Self, op: fn(Number, Number) -> Number) -> Self {
+        let coeffs = self
+            .coeffs
+            .into_iter()
+            .zip(other.coeffs)
+            .map(|(c1, c2)| op(c1, c2))
+            .collect();
+        return Self {
+            coeffs,
+            constant: op(self.constant, other.constant),
+        };
+    }
+    fn scale(self, factor: &Number) -> Self {
+        let coeffs = self
+            .coeffs
+            .into_iter()
+            .map(|c| c * factor.clone())
+            .collect();
+        return Self {
+            coeffs,
+            constant: self.constant * factor.clone(),
+        };
+    }
+
+    // returns None when the expression is not linear
+    fn from_expr(expr: &Expr, vars: &[&str], scope: &mut Scope) -> Result<Option<Self>, ()> {
+        if let Expr::Number(num) = expr {
+            return Ok(Some(Self::constant(vars.len(), num.clone())));
+        }
+        if !vars.iter().any(|var| expr.contains(var)) {
+            let num = eval_const(expr, scope)?;
+            return Ok(Some(Self::constant(vars.len(), num)));
+        }
+
+        let result = match expr {
+            Expr::Variable(name) => {
+                let mut form = Self::constant(vars.len(), Number::Int(0));
+                let index = vars.iter().position(|var| var == name).unwrap();
+                form.coeffs[index] = Number::Int(1);
+                Some(form)
+            }
+            Expr::Binary(symbol, left, right) => {
+                let Some(left) = Self::from_expr(left, vars, scope)? else {
+                    return Ok(None);
+                };
+                let Some(right) = Self::from_expr(right, vars, scope)? else {
+                    return Ok(None);
+                };
+                match (symbol, left.as_constant(), right.as_constant()) {
+                    (Symbols::Plus, _, _) => Some(left.combine(right, |n1, n2| n1 + n2)),
+                    (Symbols::Minus, _, _) => Some(left.combine(right, |n1, n2| n1 - n2)),
+                    (Symbols::Multiply, _, Some(factor)) => Some(left.scale(&factor)),
+                    (Symbols::Multiply, Some(factor), _) => Some(right.scale(&factor)),
+                    (Symbols::Divide, _, Some(divisor)) if !divisor.is_zero() => {
+                        let reciprocal = Number::Int(1).true_div(&divisor);
+                        Some(left.scale(&reciprocal))
+                    }
+                    (Symbols::Power, _, Some(Number::Int(1))) => Some(left),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        return Ok(result);
+    }
+}
+
+// solve the equation system `exprs == 0`,
+// returns the solution in the order of `vars`.
+pub fn solve_system(exprs: &[Expr], vars: &[&str], scope: &mut Scope) -> Result<Vec<Number>, ()> {
+    if exprs.len() != vars.len() {
+        return Err(math_error(
+            "the count of equations should be equal to the count of variables",
+        )?);
+    }
+    let size = vars.len();
+
+    // linear system is solved exactly
+    let mut forms = vec![];
+    for expr in exprs {
+        match LinearForm::from_expr(expr, vars, scope)? {
+            Some(form) => forms.push(form),
+            None => break,
+        }
+    }
+    if forms.len() == size {
+        let mut coeffs = vec![];
+        let mut constants = vec![];
+        for form in forms {
+            coeffs.extend(form.coeffs);
+            constants.push(Number::Int(0) - form.constant);
+        }
+        let coeff_mat = Matrix::new(size, size, coeffs);
+        let Some(solution) = coeff_mat.solve(&Matrix::new(size, 1, constants)) else {
+            return Err(math_error("the linear system has no unique solution")?);
+        };
+        return Ok(solution.transpose().row(0));
+    }
+
+    let functions: Vec<Value> = exprs
+        .iter()
+        .map(|expr| Value::from(expr.to_node()))
+        .collect();
+    let mut is_defined = false;
+    let mut eval_all = |point: &[f64]| -> Vec<f64> {
+        let args: Vec<Number> = point.iter().map(|x| Number::Float(*x)).collect();
+        let values: Vec<f64> = functions
+            .iter()
+            .map(|f| eval_sample(f, &args, vars, scope))
+            .collect();
+        is_defined |= values.iter().all(|v| !v.is_nan());
+        return values;
+    };
+
+    // another start point is tried when the iteration fails,
+    // e.g. the Jacobian matrix is singular or the point is undefined.
+    for start in NEWTON_STARTS {
+        let point = (0..size)
+            .map(|index| start * (1.0 + index as f64 * START_SKEW))
+            .collect();
+        if let Some(solution) = newton_iterate(point, &mut eval_all) {
+            return Ok(solution.into_iter().map(Number::Float).collect());
+        }
+    }
+    if !is_defined {
+        // reports the error of the equations themselves, e.g. undefined variable
+        let args = vec![Number::Float(NEWTON_STARTS[0]); size];
+        for f in &functions {
+            eval(f, &args, vars, scope)?;
+        }
+    }
+    return Err(math_error("no solution is found for the equation system")?);
+}
+
+// Newton's method with finite-difference Jacobian,
+// returns None when the iteration fails.
+fn newton_iterate(
+    mut point: Vec<f64>,
+    eval_all: &mut impl FnMut(&[f64]) -> Vec<f64>,
+) -> Option<Vec<f64>> {
+    let size = point.len();
+    for _ in 0..NEWTON_ITERATION {
+        let values = eval_all(&point);
+        if !values.iter().all(|v| v.is_finite()) {
+            return None;
+        }
+        if values.iter().all(|v| v.abs() < NEWTON_TOLERANCE) {
+            return Some(point);
+        }
+
+        let mut jacobian = vec![Number::Int(0); size * size];
+        for col in 0..size {
+            let h = 1e-7 * point[col].abs().max(1.0);
+            let mut shifted = point.clone();
+            shifted[col] += h;
+            let shifted_values = eval_all(&shifted);
+            for row in 0..size {
+                let slope = (shifted_values[row] - values[row]) / h;
+                jacobian[row * size + col] = Number::Float(slope);
+            }
+        }
+        let minus_values = values.iter().map(|v| Number::Float(-v)).collect();
+        let delta = Matrix::new(size, size, jacobian).solve(&Matrix::new(size, 1, minus_values))?;
+
+        let mut max_delta = 0.0_f64;
+        for (x, dx) in point.iter_mut().zip(delta.transpose().row(0)) {
+            let dx = dx.float_value();
+            *x += dx;
+            max_delta = max_delta.max(dx.abs() / x.abs().max(1.0));
+        }
+        if !point.iter().all(|x| x.is_finite()) {
+            return None;
+        }
+        if max_delta < NEWTON_TOLERANCE {
+            // the converged point should satisfy the equations
+            let residual = eval_all(&point);
+            return residual
+                .iter()
+                .all(|v| v.abs() <= MAX_RESIDUAL)
+                .then_some(point);
+        }
+    }
+    return None;
+}
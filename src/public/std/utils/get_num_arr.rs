@@ -0,0 +1,41 @@
+use crate::public::error::type_error;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::number::Number;
+use crate::public::value::value::{Value, ValueType};
+
+use super::get_self_prop::get_self_prop;
+use super::get_val::get_val;
+
+// used to get Numbers in Array typed function actual param,
+// the instance of Array class is also accepted.
+pub fn get_num_arr(val_name: &str, scope: &mut Scope) -> Result<Vec<Number>, ()> {
+    let value = get_val(val_name, scope)?;
+    return to_num_arr(value, val_name);
+}
+
+pub fn to_num_arr(value: Value, val_name: &str) -> Result<Vec<Number>, ()> {
+    let arr_value = match value {
+        Value::Object(_) => get_self_prop(&value, "v")?,
+        _ => value,
+    };
+    let Value::Array(arr) = arr_value else {
+        return Err(type_error(
+            Some(val_name),
+            vec![ValueType::Array],
+            arr_value.get_type(),
+        )?);
+    };
+
+    let mut result = vec![];
+    for element in arr.as_ref().borrow().iter() {
+        let Value::Number(num) = element else {
+            return Err(type_error(
+                Some(val_name),
+                vec![ValueType::Number],
+                element.get_type(),
+            )?);
+        };
+        result.push(num.clone());
+    }
+    return Ok(result);
+}
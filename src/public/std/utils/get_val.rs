@@ -13,3 +13,10 @@ pub fn get_val(val_name: &str, scope: &mut Scope) -> Result<Value, ()> {
         }
     }
 }
+
+// used to get value of optional function actual param,
+// returns None when the param is not passed.
+pub fn get_optional_val(val_name: &str, scope: &mut Scope) -> Option<Value> {
+    let option_value = scope.local.as_ref().unwrap().variables.get(val_name);
+    return option_value.cloned();
+}
@@ -1,3 +1,5 @@
+pub mod get_num_arr;
 pub mod get_self_prop;
 pub mod get_val;
+pub mod special_fn;
 pub mod str_to_num;
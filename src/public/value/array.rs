@@ -128,13 +128,13 @@ impl GetAddr for RawArray {
 
 impl ComplexStructure for RawArray {
     fn display(f: &mut fmt::Formatter<'_>, arr: &Rc<RefCell<Self>>, level: usize) -> fmt::Result {
-        const LINE_COUNT: i8 = 5;
-        let mut index = 0;
+        const LINE_COUNT: usize = 5;
+        let mut index: usize = 0;
 
         write!(f, "[")?;
         for element in arr.borrow().iter() {
             // print indent
-            if index % LINE_COUNT == 0 {
+            if index.is_multiple_of(LINE_COUNT) {
                 write!(f, "\r\n")?;
                 write!(f, "{}", "  ".repeat(level))?;
             }
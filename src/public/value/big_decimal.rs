@@ -0,0 +1,440 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::big_int::BigInt;
+
+// arbitrary-precision decimal floating point number,
+// the value is `mantissa * 10 ^ exponent`, the mantissa is
+// stored without trailing zeros and ZERO has the exponent 0.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(PartialEq, Clone)]
+pub struct BigDecimal {
+    mantissa: BigInt,
+    exponent: i64,
+}
+
+// the extra digits used in the intermediate results of functions
+const GUARD_DIGITS: usize = 10;
+
+fn pow10(exp: usize) -> BigInt {
+    return BigInt::from(10_i64).pow(exp as u32);
+}
+// count of the decimal digits of the magnitude, ZERO has no digit
+fn digit_count(num: &BigInt) -> usize {
+    if num.is_zero() {
+        return 0;
+    }
+    return num.abs().to_string().len();
+}
+// integer square root, rounded down
+fn int_sqrt(num: &BigInt) -> BigInt {
+    if num.is_zero() {
+        return BigInt::zero();
+    }
+    // the initial value is not less than the root
+    let mut root = BigInt::one().shl(num.bits().div_ceil(2) as u32);
+    loop {
+        let next = (&root + &num.div_rem(&root).0).shr(1);
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+// `scale / n` with `scale = 10 ^ digits`, the fixed-point arctangent of `1 / n`
+fn fixed_atan_inv(n: i64, scale: &BigInt) -> BigInt {
+    let n_square = BigInt::from(n * n);
+    let mut power = scale.div_rem(&BigInt::from(n)).0;
+    let mut sum = power.clone();
+    let mut k: i64 = 1;
+    loop {
+        power = power.div_rem(&n_square).0;
+        let term = power.div_rem(&BigInt::from(2 * k + 1)).0;
+        if term.is_zero() {
+            return sum;
+        }
+        sum = if k % 2 == 1 {
+            &sum - &term
+        } else {
+            &sum + &term
+        };
+        k += 1;
+    }
+}
+
+impl BigDecimal {
+    pub fn zero() -> Self {
+        Self {
+            mantissa: BigInt::zero(),
+            exponent: 0,
+        }
+    }
+    pub fn one() -> Self {
+        Self::from(BigInt::one())
+    }
+
+    fn new(mut mantissa: BigInt, mut exponent: i64) -> Self {
+        if mantissa.is_zero() {
+            return Self::zero();
+        }
+        let ten = BigInt::from(10_i64);
+        loop {
+            let (quotient, remainder) = mantissa.div_rem(&ten);
+            if !remainder.is_zero() {
+                break;
+            }
+            mantissa = quotient;
+            exponent += 1;
+        }
+        return Self { mantissa, exponent };
+    }
+
+    // parses the decimal literal, e.g. `1.5`, `-0.25` and `6.02e23`
+    pub fn parse(literal: &str) -> Option<Self> {
+        let (negative, literal) = match literal.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, literal.strip_prefix('+').unwrap_or(literal)),
+        };
+        let (digits, exp) = match literal.split_once(['e', 'E']) {
+            Some((digits, exp)) => (digits, exp.parse::<i64>().ok()?),
+            None => (literal, 0),
+        };
+        let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+
+        // the digits are accumulated in chunks of 18 digits
+        let mut mantissa = BigInt::zero();
+        let all_digits = format!("{}{}", int_part, frac_part);
+        for chunk in all_digits.as_bytes().chunks(18) {
+            let chunk = std::str::from_utf8(chunk).ok()?;
+            let value = chunk.parse::<i64>().ok()?;
+            mantissa = &(&mantissa * &pow10(chunk.len())) + &BigInt::from(value);
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+        return Some(Self::new(mantissa, exp - frac_part.len() as i64));
+    }
+    // the shortest decimal that is converted back to the same float
+    pub fn from_f64(num: f64) -> Option<Self> {
+        if !num.is_finite() {
+            return None;
+        }
+        return Self::parse(&num.to_string());
+    }
+    // `upper / lower` rounded to `precision` significant digits
+    pub fn from_ratio(upper: &BigInt, lower: &BigInt, precision: usize) -> Self {
+        if upper.is_zero() {
+            return Self::zero();
+        }
+        // the quotient has at least `precision + 1` digits
+        let shift =
+            (precision + 2) as i64 - (digit_count(upper) as i64 - digit_count(lower) as i64);
+        let (quotient, remainder) = if shift >= 0 {
+            (upper * &pow10(shift as usize)).div_rem(lower)
+        } else {
+            upper.div_rem(&(lower * &pow10(-shift as usize)))
+        };
+        // the nonzero remainder is kept as the last digit,
+        // so that the rounding is not affected by the truncation.
+        let mut sticky = BigInt::from(!remainder.is_zero() as i64);
+        if upper.is_negative() != lower.is_negative() {
+            sticky = -sticky;
+        }
+        let mantissa = &(&quotient * &BigInt::from(10_i64)) + &sticky;
+        return Self::new(mantissa, -shift - 1).round(precision);
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let text = format!("{}e{}", self.mantissa, self.exponent);
+        return text.parse::<f64>().unwrap_or(f64::NAN);
+    }
+    // the exact `(upper, lower)`
+    pub fn to_ratio(&self) -> (BigInt, BigInt) {
+        if self.exponent >= 0 {
+            let upper = &self.mantissa * &pow10(self.exponent as usize);
+            return (upper, BigInt::one());
+        }
+        return (self.mantissa.clone(), pow10(-self.exponent as usize));
+    }
+    // the integer part, truncated toward ZERO
+    pub fn trunc(&self) -> BigInt {
+        let (upper, lower) = self.to_ratio();
+        return upper.div_rem(&lower).0;
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.mantissa.is_negative()
+    }
+    pub fn neg(&self) -> Self {
+        Self {
+            mantissa: -self.mantissa.clone(),
+            exponent: self.exponent,
+        }
+    }
+    pub fn abs(&self) -> Self {
+        Self {
+            mantissa: self.mantissa.abs(),
+            exponent: self.exponent,
+        }
+    }
+
+    // the exponent of the most significant digit, e.g. 2 for 123.4
+    fn magnitude(&self) -> i64 {
+        return digit_count(&self.mantissa) as i64 - 1 + self.exponent;
+    }
+
+    // rounded to `precision` significant digits, the half is rounded away from ZERO
+    pub fn round(&self, precision: usize) -> Self {
+        let digits = digit_count(&self.mantissa);
+        if digits <= precision {
+            return self.clone();
+        }
+        return self.quantize(self.exponent + (digits - precision) as i64);
+    }
+    // rounded to the multiple of `10 ^ exponent`
+    pub fn quantize(&self, exponent: i64) -> Self {
+        if exponent <= self.exponent {
+            return self.clone();
+        }
+        let dropped = (exponent - self.exponent) as usize;
+        let divisor = pow10(dropped);
+        let (mut quotient, remainder) = self.mantissa.div_rem(&divisor);
+        let twice_remainder = &remainder.abs() * &BigInt::from(2_i64);
+        if twice_remainder >= divisor {
+            let carry = BigInt::from(if self.is_negative() { -1_i64 } else { 1 });
+            quotient = &quotient + &carry;
+        }
+        return Self::new(quotient, exponent);
+    }
+
+    pub fn add(&self, other: &Self, precision: usize) -> Self {
+        if self.is_zero() {
+            return other.round(precision);
+        }
+        if other.is_zero() {
+            return self.round(precision);
+        }
+        // the much smaller operand does not affect the rounded result
+        let limit = (precision + 2) as i64;
+        if self.magnitude() - other.magnitude() > limit {
+            return self.round(precision);
+        }
+        if other.magnitude() - self.magnitude() > limit {
+            return other.round(precision);
+        }
+
+        let exponent = self.exponent.min(other.exponent);
+        let align = |num: &Self| &num.mantissa * &pow10((num.exponent - exponent) as usize);
+        let mantissa = &align(self) + &align(other);
+        return Self::new(mantissa, exponent).round(precision);
+    }
+    pub fn sub(&self, other: &Self, precision: usize) -> Self {
+        return self.add(&other.neg(), precision);
+    }
+    pub fn mul(&self, other: &Self, precision: usize) -> Self {
+        let mantissa = &self.mantissa * &other.mantissa;
+        return Self::new(mantissa, self.exponent + other.exponent).round(precision);
+    }
+    // the divisor should not be ZERO
+    pub fn div(&self, other: &Self, precision: usize) -> Self {
+        let mut result = Self::from_ratio(&self.mantissa, &other.mantissa, precision);
+        if !result.is_zero() {
+            result.exponent += self.exponent - other.exponent;
+        }
+        return result;
+    }
+
+    pub fn powi(&self, exp: i64, precision: usize) -> Self {
+        let working = precision + GUARD_DIGITS + exp.unsigned_abs().to_string().len();
+        let mut base = self.clone();
+        let mut result = Self::one();
+        let mut rest = exp.unsigned_abs();
+        while rest > 0 {
+            if rest & 1 == 1 {
+                result = result.mul(&base, working);
+            }
+            rest >>= 1;
+            if rest > 0 {
+                base = base.mul(&base, working);
+            }
+        }
+        if exp < 0 {
+            return Self::one().div(&result, precision);
+        }
+        return result.round(precision);
+    }
+
+    // the square root of non-negative number
+    pub fn sqrt(&self, precision: usize) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        // the scaled mantissa has at least `2 * (precision + 2)` digits
+        // and the remaining exponent is even.
+        let digits = digit_count(&self.mantissa) as i64;
+        let mut scale = (2 * (precision as i64 + 2) - digits).max(0);
+        if (self.exponent - scale) % 2 != 0 {
+            scale += 1;
+        }
+        let scaled = &self.mantissa * &pow10(scale as usize);
+        let root = int_sqrt(&scaled);
+        return Self::new(root, (self.exponent - scale) / 2).round(precision);
+    }
+
+    pub fn exp(&self, precision: usize) -> Self {
+        if self.is_zero() {
+            return Self::one();
+        }
+        // exp(x) == exp(x / 2^k) ^ (2^k),
+        // the reduced argument is less than 2^-10 so that the series converges fast.
+        let float_value = self.to_f64().abs();
+        let k = (float_value.log2().ceil() as i64 + 10).max(0) as u32;
+        let working = precision + GUARD_DIGITS + (k as usize) / 3;
+
+        // x / 2^k == x * 5^k / 10^k
+        let five_power = BigInt::from(5_i64).pow(k);
+        let reduced = Self::new(&self.mantissa * &five_power, self.exponent - k as i64);
+
+        let mut sum = Self::one();
+        let mut term = Self::one();
+        let mut n: i64 = 1;
+        loop {
+            term = term
+                .mul(&reduced, working)
+                .div(&Self::from(BigInt::from(n)), working);
+            if term.is_zero() || sum.magnitude() - term.magnitude() > working as i64 {
+                break;
+            }
+            sum = sum.add(&term, working);
+            n += 1;
+        }
+        for _ in 0..k {
+            sum = sum.mul(&sum, working);
+        }
+        return sum.round(precision);
+    }
+
+    // the natural logarithm of positive number
+    pub fn ln(&self, precision: usize) -> Self {
+        let working = precision + GUARD_DIGITS;
+        // ln(x) == ln(x / 10^m) + m * ln(10), where `x / 10^m` is in [1, 10)
+        let magnitude = self.magnitude();
+        let reduced = Self {
+            mantissa: self.mantissa.clone(),
+            exponent: self.exponent - magnitude,
+        };
+        let mut result = Self::ln_newton(&reduced, working);
+        if magnitude != 0 {
+            let ln10 = Self::ln_newton(&Self::from(BigInt::from(10_i64)), working);
+            let offset = ln10.mul(&Self::from(BigInt::from(magnitude)), working);
+            result = result.add(&offset, working);
+        }
+        return result.round(precision);
+    }
+    // solves `exp(y) == x` with the Newton-like iteration of cubic convergence:
+    // y = y + 2 * (x - exp(y)) / (x + exp(y))
+    fn ln_newton(num: &Self, precision: usize) -> Self {
+        let two = Self::from(BigInt::from(2_i64));
+        let Some(mut result) = Self::from_f64(num.to_f64().ln()) else {
+            return Self::zero();
+        };
+        loop {
+            let exp_result = result.exp(precision);
+            let numerator = num.sub(&exp_result, precision).mul(&two, precision);
+            let delta = numerator.div(&num.add(&exp_result, precision), precision);
+            result = result.add(&delta, precision);
+            if delta.is_zero() || result.magnitude() - delta.magnitude() >= precision as i64 {
+                return result;
+            }
+        }
+    }
+
+    // computed with the Machin's formula:
+    // PI == 16 * atan(1 / 5) - 4 * atan(1 / 239)
+    pub fn pi(precision: usize) -> Self {
+        let digits = precision + GUARD_DIGITS;
+        let scale = pow10(digits);
+        let atan5 = fixed_atan_inv(5, &scale);
+        let atan239 = fixed_atan_inv(239, &scale);
+        let result = &(&atan5 * &BigInt::from(16_i64)) - &(&atan239 * &BigInt::from(4_i64));
+        return Self::new(result, -(digits as i64)).round(precision);
+    }
+
+    // the text with fixed count of decimal places
+    pub fn to_fixed_string(&self, decimals: usize) -> String {
+        // the rounded exponent is not less than `-decimals`
+        let rounded = self.quantize(-(decimals as i64));
+        let shift = (rounded.exponent + decimals as i64) as usize;
+        let upper = &rounded.mantissa * &pow10(shift);
+        let digits = upper.abs().to_string();
+        let sign = if upper.is_negative() { "-" } else { "" };
+        if decimals == 0 {
+            return format!("{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = decimals + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - decimals);
+        return format!("{}{}.{}", sign, int_part, frac_part);
+    }
+}
+
+impl From<BigInt> for BigDecimal {
+    fn from(value: BigInt) -> Self {
+        Self::new(value, 0)
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.is_negative(), other.is_negative()) {
+            (false, true) => return Some(Ordering::Greater),
+            (true, false) => return Some(Ordering::Less),
+            _ => {}
+        }
+        if self.is_zero() || other.is_zero() {
+            return self.mantissa.partial_cmp(&other.mantissa);
+        }
+        // the number with larger magnitude has larger absolute value
+        let ordering = match self.magnitude().cmp(&other.magnitude()) {
+            Ordering::Equal => {
+                let exponent = self.exponent.min(other.exponent);
+                let align = |num: &Self| &num.mantissa * &pow10((num.exponent - exponent) as usize);
+                return align(self).partial_cmp(&align(other));
+            }
+            ordering if self.is_negative() => ordering.reverse(),
+            ordering => ordering,
+        };
+        return Some(ordering);
+    }
+}
+
+impl fmt::Display for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let digits = self.mantissa.abs().to_string();
+        let magnitude = self.magnitude();
+
+        // the scientific notation is used for very large or small number
+        if !(-7..21).contains(&magnitude) {
+            let (first, rest) = digits.split_at(1);
+            let point = if rest.is_empty() { "" } else { "." };
+            return write!(f, "{}{}{}{}e{}", sign, first, point, rest, magnitude);
+        }
+        if self.exponent >= 0 {
+            let zeros = "0".repeat(self.exponent as usize);
+            return write!(f, "{}{}{}", sign, digits, zeros);
+        }
+
+        let decimals = -self.exponent as usize;
+        let digits = format!("{:0>width$}", digits, width = decimals + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - decimals);
+        write!(f, "{}{}.{}", sign, int_part, frac_part)
+    }
+}
@@ -0,0 +1,555 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// arbitrary-precision integer,
+// the magnitude is stored as little-endian base-2^32 limbs
+// without leading zero limbs, ZERO has no limb and is never negative.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(PartialEq, Eq, Clone)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+type Limbs = Vec<u32>;
+const LIMB_BITS: u32 = 32;
+
+impl BigInt {
+    pub fn zero() -> Self {
+        Self {
+            negative: false,
+            limbs: Limbs::new(),
+        }
+    }
+    pub fn one() -> Self {
+        Self::from(1_i64)
+    }
+
+    fn from_parts(negative: bool, mut limbs: Limbs) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        let negative = negative && !limbs.is_empty();
+        return Self { negative, limbs };
+    }
+
+    pub fn from_f64(f: f64) -> Option<Self> {
+        // the fraction part of `f` is truncated
+        if !f.is_finite() {
+            return None;
+        }
+        let f = f.trunc();
+        if f.abs() < (1_u64 << 53) as f64 {
+            return Some(Self::from(f as i64));
+        }
+
+        let bits = f.abs().to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as u32 - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let result = Self::from(mantissa as i64).shl(exponent);
+        return Some(if f < 0.0 { -result } else { result });
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        !self.negative && self.limbs == [1]
+    }
+    #[inline]
+    pub fn is_even(&self) -> bool {
+        self.limbs.first().is_none_or(|limb| limb % 2 == 0)
+    }
+    pub fn abs(&self) -> Self {
+        Self {
+            negative: false,
+            limbs: self.limbs.clone(),
+        }
+    }
+
+    // count of significant bits of the magnitude
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => {
+                (self.limbs.len() as u64) * (LIMB_BITS as u64) - top.leading_zeros() as u64
+            }
+            None => 0,
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        let magnitude = self.to_u128()?;
+        if self.negative {
+            if magnitude <= (i64::MAX as u128) + 1 {
+                return Some((magnitude as i128).wrapping_neg() as i64);
+            }
+        } else if magnitude <= i64::MAX as u128 {
+            return Some(magnitude as i64);
+        }
+        return None;
+    }
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.to_u128()?;
+        if self.negative {
+            if magnitude <= (i128::MAX as u128) + 1 {
+                return Some((magnitude as i128).wrapping_neg());
+            }
+        } else if magnitude <= i128::MAX as u128 {
+            return Some(magnitude as i128);
+        }
+        return None;
+    }
+    fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        let mut result: u128 = 0;
+        for limb in self.limbs.iter().rev() {
+            result = (result << LIMB_BITS) | (*limb as u128);
+        }
+        return Some(result);
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        if bits <= 64 {
+            let magnitude = self.to_u128().unwrap() as u64 as f64;
+            return if self.negative { -magnitude } else { magnitude };
+        }
+
+        // keep the highest 64 bits and mark the dropped bits as sticky bit,
+        // so that the conversion is rounded correctly.
+        let shift = bits - 64;
+        let top = self.abs().shr(shift).to_u128().unwrap() as u64;
+        let dropped_nonzero = self.abs().shr(shift).shl(shift as u32) != self.abs();
+        let top = top | dropped_nonzero as u64;
+        let magnitude = (top as f64) * 2_f64.powi(shift.min(i32::MAX as u64) as i32);
+        return if self.negative { -magnitude } else { magnitude };
+    }
+    // convert `upper / lower` to float without the overflow of both side
+    pub fn ratio_to_f64(upper: &Self, lower: &Self) -> f64 {
+        let max_bits = upper.bits().max(lower.bits());
+        if max_bits <= 1000 {
+            return upper.to_f64() / lower.to_f64();
+        }
+        let shift = max_bits - 1000;
+        return upper.shr(shift).to_f64() / lower.shr(shift).to_f64();
+    }
+
+    // --- --- --- --- --- ---
+
+    pub fn shl(&self, bits: u32) -> Self {
+        let limb_shift = (bits / LIMB_BITS) as usize;
+        let bit_shift = bits % LIMB_BITS;
+
+        let mut limbs = vec![0; limb_shift];
+        limbs.extend(Self::shl_bits(&self.limbs, bit_shift));
+        return Self::from_parts(self.negative, limbs);
+    }
+    // shift right the magnitude, the sign is kept
+    pub fn shr(&self, bits: u64) -> Self {
+        let limb_shift = (bits / LIMB_BITS as u64) as usize;
+        if limb_shift >= self.limbs.len() {
+            return Self::zero();
+        }
+        let bit_shift = (bits % LIMB_BITS as u64) as u32;
+        let rest = &self.limbs[limb_shift..];
+
+        let mut limbs = Limbs::with_capacity(rest.len());
+        for i in 0..rest.len() {
+            let high = if i + 1 < rest.len() { rest[i + 1] } else { 0 };
+            let combined = ((high as u64) << LIMB_BITS) | rest[i] as u64;
+            limbs.push((combined >> bit_shift) as u32);
+        }
+        return Self::from_parts(self.negative, limbs);
+    }
+
+    // the bitwise operation in two's complement, `op` is applied to each limb,
+    // e.g. `bitwise(&a, &b, |l1, l2| l1 & l2)` is the bitwise AND.
+    pub fn bitwise(&self, other: &Self, op: fn(u32, u32) -> u32) -> Self {
+        // one more limb to keep the sign bit
+        let len = self.limbs.len().max(other.limbs.len()) + 1;
+        let limbs1 = self.twos_complement(len);
+        let limbs2 = other.twos_complement(len);
+        let mut limbs: Limbs = limbs1
+            .iter()
+            .zip(&limbs2)
+            .map(|(l1, l2)| op(*l1, *l2))
+            .collect();
+
+        let negative = limbs[len - 1] >> (LIMB_BITS - 1) == 1;
+        if negative {
+            // the magnitude of negative number is `!x + 1`
+            let mut carry = true;
+            for limb in limbs.iter_mut() {
+                let (sum, overflow) = (!*limb).overflowing_add(carry as u32);
+                *limb = sum;
+                carry = overflow;
+            }
+        }
+        return Self::from_parts(negative, limbs);
+    }
+    // the two's complement limbs with the sign extended to `len` limbs
+    fn twos_complement(&self, len: usize) -> Limbs {
+        let mut limbs = self.limbs.clone();
+        limbs.resize(len, 0);
+        if self.negative {
+            // `-x == !(x - 1)`
+            let mut borrow = true;
+            for limb in limbs.iter_mut() {
+                let (diff, overflow) = limb.overflowing_sub(borrow as u32);
+                *limb = !diff;
+                borrow = overflow;
+            }
+        }
+        return limbs;
+    }
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        return result;
+    }
+
+    // truncated division, returns (quotient, remainder),
+    // the remainder has the same sign as the dividend.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) = Self::div_rem_limbs(&self.limbs, &divisor.limbs);
+        return (
+            Self::from_parts(self.negative != divisor.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        );
+    }
+
+    // floored division, returns (quotient, remainder),
+    // the remainder has the same sign as the divisor.
+    pub fn div_floor(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.div_rem(divisor);
+        if !remainder.is_zero() && remainder.is_negative() != divisor.is_negative() {
+            return (&quotient - &Self::one(), &remainder + divisor);
+        }
+        return (quotient, remainder);
+    }
+
+    // the magnitude in radix 2, 8 or 16, without sign and prefix
+    pub fn to_pow2_radix_string(&self, radix: u32) -> String {
+        if self.is_zero() {
+            return String::from("0");
+        }
+
+        let digit_bits = radix.trailing_zeros() as u64;
+        let digit_count = self.bits().div_ceil(digit_bits);
+        let bit_at = |index: u64| -> u32 {
+            let limb_index = (index / LIMB_BITS as u64) as usize;
+            match self.limbs.get(limb_index) {
+                Some(limb) => (limb >> (index % LIMB_BITS as u64)) & 1,
+                None => 0,
+            }
+        };
+
+        let mut result = String::new();
+        for i in (0..digit_count).rev() {
+            let mut digit = 0;
+            for bit in (0..digit_bits).rev() {
+                digit = (digit << 1) | bit_at(i * digit_bits + bit);
+            }
+            result.push(char::from_digit(digit, radix).unwrap().to_ascii_uppercase());
+        }
+        return result;
+    }
+
+    // greatest common divisor, the result is never negative
+    pub fn gcd(n1: &Self, n2: &Self) -> Self {
+        let mut larger = n1.abs();
+        let mut smaller = n2.abs();
+        while !smaller.is_zero() {
+            let (_, remainder) = larger.div_rem(&smaller);
+            larger = smaller;
+            smaller = remainder;
+        }
+        return larger;
+    }
+
+    // --- --- --- --- --- ---
+
+    fn cmp_limbs(limbs1: &[u32], limbs2: &[u32]) -> Ordering {
+        if limbs1.len() != limbs2.len() {
+            return limbs1.len().cmp(&limbs2.len());
+        }
+        for (l1, l2) in limbs1.iter().rev().zip(limbs2.iter().rev()) {
+            if l1 != l2 {
+                return l1.cmp(l2);
+            }
+        }
+        return Ordering::Equal;
+    }
+
+    fn add_limbs(limbs1: &[u32], limbs2: &[u32]) -> Limbs {
+        let (longer, shorter) = if limbs1.len() >= limbs2.len() {
+            (limbs1, limbs2)
+        } else {
+            (limbs2, limbs1)
+        };
+
+        let mut result = Limbs::with_capacity(longer.len() + 1);
+        let mut carry = 0_u64;
+        for i in 0..longer.len() {
+            let other = if i < shorter.len() { shorter[i] } else { 0 };
+            let sum = longer[i] as u64 + other as u64 + carry;
+            result.push(sum as u32);
+            carry = sum >> LIMB_BITS;
+        }
+        if carry > 0 {
+            result.push(carry as u32);
+        }
+        return result;
+    }
+    // `larger` should not be less than `smaller`
+    fn sub_limbs(larger: &[u32], smaller: &[u32]) -> Limbs {
+        let mut result = Limbs::with_capacity(larger.len());
+        let mut borrow = 0_i64;
+        for i in 0..larger.len() {
+            let other = if i < smaller.len() { smaller[i] } else { 0 };
+            let mut diff = larger[i] as i64 - other as i64 - borrow;
+            if diff < 0 {
+                diff += 1 << LIMB_BITS;
+                borrow = 1;
+            } else {
+                borrow = 0;
+            }
+            result.push(diff as u32);
+        }
+        return result;
+    }
+    fn mul_limbs(limbs1: &[u32], limbs2: &[u32]) -> Limbs {
+        if limbs1.is_empty() || limbs2.is_empty() {
+            return Limbs::new();
+        }
+
+        let mut result = vec![0_u32; limbs1.len() + limbs2.len()];
+        for (i, l1) in limbs1.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (j, l2) in limbs2.iter().enumerate() {
+                let current = result[i + j] as u64 + (*l1 as u64) * (*l2 as u64) + carry;
+                result[i + j] = current as u32;
+                carry = current >> LIMB_BITS;
+            }
+            result[i + limbs2.len()] = carry as u32;
+        }
+        return result;
+    }
+
+    fn shl_bits(limbs: &[u32], bits: u32) -> Limbs {
+        // `bits` should be less than `LIMB_BITS`,
+        // the result is always one limb longer than input.
+        let mut result = Limbs::with_capacity(limbs.len() + 1);
+        let mut carry = 0_u32;
+        for limb in limbs {
+            let shifted = (*limb as u64) << bits;
+            result.push(shifted as u32 | carry);
+            carry = (shifted >> LIMB_BITS) as u32;
+        }
+        result.push(carry);
+        return result;
+    }
+
+    fn div_rem_small(limbs: &[u32], divisor: u32) -> (Limbs, u32) {
+        let mut quotient = vec![0_u32; limbs.len()];
+        let mut remainder = 0_u64;
+        for i in (0..limbs.len()).rev() {
+            let current = (remainder << LIMB_BITS) | limbs[i] as u64;
+            quotient[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        return (quotient, remainder as u32);
+    }
+
+    // Knuth's Algorithm D
+    fn div_rem_limbs(dividend: &[u32], divisor: &[u32]) -> (Limbs, Limbs) {
+        if divisor.is_empty() {
+            panic!("BigInt division by zero");
+        }
+        if Self::cmp_limbs(dividend, divisor) == Ordering::Less {
+            return (Limbs::new(), dividend.to_vec());
+        }
+        if divisor.len() == 1 {
+            let (quotient, remainder) = Self::div_rem_small(dividend, divisor[0]);
+            return (quotient, vec![remainder]);
+        }
+
+        const BASE: u64 = 1 << LIMB_BITS;
+
+        // normalize, make the highest bit of divisor to be 1
+        let shift = divisor.last().unwrap().leading_zeros();
+        let mut vn = Self::shl_bits(divisor, shift);
+        vn.pop();
+        let mut un = Self::shl_bits(dividend, shift);
+
+        let n = vn.len();
+        let m = un.len() - n - 1;
+        let mut quotient = vec![0_u32; m + 1];
+
+        for j in (0..=m).rev() {
+            let numerator = ((un[j + n] as u64) << LIMB_BITS) | un[j + n - 1] as u64;
+            let mut qhat = numerator / vn[n - 1] as u64;
+            let mut rhat = numerator % vn[n - 1] as u64;
+            while qhat >= BASE
+                || qhat * vn[n - 2] as u64 > ((rhat << LIMB_BITS) | un[j + n - 2] as u64)
+            {
+                qhat -= 1;
+                rhat += vn[n - 1] as u64;
+                if rhat >= BASE {
+                    break;
+                }
+            }
+
+            // multiply and subtract
+            let mut borrow = 0_i64;
+            for i in 0..n {
+                let product = qhat * vn[i] as u64;
+                let diff = un[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+                un[i + j] = diff as u32;
+                borrow = (product >> LIMB_BITS) as i64 - (diff >> LIMB_BITS);
+            }
+            let diff = un[j + n] as i64 - borrow;
+            un[j + n] = diff as u32;
+
+            quotient[j] = qhat as u32;
+            if diff < 0 {
+                // subtracted too much, add back
+                quotient[j] -= 1;
+                let mut carry = 0_u64;
+                for i in 0..n {
+                    let sum = un[i + j] as u64 + vn[i] as u64 + carry;
+                    un[i + j] = sum as u32;
+                    carry = sum >> LIMB_BITS;
+                }
+                un[j + n] = un[j + n].wrapping_add(carry as u32);
+            }
+        }
+
+        // unnormalize the remainder
+        let remainder = Self::from_parts(false, un[..n].to_vec()).shr(shift as u64);
+        return (quotient, remainder.limbs);
+    }
+
+    fn add_signed(&self, other: &Self, other_negative: bool) -> Self {
+        if self.negative == other_negative {
+            let limbs = Self::add_limbs(&self.limbs, &other.limbs);
+            return Self::from_parts(self.negative, limbs);
+        }
+        match Self::cmp_limbs(&self.limbs, &other.limbs) {
+            Ordering::Less => {
+                let limbs = Self::sub_limbs(&other.limbs, &self.limbs);
+                Self::from_parts(other_negative, limbs)
+            }
+            _ => {
+                let limbs = Self::sub_limbs(&self.limbs, &other.limbs);
+                Self::from_parts(self.negative, limbs)
+            }
+        }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        Self::from(value as i128)
+    }
+}
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut magnitude = value.unsigned_abs();
+        let mut limbs = Limbs::new();
+        while magnitude > 0 {
+            limbs.push(magnitude as u32);
+            magnitude >>= LIMB_BITS;
+        }
+        return Self::from_parts(value < 0, limbs);
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // split the magnitude into decimal chunks with 9 digits
+        const CHUNK_BASE: u32 = 1_000_000_000;
+        let mut chunks = vec![];
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let (quotient, remainder) = Self::div_rem_small(&rest, CHUNK_BASE);
+            chunks.push(remainder);
+            rest = Self::from_parts(false, quotient).limbs;
+        }
+
+        let mut result = String::new();
+        if self.negative {
+            result.push('-');
+        }
+        result.push_str(&chunks.pop().unwrap().to_string());
+        for chunk in chunks.iter().rev() {
+            result.push_str(&format!("{:09}", chunk));
+        }
+        write!(f, "{}", result)
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => Self::cmp_limbs(&self.limbs, &other.limbs),
+            (true, true) => Self::cmp_limbs(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_parts(!self.negative, self.limbs)
+    }
+}
+impl Add for &BigInt {
+    type Output = BigInt;
+    fn add(self, other: Self) -> BigInt {
+        self.add_signed(other, other.negative)
+    }
+}
+impl Sub for &BigInt {
+    type Output = BigInt;
+    fn sub(self, other: Self) -> BigInt {
+        self.add_signed(other, !other.negative)
+    }
+}
+impl Mul for &BigInt {
+    type Output = BigInt;
+    fn mul(self, other: Self) -> BigInt {
+        let limbs = BigInt::mul_limbs(&self.limbs, &other.limbs);
+        BigInt::from_parts(self.negative != other.negative, limbs)
+    }
+}
@@ -0,0 +1,144 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(PartialEq, Clone, Copy)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const I: Self = Self { re: 0.0, im: 1.0 };
+
+    #[inline]
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    #[inline]
+    pub fn abs(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+    #[inline]
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+    #[inline]
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn sqrt(&self) -> Self {
+        // principal square root
+        let modulus = self.abs();
+        let re = ((modulus + self.re) / 2.0).sqrt();
+        let im = ((modulus - self.re) / 2.0).sqrt();
+        Self::new(re, if self.im < 0.0 { -im } else { im })
+    }
+    pub fn exp(&self) -> Self {
+        let modulus = self.re.exp();
+        Self::new(modulus * self.im.cos(), modulus * self.im.sin())
+    }
+    // principal natural logarithm
+    pub fn ln(&self) -> Self {
+        Self::new(self.abs().ln(), self.arg())
+    }
+
+    pub fn sin(&self) -> Self {
+        Self::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+    pub fn cos(&self) -> Self {
+        Self::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+    pub fn tan(&self) -> Self {
+        self.sin() / self.cos()
+    }
+    pub fn sinh(&self) -> Self {
+        Self::new(
+            self.re.sinh() * self.im.cos(),
+            self.re.cosh() * self.im.sin(),
+        )
+    }
+    pub fn cosh(&self) -> Self {
+        Self::new(
+            self.re.cosh() * self.im.cos(),
+            self.re.sinh() * self.im.sin(),
+        )
+    }
+    pub fn tanh(&self) -> Self {
+        self.sinh() / self.cosh()
+    }
+
+    pub fn powi(&self, mut exp: i64) -> Self {
+        // exponentiation by squaring keeps
+        // integer powers more accurate than `exp(n * ln(z))`.
+        let mut base = if exp < 0 {
+            exp = -exp;
+            Self::new(1.0, 0.0) / *self
+        } else {
+            *self
+        };
+        let mut result = Self::new(1.0, 0.0);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        return result;
+    }
+    pub fn pow(&self, exponent: Self) -> Self {
+        if self.re == 0.0 && self.im == 0.0 {
+            if exponent.re == 0.0 && exponent.im == 0.0 {
+                return Self::new(1.0, 0.0);
+            }
+            return Self::new(0.0, 0.0);
+        }
+        (exponent * self.ln()).exp()
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+impl Sub for Complex {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+impl Div for Complex {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        let divisor = other.re * other.re + other.im * other.im;
+        Self::new(
+            (self.re * other.re + self.im * other.im) / divisor,
+            (self.im * other.re - self.re * other.im) / divisor,
+        )
+    }
+}
+impl Neg for Complex {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
@@ -13,6 +13,8 @@ use crate::public::Param;
 use super::value::{Value, ValueType};
 use super::{into_rc_refcell, GetAddr};
 
+// the param whose identifier ends with '?' is optional,
+// optional params should be placed after the required params.
 #[derive(PartialEq, Clone)]
 pub struct BuildInFnParam(pub ValueType, pub &'static str);
 
@@ -21,7 +23,10 @@ impl Param for BuildInFnParam {
         self.0
     }
     fn identi(&self) -> &str {
-        self.1
+        self.1.trim_end_matches('?')
+    }
+    fn is_optional(&self) -> bool {
+        self.1.ends_with('?')
     }
 }
 
@@ -67,17 +72,19 @@ impl Function {
         local_scope: &mut LocalScope,
         expr_resolver: fn(&ExpressionNode, &mut Scope) -> Result<Value, ()>,
     ) -> Result<(), ()> {
-        if actual_params.len() < formal_params.len() {
+        let required_count = formal_params.iter().filter(|p| !p.is_optional()).count();
+        if actual_params.len() < required_count {
             // if param missing
             return Err(range_error(
                 "function invocation",
-                formal_params.len(),
+                required_count,
                 actual_params.len(),
             )?);
         }
 
         let mut index = 0;
-        while index < formal_params.len() {
+        // the missing optional params are not set
+        while index < formal_params.len() && index < actual_params.len() {
             let formal_param = &formal_params[index];
 
             // compute actual_param_value
@@ -0,0 +1,333 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::fmt;
+
+use crate::public::error::math_error;
+
+use super::number::Number;
+
+// closed interval of real numbers, the bounds are rounded outward
+// in computing so that the interval always contains the exact result.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy)]
+pub struct Interval {
+    lower: f64,
+    upper: f64,
+    // displayed as `mid ± err` when created with `±`,
+    // otherwise displayed as `[lower, upper]`.
+    is_symmetric: bool,
+}
+
+// the rounded result and the lower and upper bounds of the exact result,
+// the sign of `error` is the sign of `exact - rounded`.
+fn outward(rounded: f64, error: f64) -> (f64, f64) {
+    if error > 0.0 {
+        return (rounded, rounded.next_up());
+    }
+    if error < 0.0 {
+        return (rounded.next_down(), rounded);
+    }
+    return (rounded, rounded);
+}
+// the rounding errors of sum and product are computed exactly
+// with the TwoSum algorithm and the fused multiply-add.
+fn sum_bounds(num1: f64, num2: f64) -> (f64, f64) {
+    let sum = num1 + num2;
+    let virtual2 = sum - num1;
+    let error = (num1 - (sum - virtual2)) + (num2 - virtual2);
+    return outward(sum, error);
+}
+fn product_bounds(num1: f64, num2: f64) -> (f64, f64) {
+    let product = num1 * num2;
+    return outward(product, num1.mul_add(num2, -product));
+}
+fn quotient_bounds(dividend: f64, divisor: f64) -> (f64, f64) {
+    let quotient = dividend / divisor;
+    // dividend == quotient * divisor + remainder
+    let remainder = (-quotient).mul_add(divisor, dividend);
+    return outward(quotient, remainder / divisor);
+}
+
+// the bounds of `f(num)`, the result is widened by one ulp since
+// the function is not correctly rounded, except the integer results
+// at ZERO or ONE, e.g. `sqrt(0)`, `exp(0)` and `ln(1)`.
+fn image_bounds(f: fn(f64) -> f64, num: f64) -> (f64, f64) {
+    let result = f(num);
+    if result.fract() == 0.0 && (num == 0.0 || num == 1.0) {
+        return (result, result);
+    }
+    return (result.next_down(), result.next_up());
+}
+
+// the bounds of Number, the Number which may be
+// inexact in float is widened by one ulp.
+fn number_bounds(num: &Number) -> (f64, f64) {
+    let value = num.float_value();
+    match num {
+        Number::Int(i) if value as i64 == *i => (value, value),
+        _ if value.is_infinite() => (value, value),
+        _ => (value.next_down(), value.next_up()),
+    }
+}
+
+impl Interval {
+    fn new(lower: f64, upper: f64) -> Result<Self, ()> {
+        if lower.is_nan() || upper.is_nan() || lower > upper {
+            return Err(math_error(
+                "the lower bound of interval should not be greater than the upper bound",
+            )?);
+        }
+        return Ok(Self {
+            lower,
+            upper,
+            is_symmetric: false,
+        });
+    }
+    pub fn from_bounds(lower: &Number, upper: &Number) -> Result<Self, ()> {
+        return Self::new(number_bounds(lower).0, number_bounds(upper).1);
+    }
+    // the interval `mid ± err`
+    pub fn from_error(mid: &Number, err: &Number) -> Result<Self, ()> {
+        if err.float_value() < 0.0 {
+            return Err(math_error("the error of interval should not be negative")?);
+        }
+        let mid = Self::from_number(mid);
+        let err = number_bounds(err).1;
+        let mut result = Self::new(sum_bounds(mid.lower, -err).0, sum_bounds(mid.upper, err).1)?;
+        result.is_symmetric = true;
+        return Ok(result);
+    }
+    fn point(num: f64) -> Self {
+        Self {
+            lower: num,
+            upper: num,
+            is_symmetric: false,
+        }
+    }
+    pub fn from_number(num: &Number) -> Self {
+        let (lower, upper) = number_bounds(num);
+        return Self {
+            lower,
+            upper,
+            is_symmetric: false,
+        };
+    }
+
+    #[inline]
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+    #[inline]
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+    pub fn mid(&self) -> f64 {
+        return self.lower / 2.0 + self.upper / 2.0;
+    }
+    // the radius which makes `mid ± err` contain the interval
+    pub fn err(&self) -> f64 {
+        let mid = self.mid();
+        let lower_err = sum_bounds(mid, -self.lower).1;
+        let upper_err = sum_bounds(self.upper, -mid).1;
+        return lower_err.max(upper_err);
+    }
+    pub fn contains(&self, num: f64) -> bool {
+        return self.lower <= num && num <= self.upper;
+    }
+
+    // the result keeps the display style of `self`
+    fn with_bounds(&self, lower: f64, upper: f64) -> Self {
+        Self {
+            lower,
+            upper,
+            is_symmetric: self.is_symmetric,
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let lower = sum_bounds(self.lower, other.lower).0;
+        let upper = sum_bounds(self.upper, other.upper).1;
+        return self.with_bounds(lower, upper);
+    }
+    pub fn sub(&self, other: &Self) -> Self {
+        return self.add(&other.neg());
+    }
+    pub fn neg(&self) -> Self {
+        return self.with_bounds(-self.upper, -self.lower);
+    }
+    pub fn mul(&self, other: &Self) -> Self {
+        let candidates = [
+            product_bounds(self.lower, other.lower),
+            product_bounds(self.lower, other.upper),
+            product_bounds(self.upper, other.lower),
+            product_bounds(self.upper, other.upper),
+        ];
+        return self.hull(&candidates);
+    }
+    pub fn div(&self, other: &Self) -> Result<Self, ()> {
+        if other.contains(0.0) {
+            return Err(math_error("the divisor interval should not contain ZERO")?);
+        }
+        let candidates = [
+            quotient_bounds(self.lower, other.lower),
+            quotient_bounds(self.lower, other.upper),
+            quotient_bounds(self.upper, other.lower),
+            quotient_bounds(self.upper, other.upper),
+        ];
+        return Ok(self.hull(&candidates));
+    }
+    // the smallest interval containing all the candidate bounds
+    fn hull(&self, candidates: &[(f64, f64)]) -> Self {
+        let lower = candidates
+            .iter()
+            .map(|bounds| bounds.0)
+            .fold(f64::INFINITY, f64::min);
+        let upper = candidates
+            .iter()
+            .map(|bounds| bounds.1)
+            .fold(f64::NEG_INFINITY, f64::max);
+        return self.with_bounds(lower, upper);
+    }
+
+    pub fn powi(&self, exp: i64) -> Result<Self, ()> {
+        if exp == 0 {
+            // `x ^ 0 == 1` even if the interval contains ZERO
+            return Ok(Self::point(1.0).with_style(self));
+        }
+        if exp < 0 {
+            let power = self.powi(exp.saturating_neg())?;
+            return Self::point(1.0).with_style(self).div(&power);
+        }
+        // the power of non-negative number is increasing,
+        // computed with repeated outward multiplying.
+        let abs_pow = |num: f64| {
+            let (mut base, mut result) = (Self::point(num.abs()), Self::point(1.0));
+            let mut rest = exp;
+            while rest > 0 {
+                if rest & 1 == 1 {
+                    result = result.mul(&base);
+                }
+                base = base.mul(&base);
+                rest >>= 1;
+            }
+            result
+        };
+        let (lower_pow, upper_pow) = (abs_pow(self.lower), abs_pow(self.upper));
+        let result = if exp % 2 == 1 {
+            // odd power is increasing
+            let lower = if self.lower < 0.0 {
+                -lower_pow.upper
+            } else {
+                lower_pow.lower
+            };
+            let upper = if self.upper < 0.0 {
+                -upper_pow.lower
+            } else {
+                upper_pow.upper
+            };
+            self.with_bounds(lower, upper)
+        } else if self.contains(0.0) {
+            self.with_bounds(0.0, lower_pow.upper.max(upper_pow.upper))
+        } else if self.upper < 0.0 {
+            self.with_bounds(upper_pow.lower, lower_pow.upper)
+        } else {
+            self.with_bounds(lower_pow.lower, upper_pow.upper)
+        };
+        return Ok(result);
+    }
+    pub fn with_style(mut self, other: &Self) -> Self {
+        self.is_symmetric = other.is_symmetric;
+        return self;
+    }
+
+    // the image of monotonic function
+    pub fn monotonic(&self, f: fn(f64) -> f64, is_increasing: bool) -> Self {
+        let (lower, upper) = match is_increasing {
+            true => (image_bounds(f, self.lower).0, image_bounds(f, self.upper).1),
+            false => (image_bounds(f, self.upper).0, image_bounds(f, self.lower).1),
+        };
+        return self.with_bounds(lower, upper);
+    }
+    pub fn abs(&self) -> Self {
+        if self.lower >= 0.0 {
+            return *self;
+        }
+        if self.upper <= 0.0 {
+            return self.neg();
+        }
+        return self.with_bounds(0.0, self.upper.max(-self.lower));
+    }
+    // the image of exact monotonic function, e.g. `floor` and `round`
+    pub fn exact_monotonic(&self, f: fn(f64) -> f64) -> Self {
+        return self.with_bounds(f(self.lower), f(self.upper));
+    }
+    // the bounds are clamped to the known minimum of function
+    pub fn at_least(&self, min: f64) -> Self {
+        return self.with_bounds(self.lower.max(min), self.upper.max(min));
+    }
+    // the image of the function which is decreasing before `turning`
+    // and increasing after it, e.g. `cosh` with `turning` ZERO.
+    pub fn valley(&self, f: fn(f64) -> f64, turning: f64) -> Self {
+        if self.lower >= turning {
+            return self.monotonic(f, true);
+        }
+        if self.upper <= turning {
+            return self.monotonic(f, false);
+        }
+        let upper = image_bounds(f, self.lower)
+            .1
+            .max(image_bounds(f, self.upper).1);
+        return self.with_bounds(image_bounds(f, turning).0, upper);
+    }
+    // `sin` or `cos` of the interval in radians,
+    // `peak` is the angle of maximum in [0, 2PI).
+    pub fn periodic(&self, f: fn(f64) -> f64, peak: f64) -> Self {
+        // whether `angle + 2kPI` is in the interval for some integer k
+        let reaches = |angle: f64| {
+            let k = ((self.lower - angle) / TAU).ceil();
+            return angle + k * TAU <= self.upper;
+        };
+        let (mut lower, mut upper) = (-1.0, 1.0);
+        if self.upper - self.lower < TAU {
+            let (bounds1, bounds2) = (image_bounds(f, self.lower), image_bounds(f, self.upper));
+            if !reaches(peak) {
+                upper = bounds1.1.max(bounds2.1).min(1.0);
+            }
+            if !reaches(peak + PI) {
+                lower = bounds1.0.min(bounds2.0).max(-1.0);
+            }
+        }
+        return self.with_bounds(lower, upper);
+    }
+    // `tan` is increasing between the odd multiples of right angle
+    pub fn tan(&self) -> Result<Self, ()> {
+        let branch = |angle: f64| ((angle - FRAC_PI_2) / PI).floor();
+        if self.upper - self.lower >= PI || branch(self.lower) != branch(self.upper) {
+            return Err(math_error(
+                "tangent is undefined for the odd multiples of right angle",
+            )?);
+        }
+        return Ok(self.monotonic(f64::tan, true));
+    }
+    // `num * factor` with outward rounding, the factor
+    // is widened by one ulp since it is a rounded constant.
+    pub fn scale(&self, factor: f64) -> Self {
+        let factor = self.with_bounds(factor.next_down(), factor.next_up());
+        return self.mul(&factor);
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        return self.lower == other.lower && self.upper == other.upper;
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let num_text = |num: f64| Number::Float(num).to_string();
+        if self.is_symmetric {
+            return write!(f, "{} ± {}", num_text(self.mid()), num_text(self.err()));
+        }
+        write!(f, "[{}, {}]", num_text(self.lower), num_text(self.upper))
+    }
+}
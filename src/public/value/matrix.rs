@@ -0,0 +1,347 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+use crossterm::style::Stylize;
+
+use crate::public::env::ENV_OPTION;
+
+use super::number::Number;
+use super::value::Value;
+use super::{display_indent, ComplexStructure, GetAddr};
+
+// the float element whose modulus is less than this
+// is regarded as ZERO in elimination.
+const FLOAT_EPSILON: f64 = 1e-12;
+
+fn is_negligible(num: &Number) -> bool {
+    match num {
+        Number::Float(_) | Number::Decimal(_) | Number::Complex(_) => {
+            num.complex_value().abs() < FLOAT_EPSILON
+        }
+        _ => num.is_zero(),
+    }
+}
+
+// dense matrix of Numbers,
+// elements are stored in row-major order.
+#[derive(PartialEq, Clone)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<Number>,
+}
+
+impl Matrix {
+    pub fn new(rows: usize, cols: usize, data: Vec<Number>) -> Self {
+        debug_assert_eq!(rows * cols, data.len());
+        return Self { rows, cols, data };
+    }
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        return Self::new(rows, cols, vec![Number::Int(0); rows * cols]);
+    }
+    pub fn identity(size: usize) -> Self {
+        let mut result = Self::zeros(size, size);
+        for i in 0..size {
+            *result.get_mut(i, i) = Number::Int(1);
+        }
+        return result;
+    }
+    // returns None when the rows have different lengths
+    pub fn from_rows(rows: Vec<Vec<Number>>) -> Option<Self> {
+        let row_count = rows.len();
+        let col_count = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != col_count) {
+            return None;
+        }
+        let data = rows.into_iter().flatten().collect();
+        return Some(Self::new(row_count, col_count, data));
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> &Number {
+        &self.data[row * self.cols + col]
+    }
+    #[inline]
+    fn get_mut(&mut self, row: usize, col: usize) -> &mut Number {
+        &mut self.data[row * self.cols + col]
+    }
+    pub fn row(&self, index: usize) -> Vec<Number> {
+        let start = index * self.cols;
+        return self.data[start..start + self.cols].to_vec();
+    }
+    fn swap_rows(&mut self, row1: usize, row2: usize) {
+        for col in 0..self.cols {
+            self.data
+                .swap(row1 * self.cols + col, row2 * self.cols + col);
+        }
+    }
+
+    // --- --- --- --- --- ---
+
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                data.push(self.get(row, col).clone());
+            }
+        }
+        return Self::new(self.cols, self.rows, data);
+    }
+
+    // compute element-wisely with matrix in the same shape
+    pub fn zip_with(&self, other: &Self, op: fn(Number, Number) -> Number) -> Option<Self> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return None;
+        }
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(num1, num2)| op(num1.clone(), num2.clone()))
+            .collect();
+        return Some(Self::new(self.rows, self.cols, data));
+    }
+    pub fn map(&self, op: impl Fn(Number) -> Number) -> Self {
+        let data = self.data.iter().map(|num| op(num.clone())).collect();
+        return Self::new(self.rows, self.cols, data);
+    }
+    pub fn mul(&self, other: &Self) -> Option<Self> {
+        if self.cols != other.rows {
+            return None;
+        }
+        let mut result = Self::zeros(self.rows, other.cols);
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                let mut sum = Number::Int(0);
+                for k in 0..self.cols {
+                    sum = sum + self.get(row, k).clone() * other.get(k, col).clone();
+                }
+                *result.get_mut(row, col) = sum;
+            }
+        }
+        return Some(result);
+    }
+
+    // --- --- --- --- --- ---
+
+    // the row with the largest modulus is chosen
+    // to reduce the rounding error of float.
+    fn pivot_row(&self, col: usize, start_row: usize) -> Option<usize> {
+        let modulus = |row: &usize| self.get(*row, col).complex_value().abs();
+        return (start_row..self.rows)
+            .filter(|row| !is_negligible(self.get(*row, col)))
+            .max_by(|row1, row2| {
+                modulus(row1)
+                    .partial_cmp(&modulus(row2))
+                    .unwrap_or(Ordering::Equal)
+            });
+    }
+    // subtract `factor` times of `source` row from `target` row
+    fn eliminate_row(&mut self, target: usize, source: usize, start_col: usize, factor: &Number) {
+        for col in start_col..self.cols {
+            let subtrahend = factor.clone() * self.get(source, col).clone();
+            let current = self.get_mut(target, col);
+            *current = current.clone() - subtrahend;
+        }
+    }
+
+    // Gaussian elimination on the first `reduce_cols` columns,
+    // when `is_jordan` is true, the result is reduced row echelon form.
+    // returns the count of row swaps and the count of pivots (rank).
+    fn reduce(&mut self, reduce_cols: usize, is_jordan: bool) -> (usize, usize) {
+        let mut swap_count = 0;
+        let mut pivot_count = 0;
+
+        for col in 0..reduce_cols {
+            if pivot_count >= self.rows {
+                break;
+            }
+            let Some(target_row) = self.pivot_row(col, pivot_count) else {
+                continue;
+            };
+            if target_row != pivot_count {
+                self.swap_rows(target_row, pivot_count);
+                swap_count += 1;
+            }
+
+            let pivot_row = pivot_count;
+            let pivot = self.get(pivot_row, col).clone();
+            let start_row = if is_jordan { 0 } else { pivot_row + 1 };
+            for row in start_row..self.rows {
+                if row == pivot_row || self.get(row, col).is_zero() {
+                    continue;
+                }
+                let factor = self.get(row, col).true_div(&pivot);
+                self.eliminate_row(row, pivot_row, col, &factor);
+                // avoid the rounding residue of float
+                *self.get_mut(row, col) = Number::Int(0);
+            }
+            if is_jordan {
+                for c in col..self.cols {
+                    let current = self.get_mut(pivot_row, c);
+                    *current = current.true_div(&pivot);
+                }
+            }
+            pivot_count += 1;
+        }
+        return (swap_count, pivot_count);
+    }
+
+    // horizontally concatenate two matrices with the same row count
+    fn augment(&self, other: &Self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        for row in 0..self.rows {
+            data.extend(self.row(row));
+            data.extend(other.row(row));
+        }
+        return Self::new(self.rows, self.cols + other.cols, data);
+    }
+    fn columns_from(&self, start_col: usize) -> Self {
+        let mut data = vec![];
+        for row in 0..self.rows {
+            data.extend_from_slice(&self.row(row)[start_col..]);
+        }
+        return Self::new(self.rows, self.cols - start_col, data);
+    }
+
+    // the methods below expect square matrix
+    pub fn det(&self) -> Number {
+        let mut reduced = self.clone();
+        let (swap_count, rank) = reduced.reduce(self.cols, false);
+        if rank < self.rows {
+            return Number::Int(0);
+        }
+        let mut result = Number::Int(1);
+        for i in 0..self.rows {
+            result = result * reduced.get(i, i).clone();
+        }
+        if swap_count % 2 == 1 {
+            result = Number::Int(0) - result;
+        }
+        return result;
+    }
+    // returns None when the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        return self.solve(&Self::identity(self.rows));
+    }
+    // solve `self * x = b`, returns None when the matrix is singular
+    pub fn solve(&self, b: &Self) -> Option<Self> {
+        let mut augmented = self.augment(b);
+        let (_, rank) = augmented.reduce(self.cols, true);
+        if rank < self.rows {
+            return None;
+        }
+        return Some(augmented.columns_from(self.cols));
+    }
+    // LU decomposition with partial pivoting,
+    // returns (P, L, U) where `P * self = L * U`.
+    pub fn lu(&self) -> (Self, Self, Self) {
+        let size = self.rows;
+        let mut permutation = Self::identity(size);
+        let mut lower = Self::zeros(size, size);
+        let mut upper = self.clone();
+
+        for col in 0..size {
+            if let Some(target_row) = upper.pivot_row(col, col) {
+                if target_row != col {
+                    upper.swap_rows(target_row, col);
+                    lower.swap_rows(target_row, col);
+                    permutation.swap_rows(target_row, col);
+                }
+                let pivot = upper.get(col, col).clone();
+                for row in col + 1..size {
+                    let factor = upper.get(row, col).true_div(&pivot);
+                    upper.eliminate_row(row, col, col, &factor);
+                    *upper.get_mut(row, col) = Number::Int(0);
+                    *lower.get_mut(row, col) = factor;
+                }
+            }
+            *lower.get_mut(col, col) = Number::Int(1);
+        }
+        return (permutation, lower, upper);
+    }
+
+    // integer power by squaring,
+    // returns None when the exponent is negative and the matrix is singular.
+    pub fn pow(&self, exp: i64) -> Option<Self> {
+        let mut base = if exp < 0 {
+            self.inverse()?
+        } else {
+            self.clone()
+        };
+        let mut exp = exp.unsigned_abs();
+        let mut result = Self::identity(self.rows);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(&base).unwrap();
+            }
+            base = base.mul(&base).unwrap();
+            exp >>= 1;
+        }
+        return Some(result);
+    }
+
+    pub fn rank(&self) -> usize {
+        let mut reduced = self.clone();
+        let (_, rank) = reduced.reduce(self.cols, false);
+        return rank;
+    }
+}
+
+impl GetAddr for Matrix {
+    fn get_addr(&self) -> super::Addr {
+        let ptr = self as *const Self;
+        return ptr as super::Addr;
+    }
+}
+
+impl ComplexStructure for Matrix {
+    fn display(f: &mut fmt::Formatter<'_>, mat: &Rc<RefCell<Self>>, level: usize) -> fmt::Result {
+        let mat_ref = mat.borrow();
+        let texts: Vec<String> = mat_ref.data.iter().map(|num| num.to_string()).collect();
+
+        // right-align elements in each column
+        let mut widths = vec![0; mat_ref.cols];
+        for (index, text) in texts.iter().enumerate() {
+            let col = index % mat_ref.cols;
+            widths[col] = widths[col].max(text.chars().count());
+        }
+
+        write!(f, "[\r\n")?;
+        for row in 0..mat_ref.rows {
+            write!(f, "{}[", display_indent(level))?;
+            for col in 0..mat_ref.cols {
+                if col > 0 {
+                    write!(f, "{}", ", ".dim())?;
+                }
+                let text = &texts[row * mat_ref.cols + col];
+                let aligned = format!("{:>width$}", text, width = widths[col]);
+                if unsafe { ENV_OPTION.support_ansi } {
+                    write!(f, "{}", aligned.yellow())?;
+                } else {
+                    write!(f, "{}", aligned)?;
+                }
+            }
+            write!(f, "]\r\n")?;
+        }
+        write!(f, "{}]", display_indent(level - 1))
+    }
+
+    fn deep_clone(mat: &Rc<RefCell<Self>>) -> Value {
+        return Value::from(mat.borrow().clone());
+    }
+}
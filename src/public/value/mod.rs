@@ -1,13 +1,22 @@
 use std::{cell::RefCell, fmt, rc::Rc};
 
-use self::{array::RawArray, map::RawMap, oop::object::Object, value::Value};
+use self::{array::RawArray, map::RawMap, matrix::Matrix, oop::object::Object, value::Value};
+use super::std::modules::polynomial::PolynomialModule;
 
 pub mod value;
 
 pub mod array;
+pub mod big_decimal;
+pub mod big_int;
+pub mod complex;
 pub mod function;
+pub mod interval;
 pub mod map;
+pub mod matrix;
 pub mod number;
+pub mod number_format;
+pub mod number_rational;
+pub mod quantity;
 pub mod symbols;
 pub mod unique;
 
@@ -33,7 +42,11 @@ pub trait ComplexStructure {
             Value::String(_) => write!(f, "{}", value.str_format().unwrap()),
             Value::Array(arr) => RawArray::display(f, arr, level),
             Value::Map(map) => RawMap::display(f, map, level),
-            Value::Object(obj) => Object::display(f, obj, level),
+            Value::Matrix(mat) => Matrix::display(f, mat, level),
+            Value::Object(obj) => match PolynomialModule::display_text(value) {
+                Some(text) => write!(f, "{}", text),
+                None => Object::display(f, obj, level),
+            },
             _ => write!(f, "{}", value),
         }
     }
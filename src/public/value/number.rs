@@ -2,92 +2,300 @@ use std::cmp::{self, PartialEq};
 use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
 
-use crate::public::error::{internal_error, InternalComponent, math_error};
+use crate::public::env::ENV_OPTION;
+use crate::public::error::math_error;
+
+use super::big_decimal::BigDecimal;
+use super::big_int::BigInt;
+use super::complex::Complex;
 
 #[cfg_attr(debug_assertions, derive(Debug))]
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub enum Number {
     NotANumber,
 
     Int(i64),
     Float(f64),
     Fraction(i64, i64),
+
+    // used when the value overflows `Int` or `Fraction`
+    BigInt(BigInt),
+    BigFraction(BigInt, BigInt),
+
+    // used instead of `Float` in decimal mode
+    Decimal(BigDecimal),
+
+    Complex(Complex),
 }
 
+// the exponent of `Number::pow` resulting in more bits than this
+// will be computed as float number.
+const MAX_POW_RESULT_BITS: u64 = 1 << 22;
+
 impl Number {
-    pub fn modulo(dividend: Self, divisor: Self) -> Self{
-        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (dividend, divisor) {
+    // the floored division and modulo,
+    // the quotient is rounded toward negative infinity and
+    // the remainder has the same sign as the divisor.
+    pub fn floor_div(dividend: Self, divisor: Self) -> Self {
+        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (&dividend, &divisor) {
             return Self::NotANumber;
         }
 
         // the divisor can not be ZERO
-        if divisor.float_value() == 0.0 {
-            math_error("modulo by zero").unwrap_err();
+        if divisor.is_zero() {
+            math_error("the divisor should not to be ZERO").unwrap_err();
             return Self::NotANumber;
         }
 
-        if let (Self::Float(_), _) | (_, Self::Float(_)) = (dividend, divisor) {
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (&dividend, &divisor) {
+            math_error("complex number does not support floor division").unwrap_err();
+            return Self::NotANumber;
+        }
+
+        // decimal numbers are computed exactly
+        let (dividend, divisor) = match Self::decimal_operands(&dividend, &divisor) {
+            Some((d1, d2, _)) => (Self::Decimal(d1), Self::Decimal(d2)),
+            None => (dividend, divisor),
+        };
+        if let (Self::Float(_), _) | (_, Self::Float(_)) = (&dividend, &divisor) {
             let f1 = dividend.float_value();
             let f2 = divisor.float_value();
-            return Number::Float(f1 % f2);
+            return Number::Float((f1 / f2).floor());
         }
 
-        match (dividend, divisor) {
-            (Self::Int(i1), Self::Int(i2)) => Number::Int(i1 % i2),
-            (Self::Int(i), Self::Fraction(upper, lower)) | (Self::Fraction(upper, lower), Self::Int(i)) => {
-                let temp1 = i * lower;
-                let temp2 = upper * lower;
-                Number::Fraction(temp1 % temp2, lower)
+        if let (Self::Int(i1), Self::Int(i2)) = (&dividend, &divisor) {
+            if let (Some(quotient), Some(remainder)) = (i1.checked_div(*i2), i1.checked_rem(*i2)) {
+                let is_adjusted = remainder != 0 && (remainder < 0) != (*i2 < 0);
+                return Self::Int(quotient - is_adjusted as i64);
             }
-            (Self::Fraction(upper1, lower1), Self::Fraction(upper2, lower2)) => {
-                let lower_lcm = Self::lcm(lower1, lower2);
-                let multed_upper1 = upper1 * (lower_lcm / lower1);
-                let multed_upper2 = upper2 * (lower_lcm / lower2);
-                Number::Fraction(multed_upper1 % multed_upper2, lower_lcm).reduce().unwrap() 
+        }
+
+        // floor((u1 / l1) / (u2 / l2)) == floor((u1 * l2) / (u2 * l1))
+        Self::exact_compute(
+            &dividend,
+            &divisor,
+            |u1, l1, u2, l2| (Self::floor_div_rem(u1 * l2, u2 * l1).0, 1),
+            |u1, l1, u2, l2| ((u1 * l2).div_floor(&(u2 * l1)).0, BigInt::one()),
+        )
+    }
+
+    pub fn modulo(dividend: Self, divisor: Self) -> Self {
+        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (&dividend, &divisor) {
+            return Self::NotANumber;
+        }
+
+        // the divisor can not be ZERO
+        if divisor.is_zero() {
+            math_error("modulo by zero").unwrap_err();
+            return Self::NotANumber;
+        }
+
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (&dividend, &divisor) {
+            math_error("complex number does not support modulo").unwrap_err();
+            return Self::NotANumber;
+        }
+
+        // decimal numbers are computed exactly and
+        // the remainder is converted back to decimal.
+        if let Some((d1, d2, precision)) = Self::decimal_operands(&dividend, &divisor) {
+            let (u1, l1) = Self::Decimal(d1).big_ratio();
+            let (u2, l2) = Self::Decimal(d2).big_ratio();
+            let remainder = (&u1 * &l2).div_floor(&(&u2 * &l1)).1;
+            let result = BigDecimal::from_ratio(&remainder, &(&l1 * &l2), precision);
+            return Self::Decimal(result);
+        }
+        if let (Self::Float(_), _) | (_, Self::Float(_)) = (&dividend, &divisor) {
+            let f1 = dividend.float_value();
+            let f2 = divisor.float_value();
+            let remainder = f1 % f2;
+            if remainder != 0.0 && (remainder < 0.0) != (f2 < 0.0) {
+                return Number::Float(remainder + f2);
             }
-            _ => unreachable!()
+            return Number::Float(remainder);
         }
+
+        if let (Self::Int(i1), Self::Int(i2)) = (&dividend, &divisor) {
+            if let Some(remainder) = i1.checked_rem(*i2) {
+                if remainder != 0 && (remainder < 0) != (*i2 < 0) {
+                    return Self::Int(remainder + i2);
+                }
+                return Self::Int(remainder);
+            }
+        }
+
+        // (u1 / l1) % (u2 / l2) == ((u1 * l2) % (u2 * l1)) / (l1 * l2)
+        Self::exact_compute(
+            &dividend,
+            &divisor,
+            |u1, l1, u2, l2| (Self::floor_div_rem(u1 * l2, u2 * l1).1, l1 * l2),
+            |u1, l1, u2, l2| ((u1 * l2).div_floor(&(u2 * l1)).1, l1 * l2),
+        )
     }
 
     pub fn pow(base: Self, target: Self) -> Self {
-        match base {
-            Self::Int(num1) => match target {
-                Self::Int(num2) => {
-                    if num2 >= 0 {
-                        Self::Int(num1.pow(num2 as u32))
-                    } else {
-                        Self::Float((num1 as f64).powi(num2 as i32))
+        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (&base, &target) {
+            return Self::NotANumber;
+        }
+
+        let exponent = match &target {
+            Self::Int(i) => Some(*i),
+            Self::BigInt(big) => big.to_i64(),
+            _ => None,
+        };
+        if let (Self::BigInt(big), None) = (&target, exponent) {
+            if !matches!(base, Self::Complex(_)) {
+                return Self::huge_pow(base, big);
+            }
+        }
+        if let Some(result) = Self::decimal_pow(&base, &target, exponent) {
+            return result;
+        }
+        match (&base, exponent) {
+            (Self::Complex(z), Some(exp)) if exp.abs() <= i32::MAX as i64 => {
+                Self::from_complex(z.powi(exp))
+            }
+            (Self::Complex(_), _) | (_, None) if base.is_complex_pow(&target) => {
+                let base_z = base.complex_value();
+                let target_z = target.complex_value();
+                Self::from_complex(base_z.pow(target_z))
+            }
+            (Self::Float(_) | Self::Decimal(_), Some(exp))
+                if exp >= i32::MIN as i64 && exp <= i32::MAX as i64 =>
+            {
+                Self::Float(base.float_value().powi(exp as i32))
+            }
+            (Self::Float(_) | Self::Decimal(_), _) | (_, None) => {
+                let self_f = base.float_value();
+                let target_f = target.float_value();
+                Self::Float(self_f.powf(target_f))
+            }
+            (_, Some(exp)) => {
+                // exact base with integer exponent
+                if exp < 0 {
+                    if !unsafe { ENV_OPTION.exact_mode } {
+                        let self_f = base.float_value();
+                        return Self::Float(self_f.powf(exp as f64));
                     }
+                    // x ^ -n == (1 / x) ^ n
+                    let reciprocal = Self::Int(1) / base;
+                    if let Self::NotANumber = reciprocal {
+                        return Self::NotANumber;
+                    }
+                    return Self::pow(reciprocal, Self::Int(exp.saturating_neg()));
                 }
-                Self::Float(num2) => Self::Float((num1 as f64).powf(num2)),
-                Self::Fraction(_, _) => Self::Float((num1 as f64).powf(target.float_value())),
-                _ => Self::NotANumber,
-            },
-            Self::Float(num1) => match target {
-                Self::Int(num2) => Self::Float(num1.powi(num2 as i32)),
-                Self::Float(num2) => Self::Float(num1.powf(num2)),
-                Self::Fraction(_, _) => Self::Float(num1.powf(target.float_value())),
-                _ => Self::NotANumber,
-            },
-            Self::Fraction(upper, lower) => match target {
-                Self::Int(num2) => {
-                    let upper_powed = upper.pow(num2 as u32);
-                    let lower_powed = lower.pow(num2 as u32);
-                    Self::Fraction(upper_powed, lower_powed).reduce().unwrap()
-                }
-                Self::Float(num2) => {
-                    let f_value = base.float_value();
-                    Self::Float(f_value.powf(num2))
-                }
-                Self::Fraction(_, _) => {
+
+                let (upper, lower) = base.big_ratio();
+                let result_bits = cmp::max(upper.bits(), lower.bits()).saturating_mul(exp as u64);
+                if exp > u32::MAX as i64 || result_bits > MAX_POW_RESULT_BITS {
+                    if upper.is_zero() || upper.abs().is_one() && lower.is_one() {
+                        // 0 ^ n, 1 ^ n and -1 ^ n
+                        let sign_base = if exp % 2 == 0 { upper.abs() } else { upper };
+                        return Self::from_big_ratio(sign_base, lower);
+                    }
                     let self_f = base.float_value();
-                    let target_f = target.float_value();
-                    Self::Float(self_f.powf(target_f))
+                    return Self::Float(self_f.powf(exp as f64));
                 }
-                _ => Self::NotANumber,
-            },
-            Self::NotANumber => Self::NotANumber,
+
+                if let Self::Int(i) = base {
+                    if let Some(result) = i.checked_pow(exp as u32) {
+                        return Self::Int(result);
+                    }
+                }
+                Self::from_big_ratio(upper.pow(exp as u32), lower.pow(exp as u32))
+            }
+        }
+    }
+
+    // the power with the integer exponent out of the range of i64,
+    // the exact base other than ZERO and ONE results in math error.
+    fn huge_pow(base: Self, exp: &BigInt) -> Self {
+        let is_odd = !exp.is_even();
+        if let Self::Float(_) | Self::Decimal(_) = base {
+            let base_f = base.float_value();
+            let magnitude = base_f.abs().powf(exp.to_f64());
+            let is_negative = base_f < 0.0 && is_odd;
+            return Self::Float(if is_negative { -magnitude } else { magnitude });
+        }
+
+        let (upper, lower) = base.big_ratio();
+        if upper.is_zero() && exp.is_negative() {
+            // the same as the reciprocal of ZERO
+            return Self::pow(base, Self::Int(-1));
+        }
+        if upper.is_zero() || upper.abs().is_one() && lower.is_one() {
+            // 0 ^ n, 1 ^ n and -1 ^ n
+            let sign_base = if is_odd { upper } else { upper.abs() };
+            return Self::from_big_ratio(sign_base, lower);
+        }
+        math_error("the exponent is too large").unwrap_err();
+        return Self::NotANumber;
+    }
+
+    // the power in decimal mode, returns None when
+    // the result should not be a decimal number.
+    fn decimal_pow(base: &Self, target: &Self, exponent: Option<i64>) -> Option<Self> {
+        let precision = unsafe { ENV_OPTION.decimal_precision };
+        if precision == 0 || base.is_complex_pow(target) {
+            return None;
+        }
+        let is_inexact = matches!(base, Self::Float(_) | Self::Decimal(_))
+            || matches!(target, Self::Float(_) | Self::Decimal(_));
+        // the negative integer exponent results in fraction in exact mode
+        let is_reciprocal =
+            exponent.is_some_and(|exp| exp < 0) && !unsafe { ENV_OPTION.exact_mode };
+        if !is_inexact && !is_reciprocal {
+            return None;
+        }
+
+        let base_d = base.decimal_value(precision)?;
+        if base_d.is_zero() {
+            // ZERO to the negative power is computed as float
+            return match target.float_value().partial_cmp(&0.0) {
+                Some(cmp::Ordering::Greater) => Some(Self::Decimal(BigDecimal::zero())),
+                Some(cmp::Ordering::Equal) => Some(Self::Decimal(BigDecimal::one())),
+                _ => None,
+            };
+        }
+        if let Some(exp) = exponent {
+            return Some(Self::Decimal(base_d.powi(exp, precision)));
+        }
+        // x ^ y == exp(y * ln(x)), the base is positive here
+        let target_d = target.decimal_value(precision)?;
+        let working = precision + 10;
+        let product = target_d.mul(&base_d.ln(working), working);
+        return Some(Self::Decimal(product.exp(precision)));
+    }
+
+    // the bitwise operations below are only for Int and BigInt,
+    // and the negative integers are in two's complement.
+    pub fn bitwise(num1: &Self, num2: &Self, op: fn(u32, u32) -> u32) -> Self {
+        let (big1, _) = num1.big_ratio();
+        let (big2, _) = num2.big_ratio();
+        return Self::from_big_ratio(big1.bitwise(&big2, op), BigInt::one());
+    }
+    // returns None when the result is too large
+    pub fn shift_left(num: &Self, bits: u64) -> Option<Self> {
+        let (big, _) = num.big_ratio();
+        if big.is_zero() {
+            return Some(Self::Int(0));
+        }
+        if big.bits().saturating_add(bits) > MAX_POW_RESULT_BITS {
+            return None;
         }
+        return Some(Self::from_big_ratio(big.shl(bits as u32), BigInt::one()));
+    }
+    // the result is floored, e.g. `-5 >> 1 == -3`
+    pub fn shift_right(num: &Self, bits: u64) -> Self {
+        let (big, _) = num.big_ratio();
+        let result = match big.is_negative() {
+            // `floor(-x / 2^n) == -((x - 1) >> n) - 1`
+            true => {
+                let shifted = (&big.abs() - &BigInt::one()).shr(bits);
+                &-shifted - &BigInt::one()
+            }
+            false => big.shr(bits),
+        };
+        return Self::from_big_ratio(result, BigInt::one());
     }
 
     pub fn not(&self) -> Self {
@@ -101,47 +309,144 @@ impl Number {
                     Self::Int(0)
                 }
             }
+            Self::BigInt(big) => Self::Int(!(!big.is_negative() && !big.is_zero()) as i64),
+            Self::BigFraction(upper, _) => Self::Int(upper.is_zero() as i64),
+            Self::Decimal(decimal) => {
+                Self::Int((decimal.is_negative() || decimal.is_zero()) as i64)
+            }
+            Self::Complex(_) => Self::Int(self.is_zero() as i64),
             Self::NotANumber => Self::Int(1),
         }
     }
 
     pub fn int(&self) -> Self {
         match self {
-            Self::Float(f) => Self::Int(*f as i64),
+            Self::Float(f) => {
+                if f.abs() < i64::MAX as f64 {
+                    Self::Int(*f as i64)
+                } else {
+                    match BigInt::from_f64(*f) {
+                        Some(big) => Self::BigInt(big),
+                        None => Self::NotANumber,
+                    }
+                }
+            }
             Self::Fraction(_, _) => Self::Int(self.int_value()),
+            Self::BigFraction(upper, lower) => {
+                let (quotient, _) = upper.div_rem(lower);
+                Self::from_big_ratio(quotient, BigInt::one())
+            }
+            Self::Decimal(decimal) => Self::from_big_ratio(decimal.trunc(), BigInt::one()),
             _ => self.clone(),
         }
     }
     pub fn float(&self) -> Self {
         match self {
-            Self::Int(i) => Self::Float(*i as f64),
-            Self::Fraction(_, _) => Self::Float(self.float_value()),
+            Self::Int(_)
+            | Self::Fraction(_, _)
+            | Self::BigInt(_)
+            | Self::BigFraction(_, _)
+            | Self::Decimal(_) => Self::Float(self.float_value()),
             _ => self.clone(),
         }
     }
 
-    pub fn int_value(self) -> i64 {
+    pub fn int_value(&self) -> i64 {
         match self {
-            Self::Int(i) => i,
-            Self::Float(f) => f as i64,
-            Self::Fraction(upper, lower) => {
-                if lower > upper {
-                    0
+            Self::Int(i) => *i,
+            Self::Float(f) => *f as i64,
+            Self::Fraction(upper, lower) => upper / lower,
+            Self::Complex(z) => z.re as i64,
+            Self::BigInt(_) | Self::BigFraction(_, _) | Self::Decimal(_) => match self.int() {
+                // saturate to the range of i64
+                Self::BigInt(big) => big.to_i64().unwrap_or(if big.is_negative() {
+                    i64::MIN
                 } else {
-                    upper / lower
-                }
-            }
+                    i64::MAX
+                }),
+                truncated => truncated.int_value(),
+            },
             Self::NotANumber => 0,
         }
     }
-    pub fn float_value(self) -> f64 {
+    pub fn float_value(&self) -> f64 {
         match self {
-            Self::Int(i) => i as f64,
-            Self::Float(f) => f,
-            Self::Fraction(upper, lower) => (upper as f64) / (lower as f64),
+            Self::Int(i) => *i as f64,
+            Self::Float(f) => *f,
+            Self::Fraction(upper, lower) => (*upper as f64) / (*lower as f64),
+            Self::BigInt(big) => big.to_f64(),
+            Self::BigFraction(upper, lower) => BigInt::ratio_to_f64(upper, lower),
+            Self::Decimal(decimal) => decimal.to_f64(),
+            Self::Complex(z) => z.re,
             Self::NotANumber => 0_f64,
         }
     }
+    // the real number rounded to `precision` significant digits
+    pub fn decimal_value(&self, precision: usize) -> Option<BigDecimal> {
+        let decimal = match self {
+            Self::Int(i) => BigDecimal::from(BigInt::from(*i)),
+            Self::BigInt(big) => BigDecimal::from(big.clone()),
+            Self::Fraction(_, _) | Self::BigFraction(_, _) => {
+                let (upper, lower) = self.big_ratio();
+                BigDecimal::from_ratio(&upper, &lower, precision)
+            }
+            Self::Float(f) => BigDecimal::from_f64(*f)?,
+            Self::Decimal(decimal) => decimal.round(precision),
+            Self::Complex(_) | Self::NotANumber => return None,
+        };
+        return Some(decimal);
+    }
+    pub fn complex_value(&self) -> Complex {
+        match self {
+            Self::Complex(z) => *z,
+            _ => Complex::new(self.float_value(), 0.0),
+        }
+    }
+    // the complex number without imaginary part is regarded as float
+    pub fn from_complex(z: Complex) -> Self {
+        if z.im == 0.0 {
+            Self::Float(z.re)
+        } else {
+            Self::Complex(z)
+        }
+    }
+
+    fn is_complex_pow(&self, target: &Self) -> bool {
+        // complex power is used when any side is complex, or
+        // the base is negative and the exponent is not integer.
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (self, target) {
+            return true;
+        }
+        let target_f = target.float_value();
+        return self.float_value() < 0.0 && target_f.is_finite() && target_f.fract() != 0.0;
+    }
+
+    // the absolute value of real number, the exact number is kept exact
+    pub fn abs(&self) -> Self {
+        match self {
+            Self::Int(i) if *i != i64::MIN => Self::Int(i.abs()),
+            Self::Float(f) => Self::Float(f.abs()),
+            Self::Decimal(decimal) => Self::Decimal(decimal.abs()),
+            Self::Complex(z) => Self::Float(z.abs()),
+            Self::NotANumber => Self::NotANumber,
+            _ => {
+                let (upper, lower) = self.big_ratio();
+                Self::from_big_ratio(upper.abs(), lower)
+            }
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Self::Int(i) => *i == 0,
+            Self::Float(f) => *f == 0.0,
+            Self::Fraction(upper, _) => *upper == 0,
+            Self::BigInt(big) | Self::BigFraction(big, _) => big.is_zero(),
+            Self::Decimal(decimal) => decimal.is_zero(),
+            Self::Complex(z) => z.re == 0.0 && z.im == 0.0,
+            Self::NotANumber => false,
+        }
+    }
 
     fn float_cmp(num1: f64, num2: f64) -> bool {
         const EPS: f64 = f64::EPSILON;
@@ -150,61 +455,144 @@ impl Number {
         return diff_abs <= EPS;
     }
 
-    fn reduce(&self) -> Result<Self, ()> {
-        // this method is specially for Number::Fraction
-        let Self::Fraction(mut upper, mut lower) = self else {
-            return Err(internal_error(
-                InternalComponent::InternalFn,
-                "invalid `Number::reduce` invocation"
-            )?);
-        };
+    // --- --- --- --- --- ---
+
+    // get `(upper, lower)` of Int and Fraction,
+    // it is used to compute exactly without overflow.
+    fn small_ratio(&self) -> Option<(i128, i128)> {
+        match self {
+            Self::Int(i) => Some((*i as i128, 1)),
+            Self::Fraction(upper, lower) => Some((*upper as i128, *lower as i128)),
+            _ => None,
+        }
+    }
+    // this method is specially for exact typed Number
+    pub(super) fn big_ratio(&self) -> (BigInt, BigInt) {
+        match self {
+            Self::Int(i) => (BigInt::from(*i), BigInt::one()),
+            Self::Fraction(upper, lower) => (BigInt::from(*upper), BigInt::from(*lower)),
+            Self::BigInt(big) => (big.clone(), BigInt::one()),
+            Self::BigFraction(upper, lower) => (upper.clone(), lower.clone()),
+            Self::Decimal(decimal) => decimal.to_ratio(),
+            Self::Float(_) | Self::Complex(_) | Self::NotANumber => unreachable!(),
+        }
+    }
+
+    // the operands converted to decimal numbers and the precision,
+    // returns None when not in decimal mode or both operands are exact.
+    fn decimal_operands(num1: &Self, num2: &Self) -> Option<(BigDecimal, BigDecimal, usize)> {
+        let precision = unsafe { ENV_OPTION.decimal_precision };
+        if precision == 0 {
+            return None;
+        }
+        match (num1, num2) {
+            (Self::Complex(_), _) | (_, Self::Complex(_)) => return None,
+            (Self::Float(_) | Self::Decimal(_), _) | (_, Self::Float(_) | Self::Decimal(_)) => {}
+            _ => return None,
+        }
+        let d1 = num1.decimal_value(precision)?;
+        let d2 = num2.decimal_value(precision)?;
+        return Some((d1, d2, precision));
+    }
+
+    // reduce the fraction and choose the smallest variant to store it
+    pub fn from_small_ratio(mut upper: i128, mut lower: i128) -> Self {
+        if lower < 0 {
+            upper = -upper;
+            lower = -lower;
+        }
         let gcd_result = Self::gcd(upper, lower);
-        upper /= gcd_result;
-        lower /= gcd_result;
-        return Ok(Self::Fraction(upper, lower));
+        if gcd_result > 1 {
+            upper /= gcd_result;
+            lower /= gcd_result;
+        }
+
+        let upper_i64 = i64::try_from(upper);
+        let lower_i64 = i64::try_from(lower);
+        match (upper_i64, lower_i64) {
+            (Ok(upper), Ok(1)) => Self::Int(upper),
+            (Ok(upper), Ok(lower)) => Self::Fraction(upper, lower),
+            _ => Self::from_big_ratio(BigInt::from(upper), BigInt::from(lower)),
+        }
     }
-    // greatest common divisor
-    fn gcd(n1: i64, n2: i64) -> i64 {
-        if n1 == n2 {
-            return n1;
+    pub(super) fn from_big_ratio(mut upper: BigInt, mut lower: BigInt) -> Self {
+        if lower.is_negative() {
+            upper = -upper;
+            lower = -lower;
+        }
+        let gcd_result = BigInt::gcd(&upper, &lower);
+        if !gcd_result.is_one() && !gcd_result.is_zero() {
+            upper = upper.div_rem(&gcd_result).0;
+            lower = lower.div_rem(&gcd_result).0;
         }
 
-        // avoid negative number
-        let larger = cmp::max(n1, n2).abs();
-        let smaller = cmp::min(n1, n2).abs();
+        if let (Some(upper), Some(lower)) = (upper.to_i128(), lower.to_i128()) {
+            if let (Ok(upper), Ok(lower)) = (i64::try_from(upper), i64::try_from(lower)) {
+                return if lower == 1 {
+                    Self::Int(upper)
+                } else {
+                    Self::Fraction(upper, lower)
+                };
+            }
+        }
+        if lower.is_one() {
+            Self::BigInt(upper)
+        } else {
+            Self::BigFraction(upper, lower)
+        }
+    }
 
-        if smaller == 0 {
-            return larger;
+    // compute between two exact typed Numbers,
+    // i128 is used when operands are small enough,
+    // otherwise BigInt is used.
+    fn exact_compute(
+        num1: &Self,
+        num2: &Self,
+        small_cb: fn(i128, i128, i128, i128) -> (i128, i128),
+        big_cb: fn(&BigInt, &BigInt, &BigInt, &BigInt) -> (BigInt, BigInt),
+    ) -> Self {
+        if let (Some((u1, l1)), Some((u2, l2))) = (num1.small_ratio(), num2.small_ratio()) {
+            let (upper, lower) = small_cb(u1, l1, u2, l2);
+            return Self::from_small_ratio(upper, lower);
         }
+        let (u1, l1) = num1.big_ratio();
+        let (u2, l2) = num2.big_ratio();
+        let (upper, lower) = big_cb(&u1, &l1, &u2, &l2);
+        return Self::from_big_ratio(upper, lower);
+    }
+
+    // floored division of i128, returns (quotient, remainder)
+    fn floor_div_rem(dividend: i128, divisor: i128) -> (i128, i128) {
+        let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+        if remainder != 0 && (remainder < 0) != (divisor < 0) {
+            return (quotient - 1, remainder + divisor);
+        }
+        return (quotient, remainder);
+    }
+
+    // greatest common divisor
+    pub fn gcd(n1: i128, n2: i128) -> i128 {
+        // avoid negative number
+        let mut larger = n1.abs();
+        let mut smaller = n2.abs();
 
-        return Self::gcd(smaller, larger % smaller);
+        while smaller != 0 {
+            let temp = larger % smaller;
+            larger = smaller;
+            smaller = temp;
+        }
+        return larger;
     }
     // least common multiple
-    fn lcm(n1: i64, n2: i64) -> i64 {
-        return (n1 * n2) / Self::gcd(n1, n2);
+    pub fn lcm(n1: i128, n2: i128) -> i128 {
+        return n1 / Self::gcd(n1, n2) * n2;
     }
 }
 
 impl fmt::Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::NotANumber => write!(f, "Not a Number"),
-
-            Self::Int(num) => write!(f, "{}", num),
-            Self::Float(num) => {
-                // optimize float number output
-                // example:
-                // 1.0000000000 -> 1
-                if *num == num.floor() {
-                    write!(f, "{}", *num as i64)
-                } else {
-                    write!(f, "{:.10}", num)
-                }
-            }
-            Self::Fraction(upper, lower) => {
-                write!(f, "({} / {})", upper, lower)
-            }
-        }
+        let display_mode = unsafe { ENV_OPTION.display_mode };
+        write!(f, "{}", self.format(display_mode))
     }
 }
 
@@ -212,133 +600,156 @@ impl fmt::Display for Number {
 impl Add for Number {
     type Output = Self;
     fn add(self, other: Self) -> Self {
-        if self == Self::NotANumber || other == Self::NotANumber {
+        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (&self, &other) {
             return Self::NotANumber;
         }
 
-        if let (Self::Float(_), _) | (_, Self::Float(_)) = (self, other) {
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (&self, &other) {
+            let z1 = self.complex_value();
+            let z2 = other.complex_value();
+            return Self::from_complex(z1 + z2);
+        }
+
+        if let Some((d1, d2, precision)) = Self::decimal_operands(&self, &other) {
+            return Self::Decimal(d1.add(&d2, precision));
+        }
+        if let (Self::Float(_) | Self::Decimal(_), _) | (_, Self::Float(_) | Self::Decimal(_)) =
+            (&self, &other)
+        {
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Self::Float(f1 + f2);
         }
 
-        match self {
-            Self::Int(num1) => match other {
-                Self::Int(num2) => Self::Int(num1 + num2),
-                Self::Fraction(upper, lower) => Self::Fraction(upper + num1 * lower, lower)
-                    .reduce()
-                    .unwrap(),
-                _ => unreachable!(),
-            },
-            Self::Fraction(upper, lower) => match other {
-                Self::Int(num2) => Self::Fraction(upper + num2 * lower, lower)
-                    .reduce()
-                    .unwrap(),
-                Self::Fraction(other_upper, other_lower) => {
-                    let lower_lcm = Self::lcm(lower, other_lower);
-                    let self_factor = lower_lcm / lower;
-                    let other_factor = lower_lcm / other_lower;
-                    let res_upper = upper * self_factor + other_upper * other_factor;
-                    Self::Fraction(res_upper, lower_lcm).reduce().unwrap()
-                }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+        if let (Self::Int(num1), Self::Int(num2)) = (&self, &other) {
+            if let Some(result) = num1.checked_add(*num2) {
+                return Self::Int(result);
+            }
         }
+
+        Self::exact_compute(
+            &self,
+            &other,
+            |u1, l1, u2, l2| {
+                let lower_lcm = Self::lcm(l1, l2);
+                let upper = u1 * (lower_lcm / l1) + u2 * (lower_lcm / l2);
+                (upper, lower_lcm)
+            },
+            |u1, l1, u2, l2| (&(u1 * l2) + &(u2 * l1), l1 * l2),
+        )
     }
 }
 
 impl Sub for Number {
     type Output = Self;
     fn sub(self, other: Self) -> Self {
-        if self == Self::NotANumber || other == Self::NotANumber {
+        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (&self, &other) {
             return Self::NotANumber;
         }
 
-        if let (Self::Float(_), _) | (_, Self::Float(_)) = (self, other) {
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (&self, &other) {
+            let z1 = self.complex_value();
+            let z2 = other.complex_value();
+            return Self::from_complex(z1 - z2);
+        }
+
+        if let Some((d1, d2, precision)) = Self::decimal_operands(&self, &other) {
+            return Self::Decimal(d1.sub(&d2, precision));
+        }
+        if let (Self::Float(_) | Self::Decimal(_), _) | (_, Self::Float(_) | Self::Decimal(_)) =
+            (&self, &other)
+        {
             // convert num1 and num2 to float type
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Self::Float(f1 - f2);
         }
 
-        match self {
-            Self::Int(num1) => match other {
-                Self::Int(num2) => Self::Int(num1 - num2),
-                Self::Fraction(upper, lower) => Self::Fraction(num1 * lower - upper, lower)
-                    .reduce()
-                    .unwrap(),
-                _ => unreachable!(),
-            },
-            Self::Fraction(upper, lower) => match other {
-                Self::Int(num2) => Self::Fraction(upper - num2 * lower, lower)
-                    .reduce()
-                    .unwrap(),
-                Self::Fraction(other_upper, other_lower) => {
-                    let lower_lcm = Self::lcm(lower, other_lower);
-                    let self_factor = lower_lcm / lower;
-                    let other_factor = lower_lcm / other_lower;
-                    let res_upper = upper * self_factor - other_upper * other_factor;
-                    Self::Fraction(res_upper, lower_lcm).reduce().unwrap()
-                }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+        if let (Self::Int(num1), Self::Int(num2)) = (&self, &other) {
+            if let Some(result) = num1.checked_sub(*num2) {
+                return Self::Int(result);
+            }
         }
+
+        Self::exact_compute(
+            &self,
+            &other,
+            |u1, l1, u2, l2| {
+                let lower_lcm = Self::lcm(l1, l2);
+                let upper = u1 * (lower_lcm / l1) - u2 * (lower_lcm / l2);
+                (upper, lower_lcm)
+            },
+            |u1, l1, u2, l2| (&(u1 * l2) - &(u2 * l1), l1 * l2),
+        )
     }
 }
 
 impl Mul for Number {
     type Output = Self;
     fn mul(self, other: Self) -> Self {
-        if self == Self::NotANumber || other == Self::NotANumber {
+        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (&self, &other) {
             return Self::NotANumber;
         }
 
-        if let (Self::Float(_), _) | (_, Self::Float(_)) = (self, other) {
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (&self, &other) {
+            let z1 = self.complex_value();
+            let z2 = other.complex_value();
+            return Self::from_complex(z1 * z2);
+        }
+
+        if let Some((d1, d2, precision)) = Self::decimal_operands(&self, &other) {
+            return Self::Decimal(d1.mul(&d2, precision));
+        }
+        if let (Self::Float(_) | Self::Decimal(_), _) | (_, Self::Float(_) | Self::Decimal(_)) =
+            (&self, &other)
+        {
             // convert num1 and num2 to float type
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Self::Float(f1 * f2);
         }
 
-        match self {
-            Self::Int(num1) => match other {
-                Self::Int(num2) => Self::Int(num1 * num2),
-                Self::Fraction(upper, lower) => {
-                    Self::Fraction(num1 * upper, lower).reduce().unwrap()
-                }
-                _ => unreachable!(),
-            },
-            Self::Fraction(upper, lower) => match other {
-                Self::Int(num2) => Self::Fraction(upper * num2, lower).reduce().unwrap(),
-                Self::Fraction(other_upper, other_lower) => {
-                    let muled_upper = upper * other_upper;
-                    let muled_lower = lower * other_lower;
-                    Self::Fraction(muled_upper, muled_lower).reduce().unwrap()
-                }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+        if let (Self::Int(num1), Self::Int(num2)) = (&self, &other) {
+            if let Some(result) = num1.checked_mul(*num2) {
+                return Self::Int(result);
+            }
         }
+
+        Self::exact_compute(
+            &self,
+            &other,
+            |u1, l1, u2, l2| (u1 * u2, l1 * l2),
+            |u1, l1, u2, l2| (u1 * u2, l1 * l2),
+        )
     }
 }
 
 impl Div for Number {
     type Output = Self;
     fn div(self, other: Self) -> Self {
-        if self == Self::NotANumber || other == Self::NotANumber {
+        if let (Self::NotANumber, _) | (_, Self::NotANumber) = (&self, &other) {
             return Self::NotANumber;
         }
 
         // when the divisor is ZERO
-        if other.float_value() == 0.0 {
+        if other.is_zero() {
             math_error("the divisor should not to be ZERO").unwrap_err();
             return Number::NotANumber;
         }
 
         // when either `self` or `other` is float
-        if let (Self::Float(_), _) | (_, Self::Float(_)) = (self, other) {
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (&self, &other) {
+            let z1 = self.complex_value();
+            let z2 = other.complex_value();
+            return Self::from_complex(z1 / z2);
+        }
+
+        if let Some((d1, d2, precision)) = Self::decimal_operands(&self, &other) {
+            return Self::Decimal(d1.div(&d2, precision));
+        }
+        if let (Self::Float(_) | Self::Decimal(_), _) | (_, Self::Float(_) | Self::Decimal(_)) =
+            (&self, &other)
+        {
             // convert num1 and num2 to float type
             let f1 = self.float_value();
             let f2 = other.float_value();
@@ -346,24 +757,38 @@ impl Div for Number {
             return Self::Float(f1 / f2);
         }
 
-        match self {
-            Self::Int(num1) => match other {
-                Self::Int(num2) => Self::Int(num1 / num2),
-                Self::Fraction(upper, lower) => {
-                    Self::Fraction(num1 * lower, upper).reduce().unwrap()
-                }
-                _ => unreachable!(),
-            },
-            Self::Fraction(upper, lower) => match other {
-                Self::Int(num2) => Self::Fraction(upper, lower * num2).reduce().unwrap(),
-                Self::Fraction(other_upper, other_lower) => {
-                    Self::Fraction(upper * other_lower, lower * other_upper)
-                        .reduce()
-                        .unwrap()
+        let is_exact_mode = unsafe { ENV_OPTION.exact_mode };
+        let precision = unsafe { ENV_OPTION.decimal_precision };
+        match (&self, &other) {
+            // in decimal mode, the inexact quotient of integers is decimal
+            (Self::Int(_) | Self::BigInt(_), Self::Int(_) | Self::BigInt(_))
+                if !is_exact_mode && precision > 0 =>
+            {
+                let (num1, _) = self.big_ratio();
+                let (num2, _) = other.big_ratio();
+                match num1.div_rem(&num2) {
+                    (quotient, remainder) if remainder.is_zero() => {
+                        Self::from_big_ratio(quotient, BigInt::one())
+                    }
+                    _ => Self::Decimal(BigDecimal::from_ratio(&num1, &num2, precision)),
                 }
-                _ => unreachable!(),
+            }
+            // integer division, the result is truncated when not in exact mode
+            (Self::Int(num1), Self::Int(num2)) if !is_exact_mode => match num1.checked_div(*num2) {
+                Some(result) => Self::Int(result),
+                None => Self::from_big_ratio(-BigInt::from(*num1), BigInt::one()),
             },
-            _ => unreachable!(),
+            (Self::Int(_) | Self::BigInt(_), Self::Int(_) | Self::BigInt(_)) if !is_exact_mode => {
+                let (num1, _) = self.big_ratio();
+                let (num2, _) = other.big_ratio();
+                Self::from_big_ratio(num1.div_rem(&num2).0, BigInt::one())
+            }
+            _ => Self::exact_compute(
+                &self,
+                &other,
+                |u1, l1, u2, l2| (u1 * l2, l1 * u2),
+                |u1, l1, u2, l2| (u1 * l2, l1 * u2),
+            ),
         }
     }
 }
@@ -376,9 +801,27 @@ impl PartialOrd for Number {
             return None;
         }
 
-        let self_f = self.float_value();
-        let other_f = other.float_value();
-        return self_f.partial_cmp(&other_f);
+        // complex numbers are not ordered
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (self, other) {
+            return None;
+        }
+
+        if let (Self::Float(_), _) | (_, Self::Float(_)) = (self, other) {
+            let self_f = self.float_value();
+            let other_f = other.float_value();
+            return self_f.partial_cmp(&other_f);
+        }
+        if let (Self::Decimal(d1), Self::Decimal(d2)) = (self, other) {
+            return d1.partial_cmp(d2);
+        }
+
+        // the lowers are always positive
+        if let (Some((u1, l1)), Some((u2, l2))) = (self.small_ratio(), other.small_ratio()) {
+            return (u1 * l2).partial_cmp(&(u2 * l1));
+        }
+        let (u1, l1) = self.big_ratio();
+        let (u2, l2) = other.big_ratio();
+        return (&u1 * &l2).partial_cmp(&(&u2 * &l1));
     }
 }
 
@@ -388,25 +831,19 @@ impl PartialEq for Number {
             return false;
         }
 
+        if let (Self::Complex(_), _) | (_, Self::Complex(_)) = (self, other) {
+            let z1 = self.complex_value();
+            let z2 = other.complex_value();
+            return Self::float_cmp(z1.re, z2.re) && Self::float_cmp(z1.im, z2.im);
+        }
+
         if let (Self::Float(_), _) | (_, Self::Float(_)) = (self, other) {
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Self::float_cmp(f1, f2);
         }
 
-        match self {
-            Self::Int(num1) => match other {
-                Self::Int(num2) => *num1 == *num2,
-                Self::Fraction(_, _) => *num1 as f64 == other.float_value(),
-                _ => unreachable!(),
-            },
-            Self::Fraction(_, _) => match other {
-                Self::Int(num2) => self.int_value() == *num2,
-                Self::Fraction(_, _) => Self::float_cmp(self.float_value(), other.float_value()),
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
-        }
+        return self.partial_cmp(other) == Some(cmp::Ordering::Equal);
     }
     fn ne(&self, other: &Self) -> bool {
         !(self == other)
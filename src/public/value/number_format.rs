@@ -0,0 +1,201 @@
+use super::big_int::BigInt;
+use super::number::Number;
+
+// output format of Number typed value
+#[derive(PartialEq, Clone, Copy)]
+pub enum DisplayMode {
+    Default,
+    // fixed count of decimal places
+    Fixed(usize),
+    // count of significant digits
+    Significant(usize),
+    // count of decimal places of the mantissa
+    Scientific(usize),
+    Engineering(usize),
+    // fraction is output as mixed number, e.g. `1 1/2`
+    Mixed,
+    // integer is output in radix 16 | 2 | 8
+    Hex,
+    Bin,
+    Oct,
+}
+
+const DEFAULT_PRECISION: usize = 10;
+const MAX_PRECISION: usize = 100;
+
+impl DisplayMode {
+    // the mode spec is the mode name with optional precision,
+    // e.g. "default", "fixed:2", "sig:6", "sci", "eng:3", "mixed", "hex".
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (name, precision) = match spec.split_once(':') {
+            Some((name, precision)) => {
+                let Ok(precision) = precision.trim().parse::<usize>() else {
+                    return None;
+                };
+                (name.trim(), Some(precision))
+            }
+            None => (spec.trim(), None),
+        };
+        if precision > Some(MAX_PRECISION) {
+            return None;
+        }
+
+        let digits = precision.unwrap_or(DEFAULT_PRECISION);
+        let mode = match name {
+            "fixed" => Self::Fixed(digits),
+            "sig" if digits > 0 => Self::Significant(digits),
+            "sci" => Self::Scientific(digits),
+            "eng" => Self::Engineering(digits),
+
+            // modes without precision
+            _ if precision.is_some() => return None,
+            "default" => Self::Default,
+            "mixed" => Self::Mixed,
+            "hex" => Self::Hex,
+            "bin" => Self::Bin,
+            "oct" => Self::Oct,
+            _ => return None,
+        };
+        return Some(mode);
+    }
+}
+
+fn significant_format(num: f64, digits: usize) -> String {
+    // the exponent is taken from the rounded scientific output,
+    // so that the carry from rounding (e.g. 9.99 -> 10.0) is considered.
+    let sci_output = format!("{:.*e}", digits - 1, num);
+    let (_, exponent) = sci_output.split_once('e').unwrap();
+    let exponent = exponent.parse::<i32>().unwrap();
+
+    if exponent < -5 || exponent >= digits as i32 {
+        return sci_output;
+    }
+    let decimals = (digits as i32 - 1 - exponent).max(0) as usize;
+    return format!("{:.*}", decimals, num);
+}
+
+fn engineering_format(num: f64, digits: usize) -> String {
+    if num == 0.0 {
+        return format!("{:.*}e0", digits, num);
+    }
+
+    let mut exponent = num.abs().log10().floor() as i32;
+    exponent -= exponent.rem_euclid(3);
+    let mut mantissa = format!("{:.*}", digits, num / 10_f64.powi(exponent));
+    // the mantissa may reach 1000 after rounding
+    if mantissa.trim_start_matches('-').parse::<f64>().unwrap() >= 1000.0 {
+        exponent += 3;
+        mantissa = format!("{:.*}", digits, num / 10_f64.powi(exponent));
+    }
+    return format!("{}e{}", mantissa, exponent);
+}
+
+fn mixed_format(upper: &BigInt, lower: &BigInt) -> String {
+    let (whole, rest) = upper.div_rem(lower);
+    if whole.is_zero() {
+        return format!("{}/{}", rest, lower);
+    }
+    return format!("{} {}/{}", whole, rest.abs(), lower);
+}
+
+fn radix_format(num: &BigInt, radix: u32) -> String {
+    let prefix = match radix {
+        16 => "0x",
+        8 => "0o",
+        2 => "0b",
+        _ => unreachable!(),
+    };
+    let sign = if num.is_negative() { "-" } else { "" };
+    return format!("{}{}{}", sign, prefix, num.to_pow2_radix_string(radix));
+}
+
+impl Number {
+    pub fn format(&self, mode: DisplayMode) -> String {
+        if let Self::Float(num) = self {
+            if !num.is_finite() {
+                return num.to_string();
+            }
+        }
+
+        match (self, mode) {
+            (Self::NotANumber, _) => String::from("Not a Number"),
+            (Self::Complex(z), _) => {
+                // the part which is integral at the display precision is shown
+                // as integer, e.g. `(-1 + 0i)` instead of `(-1 + 0.0000000000i)`.
+                let part_format = |part: f64| {
+                    let rounded = (part * 1e10).round() / 1e10;
+                    let part = if rounded == rounded.floor() {
+                        rounded
+                    } else {
+                        part
+                    };
+                    return Self::Float(part).format(mode);
+                };
+                let sign = if z.im < 0.0 { '-' } else { '+' };
+                let re = part_format(z.re);
+                let im = part_format(z.im.abs());
+                format!("({} {} {}i)", re, sign, im)
+            }
+
+            (Self::Int(_) | Self::BigInt(_), DisplayMode::Fixed(digits)) => {
+                if digits == 0 {
+                    self.default_format()
+                } else {
+                    format!("{}.{}", self.default_format(), "0".repeat(digits))
+                }
+            }
+            (Self::Decimal(decimal), DisplayMode::Fixed(digits)) => decimal.to_fixed_string(digits),
+            (Self::Decimal(decimal), DisplayMode::Significant(digits)) => {
+                decimal.round(digits).to_string()
+            }
+            (_, DisplayMode::Fixed(digits)) => format!("{:.*}", digits, self.float_value()),
+            (_, DisplayMode::Significant(digits)) => significant_format(self.float_value(), digits),
+            (_, DisplayMode::Scientific(digits)) => format!("{:.*e}", digits, self.float_value()),
+            (_, DisplayMode::Engineering(digits)) => engineering_format(self.float_value(), digits),
+
+            (Self::Fraction(_, _) | Self::BigFraction(_, _), DisplayMode::Mixed) => {
+                let (upper, lower) = self.big_ratio();
+                mixed_format(&upper, &lower)
+            }
+            (
+                Self::Int(_) | Self::BigInt(_),
+                DisplayMode::Hex | DisplayMode::Bin | DisplayMode::Oct,
+            ) => {
+                let radix = match mode {
+                    DisplayMode::Hex => 16,
+                    DisplayMode::Bin => 2,
+                    DisplayMode::Oct => 8,
+                    _ => unreachable!(),
+                };
+                let (num, _) = self.big_ratio();
+                radix_format(&num, radix)
+            }
+            _ => self.default_format(),
+        }
+    }
+
+    fn default_format(&self) -> String {
+        match self {
+            Self::Int(num) => num.to_string(),
+            Self::Float(num) => {
+                // optimize float number output
+                // example:
+                // 1.0000000000 -> 1
+                if *num == num.floor() {
+                    if num.abs() < i64::MAX as f64 {
+                        (*num as i64).to_string()
+                    } else {
+                        format!("{:e}", num)
+                    }
+                } else {
+                    format!("{:.10}", num)
+                }
+            }
+            Self::Fraction(upper, lower) => format!("({} / {})", upper, lower),
+            Self::BigInt(num) => num.to_string(),
+            Self::BigFraction(upper, lower) => format!("({} / {})", upper, lower),
+            Self::Decimal(decimal) => decimal.to_string(),
+            Self::NotANumber | Self::Complex(_) => unreachable!(),
+        }
+    }
+}
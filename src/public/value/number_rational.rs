@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+
+use crate::public::env::RoundingMode;
+
+use super::big_int::BigInt;
+use super::number::Number;
+
+// the exact `(upper, lower)` of finite float,
+// the lower is always power of 2.
+fn float_ratio(num: f64) -> (BigInt, BigInt) {
+    let bits = num.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let mut mantissa = (bits & ((1 << 52) - 1)) as i64;
+    if exponent != 0 {
+        // the hidden bit of normal float
+        mantissa |= 1 << 52;
+    }
+    if num < 0.0 {
+        mantissa = -mantissa;
+    }
+
+    let shift = exponent.max(1) - 1075;
+    let upper = BigInt::from(mantissa);
+    if shift >= 0 {
+        return (upper.shl(shift as u32), BigInt::one());
+    }
+    return (upper, BigInt::one().shl((-shift) as u32));
+}
+
+// floor division, the remainder is never negative
+// when the divisor is positive.
+fn floor_div_rem(dividend: &BigInt, divisor: &BigInt) -> (BigInt, BigInt) {
+    let (quotient, remainder) = dividend.div_rem(divisor);
+    if remainder.is_negative() {
+        return (&quotient - &BigInt::one(), &remainder + divisor);
+    }
+    return (quotient, remainder);
+}
+
+impl Number {
+    // the exact `(upper, lower)` of real Number
+    fn exact_ratio(&self) -> Option<(BigInt, BigInt)> {
+        match self {
+            Self::Float(num) if num.is_finite() => Some(float_ratio(*num)),
+            Self::Float(_) | Self::Complex(_) | Self::NotANumber => None,
+            _ => Some(self.big_ratio()),
+        }
+    }
+
+    // best rational approximation whose lower is not greater than `max_lower`.
+    // when `max_lower` is None, float number is converted to
+    // the simplest fraction whose float value is equal to it.
+    pub fn rationalize(&self, max_lower: Option<i64>) -> Self {
+        let Some((upper, lower)) = self.exact_ratio() else {
+            return Self::NotANumber;
+        };
+        let max_lower = max_lower.map(BigInt::from);
+        match &max_lower {
+            Some(max) if lower <= *max => return Self::from_big_ratio(upper, lower),
+            None if !matches!(self, Self::Float(_)) => return self.clone(),
+            _ => {}
+        }
+        let target = self.float_value();
+
+        // (p0 / q0) and (p1 / q1) are the last two convergents
+        let (mut p0, mut q0) = (BigInt::zero(), BigInt::one());
+        let (mut p1, mut q1) = (BigInt::one(), BigInt::zero());
+        let (mut dividend, mut divisor) = (upper.clone(), lower.clone());
+        loop {
+            let (term, remainder) = floor_div_rem(&dividend, &divisor);
+            let q2 = &q0 + &(&term * &q1);
+            if let Some(max) = &max_lower {
+                if q2 > *max {
+                    break;
+                }
+            }
+            let p2 = &p0 + &(&term * &p1);
+
+            let is_equal = max_lower.is_none() && BigInt::ratio_to_f64(&p2, &q2) == target;
+            if is_equal {
+                // the simplest fraction may be a semiconvergent before the convergent,
+                // the semiconvergents `(p0 + k * p1) / (q0 + k * q1)` approach the target
+                // monotonically, so the smallest `k` equal to the float is searched by bisection.
+                let semiconvergent = |k: &BigInt| (&p0 + &(k * &p1), &q0 + &(k * &q1));
+                let (mut low, mut high) = (BigInt::one(), term);
+                while low < high {
+                    let mid = (&low + &high).shr(1);
+                    let (p, q) = semiconvergent(&mid);
+                    if BigInt::ratio_to_f64(&p, &q) == target {
+                        high = mid;
+                    } else {
+                        low = &mid + &BigInt::one();
+                    }
+                }
+                let (p, q) = semiconvergent(&high);
+                return Self::from_big_ratio(p, q);
+            }
+            if remainder.is_zero() {
+                return Self::from_big_ratio(p2, q2);
+            }
+            (p0, q0) = (p1, q1);
+            (p1, q1) = (p2, q2);
+            (dividend, divisor) = (divisor, remainder);
+        }
+
+        // the best approximation is either the last convergent
+        // or the semiconvergent with the largest lower.
+        let max = max_lower.unwrap();
+        let (k, _) = (&max - &q0).div_rem(&q1);
+        let p2 = &p0 + &(&k * &p1);
+        let q2 = &q0 + &(&k * &q1);
+
+        // compare |p / q - upper / lower| by cross multiplication
+        let distance1 = (&(&p1 * &lower) - &(&upper * &q1)).abs();
+        let distance2 = (&(&p2 * &lower) - &(&upper * &q2)).abs();
+        if &distance1 * &q2 <= &distance2 * &q1 {
+            return Self::from_big_ratio(p1, q1);
+        }
+        return Self::from_big_ratio(p2, q2);
+    }
+
+    // exact quotient of two real Numbers,
+    // float operands are converted to the simplest fraction at first.
+    pub fn exact_quotient(&self, divisor: &Self) -> Self {
+        let dividend_ratio = self.rationalize(None).exact_ratio();
+        let divisor_ratio = divisor.rationalize(None).exact_ratio();
+        let (Some((u1, l1)), Some((u2, l2))) = (dividend_ratio, divisor_ratio) else {
+            return Self::NotANumber;
+        };
+        return Self::from_big_ratio(&u1 * &l2, &l1 * &u2);
+    }
+
+    // quotient without the integer truncation,
+    // it is exact when both the operands are rational.
+    pub fn true_div(&self, divisor: &Self) -> Self {
+        let is_inexact =
+            |num: &Self| matches!(num, Self::Float(_) | Self::Decimal(_) | Self::Complex(_));
+        if is_inexact(self) || is_inexact(divisor) || divisor.is_zero() {
+            return self.clone() / divisor.clone();
+        }
+        return self.exact_quotient(divisor);
+    }
+
+    // the count of `1 / units` nearest to the real Number in `mode`,
+    // e.g. the count of cents with `units` 100.
+    // float number is converted to the simplest fraction at first,
+    // and the ties are rounded away from ZERO in `Unrounded` mode.
+    pub fn round_units(&self, units: i64, mode: RoundingMode) -> Option<Self> {
+        let (upper, lower) = self.rationalize(None).exact_ratio()?;
+        let is_negative = upper.is_negative();
+        let (quotient, remainder) = (&upper * &BigInt::from(units)).div_floor(&lower);
+        let ceiling = &quotient + &BigInt::one();
+        let is_even = quotient.div_rem(&BigInt::from(2_i64)).1.is_zero();
+
+        // compare the remainder with the half of lower
+        let half_cmp = (&remainder + &remainder).cmp(&lower);
+        let is_up = match mode {
+            _ if remainder.is_zero() => false,
+            RoundingMode::Ceiling => true,
+            RoundingMode::Floor => false,
+            RoundingMode::Up => !is_negative,
+            RoundingMode::Down => is_negative,
+            _ if half_cmp != Ordering::Equal => half_cmp == Ordering::Greater,
+            RoundingMode::HalfUp | RoundingMode::Unrounded => !is_negative,
+            RoundingMode::HalfDown => is_negative,
+            RoundingMode::HalfEven => !is_even,
+        };
+        let result = if is_up { ceiling } else { quotient };
+        return Some(Self::from_big_ratio(result, BigInt::one()));
+    }
+
+    // exact square root of non-negative rational Number,
+    // returns None when the root is not rational.
+    pub fn exact_sqrt(&self) -> Option<Self> {
+        let int_sqrt = |num: i128| -> Option<i128> {
+            if num < 0 {
+                return None;
+            }
+            let square = |n: i128| n.checked_mul(n).unwrap_or(i128::MAX);
+            let mut root = (num as f64).sqrt() as i128;
+            while square(root) > num {
+                root -= 1;
+            }
+            while square(root + 1) <= num {
+                root += 1;
+            }
+            return if square(root) == num {
+                Some(root)
+            } else {
+                None
+            };
+        };
+        if let Self::Float(_) | Self::Decimal(_) | Self::Complex(_) | Self::NotANumber = self {
+            return None;
+        }
+        let (upper, lower) = self.exact_ratio()?;
+        let upper_root = int_sqrt(upper.to_i128()?)?;
+        let lower_root = int_sqrt(lower.to_i128()?)?;
+        return Some(Self::from_small_ratio(upper_root, lower_root));
+    }
+
+    // terms of the continued fraction, at most `max_terms` terms,
+    // float number is converted to the simplest fraction at first.
+    pub fn continued_fraction(&self, max_terms: usize) -> Option<Vec<Self>> {
+        let (mut dividend, mut divisor) = self.rationalize(None).exact_ratio()?;
+
+        let mut terms = vec![];
+        while terms.len() < max_terms {
+            let (term, remainder) = floor_div_rem(&dividend, &divisor);
+            terms.push(Self::from_big_ratio(term, BigInt::one()));
+
+            if remainder.is_zero() {
+                break;
+            }
+            (dividend, divisor) = (divisor, remainder);
+        }
+        return Some(terms);
+    }
+}
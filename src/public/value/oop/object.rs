@@ -74,6 +74,24 @@ impl Object {
         }
     }
 
+    // the descriptions of the properties for the REPL completion and `:help`
+    pub fn describe_props(&mut self, descriptions: Vec<(&str, String)>) {
+        let Self::BuildIn(BuildInObject {
+            completer: Some(completer),
+            ..
+        }) = self
+        else {
+            return;
+        };
+        // the completer is not shared yet when the object is just built
+        let Some(completer) = Rc::get_mut(completer) else {
+            return;
+        };
+        for (prop_name, description) in descriptions {
+            completer.describe(prop_name, description);
+        }
+    }
+
     pub fn get(&self, prop_name: &str) -> Result<Value, ()> {
         let store = self.get_store();
         let target_value_result = store.getter(prop_name);
@@ -0,0 +1,411 @@
+use std::fmt;
+
+use crate::public::env::ENV_OPTION;
+use crate::public::error::{dimension_error, syntax_error, type_error};
+
+use super::number::Number;
+use super::value::{Value, ValueType};
+
+// the exponents of the SI base units:
+// metre, kilogram, second, ampere, kelvin, mole, candela
+pub type Dimension = [i8; 7];
+pub const DIMENSIONLESS: Dimension = [0; 7];
+const BASE_UNITS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+// (name, factor to SI base units, dimension, accepts prefixes)
+type UnitDef = (&'static str, f64, Dimension, bool);
+const UNITS: [UnitDef; 46] = [
+    // SI base units, the gram is used instead of kilogram
+    // so that the prefixes can be applied.
+    ("m", 1.0, [1, 0, 0, 0, 0, 0, 0], true),
+    ("g", 1e-3, [0, 1, 0, 0, 0, 0, 0], true),
+    ("s", 1.0, [0, 0, 1, 0, 0, 0, 0], true),
+    ("A", 1.0, [0, 0, 0, 1, 0, 0, 0], true),
+    ("K", 1.0, [0, 0, 0, 0, 1, 0, 0], true),
+    ("mol", 1.0, [0, 0, 0, 0, 0, 1, 0], true),
+    ("cd", 1.0, [0, 0, 0, 0, 0, 0, 1], true),
+    // SI derived units
+    ("N", 1.0, [1, 1, -2, 0, 0, 0, 0], true),
+    ("Pa", 1.0, [-1, 1, -2, 0, 0, 0, 0], true),
+    ("J", 1.0, [2, 1, -2, 0, 0, 0, 0], true),
+    ("W", 1.0, [2, 1, -3, 0, 0, 0, 0], true),
+    ("C", 1.0, [0, 0, 1, 1, 0, 0, 0], true),
+    ("V", 1.0, [2, 1, -3, -1, 0, 0, 0], true),
+    ("F", 1.0, [-2, -1, 4, 2, 0, 0, 0], true),
+    ("ohm", 1.0, [2, 1, -3, -2, 0, 0, 0], true),
+    ("S", 1.0, [-2, -1, 3, 2, 0, 0, 0], true),
+    ("Wb", 1.0, [2, 1, -2, -1, 0, 0, 0], true),
+    ("T", 1.0, [0, 1, -2, -1, 0, 0, 0], true),
+    ("H", 1.0, [2, 1, -2, -2, 0, 0, 0], true),
+    ("Hz", 1.0, [0, 0, -1, 0, 0, 0, 0], true),
+    ("L", 1e-3, [3, 0, 0, 0, 0, 0, 0], true),
+    // non-SI units accepted for use with SI
+    ("min", 60.0, [0, 0, 1, 0, 0, 0, 0], false),
+    ("h", 3600.0, [0, 0, 1, 0, 0, 0, 0], false),
+    ("day", 86400.0, [0, 0, 1, 0, 0, 0, 0], false),
+    ("week", 604800.0, [0, 0, 1, 0, 0, 0, 0], false),
+    ("t", 1000.0, [0, 1, 0, 0, 0, 0, 0], false),
+    ("ha", 1e4, [2, 0, 0, 0, 0, 0, 0], false),
+    ("eV", 1.602176634e-19, [2, 1, -2, 0, 0, 0, 0], true),
+    ("Wh", 3600.0, [2, 1, -2, 0, 0, 0, 0], true),
+    ("cal", 4.184, [2, 1, -2, 0, 0, 0, 0], true),
+    ("bar", 1e5, [-1, 1, -2, 0, 0, 0, 0], true),
+    ("atm", 101325.0, [-1, 1, -2, 0, 0, 0, 0], false),
+    // imperial and US customary units
+    ("in", 0.0254, [1, 0, 0, 0, 0, 0, 0], false),
+    ("ft", 0.3048, [1, 0, 0, 0, 0, 0, 0], false),
+    ("yd", 0.9144, [1, 0, 0, 0, 0, 0, 0], false),
+    ("mi", 1609.344, [1, 0, 0, 0, 0, 0, 0], false),
+    ("nmi", 1852.0, [1, 0, 0, 0, 0, 0, 0], false),
+    ("ac", 4046.8564224, [2, 0, 0, 0, 0, 0, 0], false),
+    ("gal", 3.785411784e-3, [3, 0, 0, 0, 0, 0, 0], false),
+    ("oz", 0.028349523125, [0, 1, 0, 0, 0, 0, 0], false),
+    ("lb", 0.45359237, [0, 1, 0, 0, 0, 0, 0], false),
+    ("lbf", 4.4482216152605, [1, 1, -2, 0, 0, 0, 0], false),
+    ("psi", 6894.757293168361, [-1, 1, -2, 0, 0, 0, 0], false),
+    ("hp", 745.6998715822702, [2, 1, -3, 0, 0, 0, 0], false),
+    ("mph", 0.44704, [1, 0, -1, 0, 0, 0, 0], false),
+    ("kn", 1852.0 / 3600.0, [1, 0, -1, 0, 0, 0, 0], false),
+];
+
+const PREFIXES: [(&str, f64); 21] = [
+    // the two-letter prefix is checked first
+    ("da", 1e1),
+    ("Y", 1e24),
+    ("Z", 1e21),
+    ("E", 1e18),
+    ("P", 1e15),
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+    ("f", 1e-15),
+    ("a", 1e-18),
+    ("z", 1e-21),
+    ("y", 1e-24),
+    ("μ", 1e-6),
+];
+
+// the derived unit names used to display the quantity
+// whose dimension matches exactly.
+const DISPLAY_UNITS: [&str; 10] = ["N", "Pa", "J", "W", "C", "V", "F", "ohm", "Wb", "T"];
+
+fn find_simple_unit(name: &str) -> Option<(f64, Dimension)> {
+    let find_exact = |name: &str, need_prefixable: bool| {
+        UNITS
+            .iter()
+            .find(|unit| unit.0 == name && (unit.3 || !need_prefixable))
+            .map(|unit| (unit.1, unit.2))
+    };
+
+    if let Some(unit) = find_exact(name, false) {
+        return Some(unit);
+    }
+    for (prefix, prefix_factor) in PREFIXES {
+        let Some(rest) = name.strip_prefix(prefix) else {
+            continue;
+        };
+        if let Some((factor, dim)) = find_exact(rest, true) {
+            return Some((prefix_factor * factor, dim));
+        }
+    }
+    return None;
+}
+
+// the recursive descent parser of unit expression like `kg*m/s^2`,
+// the units are separated by `*`, `.` or `/`, and `^` is followed by an integer.
+struct UnitParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> UnitParser<'a> {
+    fn skip_space(&mut self) {
+        while let Some(' ' | '\t') = self.chars.peek() {
+            self.chars.next();
+        }
+    }
+
+    fn expression(&mut self) -> Option<(f64, Dimension)> {
+        let (mut factor, mut dim) = self.term()?;
+        loop {
+            self.skip_space();
+            let is_divide = match self.chars.peek() {
+                Some('*' | '.') => false,
+                Some('/') => true,
+                _ => return Some((factor, dim)),
+            };
+            self.chars.next();
+
+            let (term_factor, term_dim) = self.term()?;
+            if is_divide {
+                factor /= term_factor;
+                dim = dim_combine(&dim, &term_dim, -1);
+            } else {
+                factor *= term_factor;
+                dim = dim_combine(&dim, &term_dim, 1);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Option<(f64, Dimension)> {
+        self.skip_space();
+        let (factor, dim) = match self.chars.peek()? {
+            '(' => {
+                self.chars.next();
+                let result = self.expression()?;
+                self.skip_space();
+                if self.chars.next()? != ')' {
+                    return None;
+                }
+                result
+            }
+            // the `1` in `1/s`
+            '1' => {
+                self.chars.next();
+                (1.0, DIMENSIONLESS)
+            }
+            _ => {
+                let mut name = String::new();
+                while let Some(&ch) = self.chars.peek() {
+                    if !ch.is_alphabetic() {
+                        break;
+                    }
+                    name.push(ch);
+                    self.chars.next();
+                }
+                find_simple_unit(&name)?
+            }
+        };
+
+        self.skip_space();
+        if self.chars.peek() != Some(&'^') {
+            return Some((factor, dim));
+        }
+        self.chars.next();
+        self.skip_space();
+
+        let mut exp_text = String::new();
+        if self.chars.peek() == Some(&'-') {
+            exp_text.push('-');
+            self.chars.next();
+        }
+        while let Some(&ch) = self.chars.peek() {
+            if !ch.is_ascii_digit() {
+                break;
+            }
+            exp_text.push(ch);
+            self.chars.next();
+        }
+        let exp = exp_text.parse::<i8>().ok()?;
+        return Some((factor.powi(exp as i32), dim_scale(&dim, exp)));
+    }
+}
+
+pub fn dim_combine(dim1: &Dimension, dim2: &Dimension, sign: i8) -> Dimension {
+    let mut result = *dim1;
+    for (exp, other) in result.iter_mut().zip(dim2) {
+        *exp = exp.saturating_add(other.saturating_mul(sign));
+    }
+    return result;
+}
+pub fn dim_scale(dim: &Dimension, times: i8) -> Dimension {
+    return dim.map(|exp| exp.saturating_mul(times));
+}
+
+// the quotient without integer truncation,
+// the fraction result is only kept in exact mode.
+pub fn quotient(dividend: Number, divisor: &Number) -> Number {
+    let result = dividend.true_div(divisor);
+    if unsafe { ENV_OPTION.exact_mode } {
+        return result;
+    }
+    let precision = unsafe { ENV_OPTION.decimal_precision };
+    return match result {
+        Number::Fraction(_, _) | Number::BigFraction(_, _) if precision > 0 => {
+            Number::Decimal(result.decimal_value(precision).unwrap())
+        }
+        Number::Fraction(_, _) | Number::BigFraction(_, _) => result.float(),
+        _ => result,
+    };
+}
+
+// the text of the dimension composed of base units, e.g. `kg*m/s^2`
+pub fn dim_text(dim: &Dimension) -> String {
+    if let Some(name) = DISPLAY_UNITS
+        .iter()
+        .find(|name| find_simple_unit(name).map(|unit| unit.1) == Some(*dim))
+    {
+        return name.to_string();
+    }
+
+    let unit_power = |name: &str, exp: i8| match exp {
+        1 => name.to_string(),
+        _ => format!("{}^{}", name, exp),
+    };
+    let mut numerator = vec![];
+    let mut denominator = vec![];
+    for (name, exp) in BASE_UNITS.iter().zip(dim) {
+        if *exp > 0 {
+            numerator.push(unit_power(name, *exp));
+        } else if *exp < 0 {
+            denominator.push(unit_power(name, -exp));
+        }
+    }
+
+    let numerator_text = match numerator.is_empty() {
+        true => String::from("1"),
+        false => numerator.join("*"),
+    };
+    return match denominator.len() {
+        0 => numerator_text,
+        1 => format!("{}/{}", numerator_text, denominator[0]),
+        _ => format!("{}/({})", numerator_text, denominator.join("*")),
+    };
+}
+
+// the unit used to display the quantity
+#[derive(PartialEq, Clone)]
+pub struct Unit {
+    pub name: String,
+    pub factor: f64,
+    pub dim: Dimension,
+}
+
+impl Unit {
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let mut parser = UnitParser {
+            chars: text.chars().peekable(),
+        };
+        let (factor, dim) = parser.expression()?;
+        if parser.chars.next().is_some() || !factor.is_finite() || factor == 0.0 {
+            return None;
+        }
+        return Some(Self {
+            name: text.to_string(),
+            factor,
+            dim,
+        });
+    }
+
+    // the factor as Number, integer factor is kept exact
+    fn factor_number(&self) -> Number {
+        if self.factor.fract() == 0.0 && self.factor.abs() < 1e15 {
+            return Number::Int(self.factor as i64);
+        }
+        return Number::Float(self.factor);
+    }
+}
+
+// the physical quantity, the value is stored in SI base units.
+#[derive(Clone)]
+pub struct Quantity {
+    value: Number,
+    dim: Dimension,
+    unit: Option<Unit>,
+}
+
+impl Quantity {
+    // the quantity measured in the unit
+    pub fn new(value: Number, unit: Unit) -> Self {
+        let si_value = if unit.factor == 1.0 {
+            value
+        } else {
+            value * unit.factor_number()
+        };
+        return Self {
+            value: si_value,
+            dim: unit.dim,
+            unit: Some(unit),
+        };
+    }
+    pub fn from_si(value: Number, dim: Dimension, unit: Option<Unit>) -> Self {
+        // the unit is discarded when the dimension changes
+        let unit = unit.filter(|unit| unit.dim == dim);
+        return Self { value, dim, unit };
+    }
+
+    #[inline]
+    pub fn value(&self) -> &Number {
+        &self.value
+    }
+    #[inline]
+    pub fn dim(&self) -> &Dimension {
+        &self.dim
+    }
+    #[inline]
+    pub fn unit(&self) -> Option<&Unit> {
+        self.unit.as_ref()
+    }
+
+    // returns None when the dimension of the unit is different
+    pub fn convert(&self, unit: Unit) -> Option<Self> {
+        if unit.dim != self.dim {
+            return None;
+        }
+        return Some(Self {
+            value: self.value.clone(),
+            dim: self.dim,
+            unit: Some(unit),
+        });
+    }
+
+    // the value measured in the display unit
+    pub fn display_value(&self) -> Number {
+        match &self.unit {
+            Some(unit) if unit.factor != 1.0 => quotient(self.value.clone(), &unit.factor_number()),
+            _ => self.value.clone(),
+        }
+    }
+    pub fn unit_text(&self) -> String {
+        match &self.unit {
+            Some(unit) => unit.name.clone(),
+            None => dim_text(&self.dim),
+        }
+    }
+}
+
+impl PartialEq for Quantity {
+    fn eq(&self, other: &Self) -> bool {
+        return self.dim == other.dim && self.value == other.value;
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.display_value(), self.unit_text())
+    }
+}
+
+// attaches the unit to the Number or converts the Quantity to the unit
+pub fn convert_value(value: &Value, unit_text: &str) -> Result<Value, ()> {
+    let Some(unit) = Unit::parse(unit_text) else {
+        let msg = format!("unknown unit \"{}\"", unit_text.trim());
+        return Err(syntax_error(&msg)?);
+    };
+    let result = match value {
+        Value::Number(num) => Quantity::new(num.clone(), unit),
+        Value::Quantity(qty) => {
+            let target_text = dim_text(&unit.dim);
+            let Some(result) = qty.convert(unit) else {
+                return Err(dimension_error(&target_text, &dim_text(qty.dim()))?);
+            };
+            result
+        }
+        _ => {
+            return Err(type_error(
+                Some("unit conversion"),
+                vec![ValueType::Number, ValueType::Quantity],
+                value.get_type(),
+            )?)
+        }
+    };
+    return Ok(Value::from(result));
+}
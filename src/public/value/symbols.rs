@@ -8,6 +8,7 @@ pub enum Symbols {
     Minus,
     Multiply,
     Divide,
+    FloorDivide,
     Mod,
     Power,
 
@@ -22,6 +23,24 @@ pub enum Symbols {
     AndSign,
     OrSign,
 
+    // bitwise operators
+    BitAnd,
+    BitOr,
+    BitXor,
+    BitNot,
+    ShiftLeft,
+    ShiftRight,
+
+    // postfix operators: `5!` and `20%`
+    Factorial,
+    Percent,
+
+    // interval operator: `1.25 ± 0.05` or `1.25 +/- 0.05`
+    PlusMinus,
+
+    // unit attaching and converting: `->`
+    Convert,
+
     Equal,
     PlusEqual,
     MinusEqual,
@@ -32,7 +51,25 @@ pub enum Symbols {
     ObjectReading,
 }
 
+// the bitwise operators spelled as words,
+// since `&`, `|`, `^` and `!` are used as logical and power operators.
+pub const WORD_SYMBOLS: [(&str, Symbols); 4] = [
+    ("band", Symbols::BitAnd),
+    ("bor", Symbols::BitOr),
+    ("bxor", Symbols::BitXor),
+    ("bnot", Symbols::BitNot),
+];
+
 impl Symbols {
+    pub fn is_word_symbol(word: &str) -> Option<Self> {
+        for (symbol_word, symbol) in WORD_SYMBOLS {
+            if word == symbol_word {
+                return Some(symbol);
+            }
+        }
+        return None;
+    }
+
     pub fn combine(&self, other: Symbols) -> Result<Self, ()> {
         // example:
         //    let equal_symbol = Symbols::Equal;
@@ -124,12 +161,23 @@ impl fmt::Display for Symbols {
             Self::Minus => write!(f, "Minus"),
             Self::Multiply => write!(f, "Multiply"),
             Self::Divide => write!(f, "Divide"),
+            Self::FloorDivide => write!(f, "FloorDivide"),
             Self::Mod => write!(f, "Mod"),
             Self::Power => write!(f, "Power"),
 
             Self::Not => write!(f, "Not"),
             Self::AndSign => write!(f, "And"),
             Self::OrSign => write!(f, "Or"),
+            Self::BitAnd => write!(f, "BitAnd"),
+            Self::BitOr => write!(f, "BitOr"),
+            Self::BitXor => write!(f, "BitXor"),
+            Self::BitNot => write!(f, "BitNot"),
+            Self::ShiftLeft => write!(f, "ShiftLeft"),
+            Self::ShiftRight => write!(f, "ShiftRight"),
+            Self::Factorial => write!(f, "Factorial"),
+            Self::Percent => write!(f, "Percent"),
+            Self::PlusMinus => write!(f, "PlusMinus"),
+            Self::Convert => write!(f, "Convert"),
 
             Self::LessThan => write!(f, "LessThan"),
             Self::MoreThan => write!(f, "MoreThan"),
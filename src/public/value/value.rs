@@ -7,14 +7,20 @@ use crossterm::style::Stylize;
 
 use crate::public::env::ENV_OPTION;
 use crate::public::error::{internal_error, InternalComponent};
+use crate::public::std::modules::polynomial::PolynomialModule;
+use crate::public::std::symbolic::display::lazy_expr_display;
 
 use super::super::compile_time::ast::ast_enum::ASTNode;
 use super::array::{ArrayLiteral, RawArray};
+use super::complex::Complex;
 use super::function::{BuildInFunction, Function, UserDefinedFunction};
+use super::interval::Interval;
 use super::map::RawMap;
+use super::matrix::Matrix;
 use super::number::Number;
 use super::oop::class::Class;
 use super::oop::object::Object;
+use super::quantity::Quantity;
 use super::unique::Unique;
 use super::{into_rc_refcell, ComplexStructure, GetAddr};
 
@@ -25,11 +31,14 @@ pub enum ValueType {
 
     Boolean,
     Number,
+    Quantity,
+    Interval,
     Unique,
 
     String,
     Array,
     Map,
+    Matrix,
     LazyExpression,
 
     Function,
@@ -59,6 +68,16 @@ impl ValueType {
                     ("number", ValueType::Number),
                     ("Number", ValueType::Number),
                     // --- --- --- --- --- ---
+                    ("qty", ValueType::Quantity),
+                    ("Qty", ValueType::Quantity),
+                    ("quantity", ValueType::Quantity),
+                    ("Quantity", ValueType::Quantity),
+                    // --- --- --- --- --- ---
+                    ("itv", ValueType::Interval),
+                    ("Itv", ValueType::Interval),
+                    ("interval", ValueType::Interval),
+                    ("Interval", ValueType::Interval),
+                    // --- --- --- --- --- ---
                     ("uni", ValueType::Unique),
                     ("Uni", ValueType::Unique),
                     ("unique", ValueType::Unique),
@@ -77,6 +96,11 @@ impl ValueType {
                     ("map", ValueType::Map),
                     ("Map", ValueType::Map),
                     // --- --- --- --- --- ---
+                    ("mat", ValueType::Matrix),
+                    ("Mat", ValueType::Matrix),
+                    ("matrix", ValueType::Matrix),
+                    ("Matrix", ValueType::Matrix),
+                    // --- --- --- --- --- ---
                     ("lExpr", ValueType::LazyExpression),
                     ("LazyExpr", ValueType::LazyExpression),
                     // --- --- --- --- --- ---
@@ -117,10 +141,13 @@ impl fmt::Display for ValueType {
             ValueType::Void => write!(f, "Void"),
             ValueType::Boolean => write!(f, "Boolean"),
             ValueType::Number => write!(f, "Number"),
+            ValueType::Quantity => write!(f, "Quantity"),
+            ValueType::Interval => write!(f, "Interval"),
             ValueType::Unique => write!(f, "Unique"),
             ValueType::String => write!(f, "String"),
             ValueType::Array => write!(f, "Array"),
             ValueType::Map => write!(f, "Map"),
+            ValueType::Matrix => write!(f, "Matrix"),
             ValueType::LazyExpression => write!(f, "LazyExpression"),
             ValueType::Function => write!(f, "Function"),
             ValueType::Class => write!(f, "Class"),
@@ -146,10 +173,13 @@ pub enum Value {
 
     Boolean(bool),
     Number(Number),
+    Quantity(Quantity),
+    Interval(Interval),
     Unique(Unique),
     String(Rc<RefCell<String>>),
     Array(Rc<RefCell<RawArray>>),
     Map(Rc<RefCell<RawMap>>),
+    Matrix(Rc<RefCell<Matrix>>),
     LazyExpression(Rc<RefCell<ASTNode>>),
 
     Function(Function),
@@ -198,13 +228,26 @@ impl Value {
         };
         return Ok(num.float_value());
     }
+    pub fn get_complex(&self) -> Result<Complex, ()> {
+        // expected Number typed value to call this method
+        let Self::Number(num) = self else {
+            return Err(internal_error(
+                InternalComponent::InternalFn,
+                "invalid `Value::get_complex` invocation",
+            )?);
+        };
+        return Ok(num.complex_value());
+    }
     pub fn get_bool(&self) -> bool {
         match self {
             Self::Boolean(bool_val) => *bool_val,
             Self::Number(num) => *num != Number::Int(0),
+            Self::Quantity(qty) => !qty.value().is_zero(),
+            Self::Interval(itv) => itv.lower() != 0.0 || itv.upper() != 0.0,
             Self::String(str) => str.as_ref().borrow().len() > 0,
             Self::Array(arr) => arr.as_ref().borrow().len() > 0,
             Self::Map(map) => map.as_ref().borrow().len() > 0,
+            Self::Matrix(mat) => mat.as_ref().borrow().rows() > 0,
 
             Self::Void(_) => false,
             Self::LazyExpression(_)
@@ -232,15 +275,20 @@ impl Value {
             Self::Void(_) => self.to_string(),
             Self::Boolean(bool_val) => bool_val.to_string(),
             Self::Number(num) => num.to_string(),
+            Self::Quantity(qty) => qty.to_string(),
+            Self::Interval(itv) => itv.to_string(),
             Self::Unique(uni) => uni.get_identi().to_string(),
             Self::String(str) => str.as_ref().borrow().clone(),
             Self::Function(func) => func.to_string(),
             Self::Array(arr) => arr.as_ref().borrow().join(", "),
 
             Self::Map(_) => String::from("<Map>"),
-            Self::LazyExpression(_) => String::from("<Lazy-Expression>"),
+            Self::Matrix(_) => String::from("<Matrix>"),
+            Self::LazyExpression(l_expr) => lazy_expr_text(&l_expr.borrow()),
             Self::Class(_) => String::from("<Class>"),
-            Self::Object(_) => String::from("<Object>"),
+            Self::Object(_) => {
+                PolynomialModule::display_text(self).unwrap_or(String::from("<Object>"))
+            }
         }
     }
 
@@ -255,11 +303,15 @@ impl Value {
             // can be directly cloned.
             Self::Boolean(_)
             | Self::Number(_)
+            | Self::Quantity(_)
+            | Self::Interval(_)
             // Unique, Function and Class can not be modified,
             // can just clone their Rc.
             | Self::Unique(_)
             | Self::Function(_)
-            | Self::Class(_) => self.clone(),
+            | Self::Class(_)
+            // Matrix has no method to modify its elements.
+            | Self::Matrix(_) => self.clone(),
 
             Self::String(str) => {
                 let cloned_str = str.as_ref().borrow().clone();
@@ -294,10 +346,13 @@ impl Value {
 
             Self::Boolean(_) => ValueType::Boolean,
             Self::Number(_) => ValueType::Number,
+            Self::Quantity(_) => ValueType::Quantity,
+            Self::Interval(_) => ValueType::Interval,
             Self::Unique(_) => ValueType::Unique,
             Self::String(_) => ValueType::String,
             Self::Array(_) => ValueType::Array,
             Self::Map(_) => ValueType::Map,
+            Self::Matrix(_) => ValueType::Matrix,
             Self::LazyExpression(_) => ValueType::LazyExpression,
 
             Self::Function(_) => ValueType::Function,
@@ -314,6 +369,12 @@ impl Value {
     }
 }
 
+// the infix text of lazy expression,
+// e.g. `{x ^ 2 + 1}`.
+fn lazy_expr_text(node: &ASTNode) -> String {
+    return lazy_expr_display(node).unwrap_or(String::from("<Lazy-Expression>"));
+}
+
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -327,7 +388,12 @@ impl fmt::Display for Value {
             Self::String(str) => write!(f, "{}", str.as_ref().borrow()),
             Self::Array(arr) => RawArray::display(f, arr, 1),
             Self::Map(map) => RawMap::display(f, map, 1),
-            Self::Object(obj) => Object::display(f, obj, 1),
+            Self::Matrix(mat) => Matrix::display(f, mat, 1),
+            Self::Object(obj) => match PolynomialModule::display_text(self) {
+                // the instance of Polynomial class is displayed as `3x^2 + 2x - 1`
+                Some(text) => write!(f, "{}", text),
+                None => Object::display(f, obj, 1),
+            },
             Self::Class(cls) => write!(f, "{}", cls),
 
             _ => {
@@ -337,7 +403,16 @@ impl fmt::Display for Value {
                             write!(f, "{}", bool_val.to_string().dark_yellow())
                         }
                         Self::Number(num) => write!(f, "{}", num.to_string().yellow()),
-                        Self::LazyExpression(_) => write!(f, "{}", "<Lazy-Expression>".cyan()),
+                        Self::Quantity(qty) => write!(
+                            f,
+                            "{} {}",
+                            qty.display_value().to_string().yellow(),
+                            qty.unit_text()
+                        ),
+                        Self::Interval(itv) => write!(f, "{}", itv.to_string().yellow()),
+                        Self::LazyExpression(l_expr) => {
+                            write!(f, "{}", lazy_expr_text(&l_expr.borrow()).cyan())
+                        }
                         Self::Function(func) => write!(f, "{}", func.to_string().cyan()),
                         _ => unreachable!(),
                     }
@@ -345,7 +420,11 @@ impl fmt::Display for Value {
                     match self {
                         Self::Boolean(bool_val) => write!(f, "{}", bool_val),
                         Self::Number(num) => write!(f, "{}", num),
-                        Self::LazyExpression(_) => write!(f, "{}", "<Lazy-Expression>"),
+                        Self::Quantity(qty) => write!(f, "{}", qty),
+                        Self::Interval(itv) => write!(f, "{}", itv),
+                        Self::LazyExpression(l_expr) => {
+                            write!(f, "{}", lazy_expr_text(&l_expr.borrow()))
+                        }
                         Self::Function(func) => write!(f, "{}", func),
                         _ => unreachable!(),
                     }
@@ -379,12 +458,15 @@ impl PartialEq for Value {
             (Self::Void(sub1), Self::Void(sub2)) => sub1 == sub2,
             (Self::Boolean(bool_val1), Self::Boolean(bool_val2)) => *bool_val1 == *bool_val2,
             (Self::Number(num1), Self::Number(num2)) => *num1 == *num2,
+            (Self::Quantity(qty1), Self::Quantity(qty2)) => *qty1 == *qty2,
+            (Self::Interval(itv1), Self::Interval(itv2)) => *itv1 == *itv2,
             (Self::String(str_ref1), Self::String(str_ref2)) => {
                 let str1 = str_ref1.as_ref().borrow();
                 let temp = str_ref2.as_ref().borrow();
                 let str2 = temp.as_str();
                 str1.eq(str2)
             }
+            (Self::Matrix(mat1), Self::Matrix(mat2)) => *mat1.borrow() == *mat2.borrow(),
             (Self::LazyExpression(_), Self::LazyExpression(_))
             | (Self::Unique(_), Self::Unique(_))
             | (Self::Array(_), Self::Array(_))
@@ -409,11 +491,26 @@ impl From<i64> for Value {
         Self::Number(Number::Int(value))
     }
 }
+impl From<Number> for Value {
+    fn from(value: Number) -> Self {
+        Self::Number(value)
+    }
+}
 impl From<f64> for Value {
     fn from(value: f64) -> Self {
         Self::Number(Number::Float(value))
     }
 }
+impl From<Quantity> for Value {
+    fn from(value: Quantity) -> Self {
+        Self::Quantity(value)
+    }
+}
+impl From<Interval> for Value {
+    fn from(value: Interval) -> Self {
+        Self::Interval(value)
+    }
+}
 impl From<Unique> for Value {
     fn from(value: Unique) -> Self {
         Self::Unique(value)
@@ -439,6 +536,11 @@ impl From<RawMap> for Value {
         Self::Map(into_rc_refcell(value))
     }
 }
+impl From<Matrix> for Value {
+    fn from(value: Matrix) -> Self {
+        Self::Matrix(into_rc_refcell(value))
+    }
+}
 impl From<ASTNode> for Value {
     fn from(value: ASTNode) -> Self {
         Self::LazyExpression(into_rc_refcell(value))
@@ -1,6 +1,8 @@
 // [a-z A-Z _ Non-ASCII]
 pub fn is_identi_ascii(ch: char) -> bool {
-    ch.is_ascii_alphabetic() || ch == '_' || !ch.is_ascii()
+    // non-ASCII characters are allowed in identifiers,
+    // except the interval symbol `±`.
+    ch.is_ascii_alphabetic() || ch == '_' || (!ch.is_ascii() && ch != '±')
 }
 // '1' -> 1
 pub fn ascii_to_num(ch: char) -> i64 {
@@ -1,16 +1,21 @@
 mod node;
 
+use std::collections::HashMap;
+
 use node::CompleterNode;
 
 #[derive(Debug, PartialEq)]
 pub struct Completer {
     root: CompleterNode,
+    // the descriptions shown after the completion hint
+    descriptions: HashMap<String, String>,
 }
 
 impl Completer {
     pub fn new() -> Self {
         Self {
             root: CompleterNode::new(),
+            descriptions: HashMap::new(),
         }
     }
 
@@ -18,6 +23,13 @@ impl Completer {
         self.root.insert(word.chars());
     }
 
+    pub fn describe(&mut self, word: &str, description: String) {
+        self.descriptions.insert(String::from(word), description);
+    }
+    pub fn description(&self, word: &str) -> Option<&str> {
+        return self.descriptions.get(word).map(String::as_str);
+    }
+
     pub fn complete(&self, word: &str) -> Vec<String> {
         let mut result = Vec::<String>::new();
         self.root.complete(word.chars(), &mut result);
@@ -49,7 +49,8 @@ fn get_end_part(tokens: &TokenVec) -> Option<Vec<String>> {
     }
 }
 
-pub fn analyze(tokens: &TokenVec, scope: &Scope) -> Result<Vec<String>, ()> {
+// the completion candidates with the descriptions
+pub fn analyze(tokens: &TokenVec, scope: &Scope) -> Result<Vec<(String, Option<String>)>, ()> {
     let Some(mut end_part) = get_end_part(tokens) else {
         return Ok(vec![])
     };
@@ -58,7 +59,11 @@ pub fn analyze(tokens: &TokenVec, scope: &Scope) -> Result<Vec<String>, ()> {
         // variable complete
         let global_completer = scope.completer.as_ref().unwrap();
         let word_to_complete = &end_part[0];
-        let candidates = global_completer.complete(word_to_complete);
+        let candidates = global_completer
+            .complete(word_to_complete)
+            .into_iter()
+            .map(|candidate| (candidate, None))
+            .collect();
         return Ok(candidates);
     } else {
         // object property complete
@@ -87,7 +92,16 @@ pub fn analyze(tokens: &TokenVec, scope: &Scope) -> Result<Vec<String>, ()> {
         let Some(completer) = completer else {
             return Err(());
         };
-        let candidates = completer.complete(&end_part[0]);
+        let word_to_complete = &end_part[0];
+        let candidates = completer
+            .complete(word_to_complete)
+            .into_iter()
+            .map(|candidate| {
+                let prop_name = format!("{}{}", word_to_complete, candidate);
+                let description = completer.description(&prop_name).map(String::from);
+                (candidate, description)
+            })
+            .collect();
         return Ok(candidates);
     }
 }
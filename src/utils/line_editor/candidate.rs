@@ -1,5 +1,6 @@
 pub struct Candidate {
-    list: Vec<String>,
+    // the completion hints with the descriptions
+    list: Vec<(String, Option<String>)>,
     index: isize,
 }
 
@@ -11,7 +12,7 @@ impl Candidate {
         }
     }
 
-    pub fn set(&mut self, candidates: Vec<String>) {
+    pub fn set(&mut self, candidates: Vec<(String, Option<String>)>) {
         self.index = -1; // reset index
         self.list = candidates;
     }
@@ -22,18 +23,29 @@ impl Candidate {
 
     pub fn current_hint<'a>(&'a self) -> Option<&'a str> {
         if !self.list.is_empty() && self.index >= 0 {
-            Some(&self.list[self.index as usize])
+            Some(&self.list[self.index as usize].0)
         } else {
             None
         }
     }
+    // the hint followed by the description, e.g. `h  # Planck constant`
+    pub fn current_display(&self) -> Option<String> {
+        if self.list.is_empty() || self.index < 0 {
+            return None;
+        }
+        let (hint_text, description) = &self.list[self.index as usize];
+        match description {
+            Some(description) => Some(format!("{}  # {}", hint_text, description)),
+            None => Some(hint_text.clone()),
+        }
+    }
     pub fn next<'a>(&'a mut self) -> Option<&'a str> {
         let index = self.index;
         let len = self.list.len() as isize;
 
         if len > 0 {
             self.index = (index + 1) % len;
-            Some(&self.list[self.index as usize])
+            Some(&self.list[self.index as usize].0)
         } else {
             None
         }
@@ -30,7 +30,7 @@ use tokenizer::{TextType, Token};
 use super::{cursor::Cursor, print_line, terminal::Terminal};
 
 pub struct LineEditor {
-    prompt: &'static str,
+    prompt: String,
     // terminal: Terminal,
     history: History,
     candidate: Candidate,
@@ -44,10 +44,11 @@ pub struct LineEditor {
 }
 
 impl LineEditor {
-    pub fn new(prompt: &'static str) -> Self {
+    pub fn new(prompt: String) -> Self {
         let term_width = Terminal::width();
 
         Self {
+            visible_area_width: term_width - prompt.len() - 2,
             prompt,
             history: History::new(),
             candidate: Candidate::new(),
@@ -57,10 +58,13 @@ impl LineEditor {
             line_count: 1,
             overflow_left: 0,
             overflow_right: 0,
-            visible_area_width: term_width - prompt.len() - 2,
         }
     }
 
+    pub fn set_prompt(&mut self, prompt: String) {
+        self.prompt = prompt;
+    }
+
     #[inline]
     fn display_prompt(&mut self) -> io::Result<()> {
         print!("{}", self.prompt);
@@ -127,13 +131,14 @@ impl LineEditor {
 
     // display & hide hint
     fn display_hint(&mut self, scope: &Scope) -> io::Result<()> {
-        if let Some(hint_text) = self.candidate.next() {
-            let hint_token = Token::new(TextType::Hint, String::from(hint_text));
+        if self.candidate.next().is_some() {
+            let hint_text = self.candidate.current_display().unwrap();
+            let hint_width = hint_text.chars().count();
+            let hint_token = Token::new(TextType::Hint, hint_text);
 
             // temporarily push hint token
             self.current_line.tokens.push(hint_token);
 
-            let hint_width = hint_text.len();
             let content_width = self.current_line.len() + hint_width;
 
             if content_width > self.visible_area_width {
@@ -164,7 +169,7 @@ impl LineEditor {
         return Ok(());
     }
     fn hide_hint(&mut self) -> io::Result<()> {
-        if let Some(hint_text) = self.candidate.current_hint() {
+        if let Some(hint_text) = self.candidate.current_display() {
             let hint_width = hint_text.chars().count();
             let overflow = self.overflow_left;
 
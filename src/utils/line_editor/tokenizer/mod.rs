@@ -1,6 +1,7 @@
 mod token;
 
-use crate::{public::compile_time::keywords::Keyword, utils::ascii::is_identi_ascii};
+use crate::public::{compile_time::keywords::Keyword, value::symbols::Symbols};
+use crate::utils::ascii::is_identi_ascii;
 
 pub use token::{TextType, Token, TokenType, TokenVec};
 
@@ -42,8 +43,29 @@ pub fn tokenize(source: &str) -> TokenVec {
                 value.insert(0, '-');
             }
 
+            // number literal with prefix: 0x | 0o | 0b
+            let mut radix = 10;
+            if ch == '0' {
+                radix = match chars.clone().next() {
+                    Some('x' | 'X') => 16,
+                    Some('o' | 'O') => 8,
+                    Some('b' | 'B') => 2,
+                    _ => 10,
+                };
+                if radix != 10 {
+                    value.push(chars.next().unwrap());
+                }
+            }
+
             while let Some(ch) = chars.next() {
-                if ch.is_ascii_digit() || ch == '.' {
+                let is_number_ch = match radix {
+                    10 => ch.is_ascii_digit() || ch == '.' || ch == 'e' || ch == 'E',
+                    _ => ch.is_digit(radix),
+                };
+                if is_number_ch || ch == '_' {
+                    value.push(ch);
+                } else if (ch == '+' || ch == '-') && radix == 10 && value.ends_with(['e', 'E']) {
+                    // sign of exponent
                     value.push(ch);
                 } else {
                     cached_ch = ch;
@@ -79,6 +101,9 @@ pub fn tokenize(source: &str) -> TokenVec {
                 if is_keyword {
                     last_type = TokenType::Keyword;
                     tokens.push(Token::new(TextType::Keyword, value));
+                } else if Symbols::is_word_symbol(&value).is_some() {
+                    last_type = TokenType::Symbol;
+                    tokens.push(Token::new(TextType::Symbol, value));
                 } else {
                     last_type = TokenType::Identifier;
                     tokens.push(Token::new(TextType::Variable, value));
@@ -96,7 +121,7 @@ pub fn tokenize(source: &str) -> TokenVec {
                 tokens.push(Token::new(TextType::Paren, String::from(ch)));
             }
             // Computing symbols
-            '+' | '-' | '*' | '/' | '%' | '^' | '!' | '<' | '>' | '=' | '.' | '&' | '|' => {
+            '+' | '-' | '*' | '/' | '%' | '^' | '!' | '<' | '>' | '=' | '.' | '&' | '|' | '±' => {
                 last_type = TokenType::Symbol;
                 tokens.push(Token::new(TextType::Symbol, String::from(ch)));
             }